@@ -1,4 +1,5 @@
-use crate::{Model, Solving, ffi};
+use crate::error::Infallible;
+use crate::{Model, PluginError, Solving, ffi};
 use scip_sys::SCIP_Result;
 
 /// A trait for defining custom branching rules.
@@ -21,6 +22,42 @@ pub trait BranchRule {
     ) -> BranchingResult;
 }
 
+/// A fallible variant of `BranchRule`, for branching rules that can fail (e.g. because they call external code).
+///
+/// Every `BranchRule` is a `TryBranchRule` that never fails. An error aborts the solve and is returned by
+/// `Model::try_solve`.
+pub trait TryBranchRule {
+    /// Executes the branching rule, see `BranchRule::execute`.
+    fn try_execute(
+        &mut self,
+        model: Model<Solving>,
+        branchrule: SCIPBranchRule,
+        candidates: Vec<BranchingCandidate>,
+    ) -> Result<BranchingResult, PluginError>;
+}
+
+impl<R: BranchRule + ?Sized> TryBranchRule for R {
+    fn try_execute(
+        &mut self,
+        model: Model<Solving>,
+        branchrule: SCIPBranchRule,
+        candidates: Vec<BranchingCandidate>,
+    ) -> Result<BranchingResult, PluginError> {
+        Ok(self.execute(model, branchrule, candidates))
+    }
+}
+
+impl TryBranchRule for Infallible<dyn BranchRule> {
+    fn try_execute(
+        &mut self,
+        model: Model<Solving>,
+        branchrule: SCIPBranchRule,
+        candidates: Vec<BranchingCandidate>,
+    ) -> Result<BranchingResult, PluginError> {
+        Ok(self.0.execute(model, branchrule, candidates))
+    }
+}
+
 /// The result of a branching rule execution.
 #[derive(Debug, Clone, PartialEq)]
 pub enum BranchingResult {
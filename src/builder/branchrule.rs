@@ -1,8 +1,8 @@
 use crate::builder::CanBeAddedToModel;
-use crate::{BranchRule, Model, ProblemCreated};
+use crate::{Model, ProblemCreated, TryBranchRule};
 
 /// A builder for easily creating branch rules. It can be created using the `branch_rule` function.
-pub struct BranchRuleBuilder<R: BranchRule> {
+pub struct BranchRuleBuilder<R: TryBranchRule> {
     name: Option<String>,
     desc: Option<String>,
     priority: i32,
@@ -11,7 +11,7 @@ pub struct BranchRuleBuilder<R: BranchRule> {
     rule: R,
 }
 
-impl<R: BranchRule> BranchRuleBuilder<R> {
+impl<R: TryBranchRule> BranchRuleBuilder<R> {
     /// Creates a new `BranchRuleBuilder` with the given branch rule.
     ///
     /// Defaults:
@@ -93,11 +93,11 @@ impl<R: BranchRule> BranchRuleBuilder<R> {
 /// let mut model = Model::default();
 /// model.add(rule);
 /// ```
-pub fn branchrule<R: BranchRule>(rule: R) -> BranchRuleBuilder<R> {
+pub fn branchrule<R: TryBranchRule>(rule: R) -> BranchRuleBuilder<R> {
     BranchRuleBuilder::new(rule)
 }
 
-impl<R: BranchRule + 'static> CanBeAddedToModel<ProblemCreated> for BranchRuleBuilder<R> {
+impl<R: TryBranchRule + 'static> CanBeAddedToModel<ProblemCreated> for BranchRuleBuilder<R> {
     type Return = ();
 
     fn add(self, model: &mut Model<ProblemCreated>) {
@@ -105,7 +105,7 @@ impl<R: BranchRule + 'static> CanBeAddedToModel<ProblemCreated> for BranchRuleBu
         let name = self.name.unwrap_or_else(|| "".into());
         let desc = self.desc.unwrap_or_else(|| "".into());
         let rule_box = Box::new(self.rule);
-        model.include_branch_rule(
+        model.include_try_branch_rule(
             &name,
             &desc,
             self.priority,
@@ -116,7 +116,7 @@ impl<R: BranchRule + 'static> CanBeAddedToModel<ProblemCreated> for BranchRuleBu
     }
 }
 
-impl<R: BranchRule> From<R> for BranchRuleBuilder<R> {
+impl<R: TryBranchRule> From<R> for BranchRuleBuilder<R> {
     fn from(rule: R) -> Self {
         BranchRuleBuilder::new(rule)
     }
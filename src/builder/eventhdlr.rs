@@ -1,14 +1,14 @@
 use crate::builder::CanBeAddedToModel;
-use crate::{Eventhdlr, Model, ProblemCreated};
+use crate::{Model, ProblemCreated, TryEventhdlr};
 
 /// A builder for easily creating event handlers. It can be created using the `eventhdlr` function.
-pub struct EventHdlrBuilder<E: Eventhdlr> {
+pub struct EventHdlrBuilder<E: TryEventhdlr> {
     name: Option<String>,
     desc: Option<String>,
     eventhdlr: E,
 }
 
-impl<E: Eventhdlr> EventHdlrBuilder<E> {
+impl<E: TryEventhdlr> EventHdlrBuilder<E> {
     /// Creates a new `EventHdlrBuilder` with the given event handler.
     ///
     /// # Defaults
@@ -56,17 +56,17 @@ impl<E: Eventhdlr> EventHdlrBuilder<E> {
 /// let mut model = Model::default();
 /// model.add(eventhdlr(MyEventHandler {}));
 /// ```
-pub fn eventhdlr<E: Eventhdlr>(ev: E) -> EventHdlrBuilder<E> {
+pub fn eventhdlr<E: TryEventhdlr>(ev: E) -> EventHdlrBuilder<E> {
     EventHdlrBuilder::new(ev)
 }
 
-impl<E: Eventhdlr + 'static> CanBeAddedToModel<ProblemCreated> for EventHdlrBuilder<E> {
+impl<E: TryEventhdlr + 'static> CanBeAddedToModel<ProblemCreated> for EventHdlrBuilder<E> {
     type Return = ();
 
     fn add(self, model: &mut Model<ProblemCreated>) {
         let name = self.name.unwrap_or_else(|| "".into());
         let desc = self.desc.unwrap_or_else(|| "".into());
         let eventhdlr = Box::new(self.eventhdlr);
-        model.include_eventhdlr(&name, &desc, eventhdlr);
+        model.include_try_eventhdlr(&name, &desc, eventhdlr);
     }
 }
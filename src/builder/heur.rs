@@ -1,8 +1,8 @@
 use crate::builder::CanBeAddedToModel;
-use crate::{HeurTiming, Heuristic, Model, ProblemCreated};
+use crate::{HeurTiming, Model, ProblemCreated, TryHeuristic};
 
 /// A builder for easily creating primal heuristics. It can be created using the `heur` function.
-pub struct HeurBuilder<H: Heuristic> {
+pub struct HeurBuilder<H: TryHeuristic> {
     name: Option<String>,
     desc: Option<String>,
     priority: i32,
@@ -15,7 +15,7 @@ pub struct HeurBuilder<H: Heuristic> {
     heur: H,
 }
 
-impl<H: Heuristic> HeurBuilder<H> {
+impl<H: TryHeuristic> HeurBuilder<H> {
     /// Creates a new `HeurBuilder` with the given heuristic.
     ///
     /// # Defaults
@@ -127,11 +127,11 @@ impl<H: Heuristic> HeurBuilder<H> {
 /// let mut model = Model::default();
 /// model.add(my_heur);
 /// ```
-pub fn heur<H: Heuristic>(heur: H) -> HeurBuilder<H> {
+pub fn heur<H: TryHeuristic>(heur: H) -> HeurBuilder<H> {
     HeurBuilder::new(heur)
 }
 
-impl<H: Heuristic + 'static> CanBeAddedToModel<ProblemCreated> for HeurBuilder<H> {
+impl<H: TryHeuristic + 'static> CanBeAddedToModel<ProblemCreated> for HeurBuilder<H> {
     type Return = ();
 
     fn add(self, model: &mut Model<ProblemCreated>) {
@@ -140,7 +140,7 @@ impl<H: Heuristic + 'static> CanBeAddedToModel<ProblemCreated> for HeurBuilder<H
         let dispchar = self.dispchar.unwrap_or('?');
         let timing = self.timing.unwrap_or(HeurTiming::BEFORE_NODE);
         let heur_box = Box::new(self.heur);
-        model.include_heur(
+        model.include_try_heur(
             &name,
             &desc,
             self.priority,
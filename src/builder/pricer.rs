@@ -1,8 +1,8 @@
 use crate::builder::CanBeAddedToModel;
-use crate::{Model, Pricer, ProblemCreated};
+use crate::{Model, ProblemCreated, TryPricer};
 
 /// A builder for easily creating pricers. It can be created using the `pricer` function.
-pub struct PricerBuilder<P: Pricer> {
+pub struct PricerBuilder<P: TryPricer> {
     name: Option<String>,
     desc: Option<String>,
     priority: i32,
@@ -10,7 +10,7 @@ pub struct PricerBuilder<P: Pricer> {
     pricer: P,
 }
 
-impl<P: Pricer> PricerBuilder<P> {
+impl<P: TryPricer> PricerBuilder<P> {
     /// Creates a new `PricerBuilder` with the given pricer.
     ///
     /// # Defaults
@@ -82,11 +82,11 @@ impl<P: Pricer> PricerBuilder<P> {
 /// let mut model = Model::default();
 /// model.add(pricer);
 /// ```
-pub fn pricer<P: Pricer>(pricer: P) -> PricerBuilder<P> {
+pub fn pricer<P: TryPricer>(pricer: P) -> PricerBuilder<P> {
     PricerBuilder::new(pricer)
 }
 
-impl<P: Pricer + 'static> CanBeAddedToModel<ProblemCreated> for PricerBuilder<P> {
+impl<P: TryPricer + 'static> CanBeAddedToModel<ProblemCreated> for PricerBuilder<P> {
     type Return = ();
 
     fn add(self, model: &mut Model<ProblemCreated>) {
@@ -94,6 +94,6 @@ impl<P: Pricer + 'static> CanBeAddedToModel<ProblemCreated> for PricerBuilder<P>
         let desc = self.desc.unwrap_or_else(|| "".into());
         let pricer_box = Box::new(self.pricer);
 
-        model.include_pricer(&name, &desc, self.priority, self.delay, pricer_box);
+        model.include_try_pricer(&name, &desc, self.priority, self.delay, pricer_box);
     }
 }
@@ -1,8 +1,8 @@
 use crate::builder::CanBeAddedToModel;
-use crate::{Model, ProblemCreated, Separator};
+use crate::{Model, ProblemCreated, TrySeparator};
 
 /// A builder for easily creating separators. It can be easily created using the `sepa` function.
-pub struct SepaBuilder<S: Separator> {
+pub struct SepaBuilder<S: TrySeparator> {
     name: Option<String>,
     desc: Option<String>,
     priority: i32,
@@ -13,7 +13,7 @@ pub struct SepaBuilder<S: Separator> {
     sepa: S,
 }
 
-impl<S: Separator> SepaBuilder<S> {
+impl<S: TrySeparator> SepaBuilder<S> {
     /// Create a new `SepaBuilder` with the given separator.
     ///
     /// # Defaults
@@ -56,11 +56,11 @@ impl<S: Separator> SepaBuilder<S> {
 /// let mut model = Model::default();
 /// model.add(sepa);
 /// ```
-pub fn sepa<S: Separator>(sepa: S) -> SepaBuilder<S> {
+pub fn sepa<S: TrySeparator>(sepa: S) -> SepaBuilder<S> {
     SepaBuilder::new(sepa)
 }
 
-impl<S: Separator> SepaBuilder<S> {
+impl<S: TrySeparator> SepaBuilder<S> {
     /// Sets the name of the separator.
     pub fn name(mut self, name: &str) -> Self {
         self.name = Some(name.to_string());
@@ -107,7 +107,7 @@ impl<S: Separator> SepaBuilder<S> {
     }
 }
 
-impl<S: Separator + 'static> CanBeAddedToModel<ProblemCreated> for SepaBuilder<S> {
+impl<S: TrySeparator + 'static> CanBeAddedToModel<ProblemCreated> for SepaBuilder<S> {
     type Return = ();
     fn add(self, model: &mut Model<ProblemCreated>) {
         let name = self.name.clone().unwrap_or("".into());
@@ -116,7 +116,7 @@ impl<S: Separator + 'static> CanBeAddedToModel<ProblemCreated> for SepaBuilder<S
         let sepa = self.sepa;
         let sepa = Box::new(sepa);
 
-        model.include_separator(
+        model.include_try_separator(
             &name,
             &desc,
             self.priority,
@@ -59,6 +59,11 @@ impl Col {
         unsafe { ffi::SCIPcolGetPrimsol(self.raw) }
     }
 
+    /// Returns the reduced cost of the column in the last LP solution.
+    pub fn reduced_cost(&self) -> f64 {
+        unsafe { ffi::SCIPgetColRedcost(self.scip.raw, self.raw) }
+    }
+
     /// Returns the minimal LP solution value, this column ever assumed.
     pub fn min_primal_sol(&self) -> f64 {
         unsafe { ffi::SCIPcolGetMinPrimsol(self.raw) }
@@ -132,6 +137,9 @@ impl Col {
     /// Returns the rows of non-zero entries.
     pub fn rows(&self) -> Vec<Row> {
         let n_non_zeros = self.n_non_zeros();
+        if n_non_zeros == 0 {
+            return Vec::new();
+        }
         let rows_ptr = unsafe { ffi::SCIPcolGetRows(self.raw) };
         let rows = unsafe { std::slice::from_raw_parts(rows_ptr, n_non_zeros) };
         rows.iter()
@@ -145,6 +153,9 @@ impl Col {
     /// Returns the coefficients of non-zero entries.
     pub fn vals(&self) -> Vec<f64> {
         let n_non_zeros = self.n_non_zeros();
+        if n_non_zeros == 0 {
+            return Vec::new();
+        }
         let vals_ptr = unsafe { ffi::SCIPcolGetVals(self.raw) };
         let vals = unsafe { std::slice::from_raw_parts(vals_ptr, n_non_zeros) };
         vals.to_vec()
@@ -235,4 +246,31 @@ mod tests {
         model.add(eventhdlr(e).name("ColTesterEventHandler"));
         model.solve();
     }
+
+    struct EmptyColEventHandler;
+
+    impl Eventhdlr for EmptyColEventHandler {
+        fn get_type(&self) -> EventMask {
+            EventMask::FIRST_LP_SOLVED
+        }
+
+        fn execute(&mut self, model: Model<Solving>, _eventhdlr: SCIPEventhdlr, _event: Event) {
+            // SCIP does not allocate the arrays of a column without entries
+            let col = model.vars()[1].col().unwrap();
+            assert_eq!(col.n_non_zeros(), 0);
+            assert!(col.rows().is_empty());
+            assert!(col.vals().is_empty());
+        }
+    }
+
+    #[test]
+    fn empty_col() {
+        let mut model = minimal_model();
+        let x = model.add_var(0.0, 1.0, 1.0, "x", VarType::Binary);
+        model.add_var(0.0, 1.0, 1.0, "y", VarType::Binary);
+        model.add_cons(vec![&x], &[1.0], 1.0, 1.0, "cons1");
+
+        model.add(eventhdlr(EmptyColEventHandler).name("EmptyColEventHandler"));
+        model.solve();
+    }
 }
@@ -1,4 +1,5 @@
-use crate::{Model, Retcode, Row, Solution, Solving, ffi, scip_call};
+use crate::error::Infallible;
+use crate::{Model, PluginError, Retcode, Row, Solution, Solving, ffi, scip_call};
 use scip_sys::SCIP_ROW;
 use std::fmt::Debug;
 
@@ -28,6 +29,65 @@ pub trait Conshdlr {
     fn enforce(&mut self, model: Model<Solving>, conshdlr: SCIPConshdlr) -> ConshdlrResult;
 }
 
+/// A fallible variant of `Conshdlr`, for constraint handlers that can fail (e.g. because they call external code).
+///
+/// Every `Conshdlr` is a `TryConshdlr` that never fails. An error aborts the solve and is returned by
+/// `Model::try_solve`.
+pub trait TryConshdlr {
+    /// Checks if the (primal) solution satisfies the constraint, see `Conshdlr::check`.
+    fn try_check(
+        &mut self,
+        model: Model<Solving>,
+        conshdlr: SCIPConshdlr,
+        solution: &Solution,
+    ) -> Result<bool, PluginError>;
+
+    /// Enforces the constraint for the current sub-problem's (LP) solution, see `Conshdlr::enforce`.
+    fn try_enforce(
+        &mut self,
+        model: Model<Solving>,
+        conshdlr: SCIPConshdlr,
+    ) -> Result<ConshdlrResult, PluginError>;
+}
+
+impl<C: Conshdlr + ?Sized> TryConshdlr for C {
+    fn try_check(
+        &mut self,
+        model: Model<Solving>,
+        conshdlr: SCIPConshdlr,
+        solution: &Solution,
+    ) -> Result<bool, PluginError> {
+        Ok(self.check(model, conshdlr, solution))
+    }
+
+    fn try_enforce(
+        &mut self,
+        model: Model<Solving>,
+        conshdlr: SCIPConshdlr,
+    ) -> Result<ConshdlrResult, PluginError> {
+        Ok(self.enforce(model, conshdlr))
+    }
+}
+
+impl TryConshdlr for Infallible<dyn Conshdlr> {
+    fn try_check(
+        &mut self,
+        model: Model<Solving>,
+        conshdlr: SCIPConshdlr,
+        solution: &Solution,
+    ) -> Result<bool, PluginError> {
+        Ok(self.0.check(model, conshdlr, solution))
+    }
+
+    fn try_enforce(
+        &mut self,
+        model: Model<Solving>,
+        conshdlr: SCIPConshdlr,
+    ) -> Result<ConshdlrResult, PluginError> {
+        Ok(self.0.enforce(model, conshdlr))
+    }
+}
+
 /// The result of enforcing a constraint handler.
 pub enum ConshdlrResult {
     /// States that the problem is feasible.
@@ -97,7 +157,7 @@ impl SCIPConshdlr {
         modifiable: bool,
         removable: bool,
     ) -> Result<Row, Retcode> {
-        let name = std::ffi::CString::new(name).unwrap();
+        let name = std::ffi::CString::new(name).map_err(|_| Retcode::InvalidData)?;
         let local = if local { 1 } else { 0 };
         let modifiable = if modifiable { 1 } else { 0 };
         let removable = if removable { 1 } else { 0 };
@@ -115,7 +175,7 @@ impl SCIPConshdlr {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Status;
+    use crate::{ProblemOrSolving, Status};
 
     #[test]
     fn all_inf_conshdlr() {
@@ -154,4 +214,42 @@ mod tests {
 
         assert_eq!(solved.status(), Status::Infeasible);
     }
+
+    #[test]
+    #[should_panic(expected = "check failed")]
+    fn panicking_check_outside_solve() {
+        struct PanickingConshdlr;
+
+        impl Conshdlr for PanickingConshdlr {
+            fn check(
+                &mut self,
+                _model: Model<Solving>,
+                _conshdlr: SCIPConshdlr,
+                _solution: &Solution,
+            ) -> bool {
+                panic!("check failed")
+            }
+
+            fn enforce(
+                &mut self,
+                _model: Model<Solving>,
+                _conshdlr: SCIPConshdlr,
+            ) -> ConshdlrResult {
+                ConshdlrResult::Feasible
+            }
+        }
+
+        let mut model = Model::default().hide_output();
+        model.include_conshdlr(
+            "PanickingConshdlr",
+            "Constraint handler with a panicking check",
+            -1,
+            -1,
+            Box::new(PanickingConshdlr {}),
+        );
+
+        // the panic of the check is resumed when adding the solution
+        let sol = model.create_orig_sol();
+        let _ = model.add_sol(sol);
+    }
 }
@@ -26,6 +26,15 @@ impl Constraint {
         }
     }
 
+    /// Returns the name of the constraint handler of the constraint (e.g. `linear` or `setppc`).
+    pub fn conshdlr_name(&self) -> String {
+        unsafe {
+            let conshdlr = ffi::SCIPconsGetHdlr(self.raw);
+            let name = ffi::SCIPconshdlrGetName(conshdlr);
+            String::from(std::ffi::CStr::from_ptr(name).to_str().unwrap())
+        }
+    }
+
     /// Returns the row associated with the constraint.
     pub fn row(&self) -> Option<Row> {
         let row_ptr = unsafe { ffi::SCIPconsGetRow(self.scip.raw, self.raw) };
@@ -0,0 +1,266 @@
+use crate::error::SolveError;
+use crate::param::ParamSnapshot;
+use crate::retcode::ScipError;
+use crate::scip::ScipPtr;
+use crate::status::Status;
+use crate::variable::Variable;
+use crate::{ffi, scip_call};
+use std::collections::HashMap;
+use std::ffi::{CStr, CString, c_char};
+use std::rc::Rc;
+
+/// The options of `Model::count_solutions`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Counting {
+    /// Whether to collect the counted solutions.
+    pub(crate) collect: bool,
+    /// The number of solutions after which counting stops.
+    pub(crate) limit: Option<u64>,
+}
+
+impl Counting {
+    /// Creates the options for counting all solutions without collecting them.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Collects the counted solutions as `SparseSolution`s. Presolving is turned off while collecting, so that
+    /// the solutions cover all original variables.
+    pub fn collect(mut self, collect: bool) -> Self {
+        self.collect = collect;
+        self
+    }
+
+    /// Stops counting after the given number of solutions.
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// A set of solutions in which each variable independently takes every integer value between its bounds.
+#[derive(Debug, Clone)]
+pub struct SparseSolution {
+    /// The original variables with their lower and upper bound.
+    pub ranges: Vec<(Variable, i64, i64)>,
+}
+
+impl SparseSolution {
+    /// Returns the number of solutions represented by the sparse solution, saturating at `u128::MAX`.
+    pub fn n_sols(&self) -> u128 {
+        self.ranges.iter().fold(1u128, |n, (_, lb, ub)| {
+            n.saturating_mul((ub - lb + 1) as u128)
+        })
+    }
+}
+
+/// The result of `Model::count_solutions`.
+#[derive(Debug, Clone)]
+pub struct CountResult {
+    /// The exact number of counted solutions in decimal notation, which may exceed the range of `u64`.
+    pub count: String,
+    /// Whether the number of solutions exceeds the range of a 64-bit integer.
+    pub overflow: bool,
+    /// The status of the counting process. Counting is complete once all solutions were counted and rejected,
+    /// i.e. if the status is `Status::Infeasible`.
+    pub status: Status,
+    /// The collected solutions, empty unless collecting was enabled.
+    pub solutions: Vec<SparseSolution>,
+}
+
+impl CountResult {
+    /// Returns the number of counted solutions, or `None` if it exceeds the range of `u64`.
+    pub fn n_sols(&self) -> Option<u64> {
+        self.count.parse().ok()
+    }
+}
+
+/// Counts the feasible solutions of the problem with the counter emphasis settings, which are reset afterwards.
+pub(crate) fn count(scip: &Rc<ScipPtr>, options: &Counting) -> Result<CountResult, SolveError> {
+    let mut settings = CounterSettings {
+        scip,
+        params: Some(ParamSnapshot::save(scip.raw)),
+    };
+    let result = count_with_settings(scip, options);
+    let reset = settings.reset();
+    let result = result?;
+    reset?;
+    Ok(result)
+}
+
+/// The parameters before switching to the counter emphasis, which are reset when dropped so that the settings are
+/// left unchanged even if a plugin panics during counting.
+struct CounterSettings<'a> {
+    scip: &'a ScipPtr,
+    params: Option<ParamSnapshot>,
+}
+
+impl CounterSettings<'_> {
+    /// Frees the transformed problem and resets the parameters, returning the first error.
+    fn reset(&mut self) -> Result<(), ScipError> {
+        let freed = self.scip.free_transform();
+        let restored = match self.params.take() {
+            Some(params) => params.restore(self.scip.raw),
+            None => Ok(()),
+        };
+        freed.and(restored)
+    }
+}
+
+impl Drop for CounterSettings<'_> {
+    fn drop(&mut self) {
+        let _ = self.reset();
+    }
+}
+
+fn count_with_settings(scip: &Rc<ScipPtr>, options: &Counting) -> Result<CountResult, SolveError> {
+    scip_call!(ffi::SCIPsetEmphasis(
+        scip.raw,
+        ffi::SCIP_ParamEmphasis_SCIP_PARAMEMPHASIS_COUNTER,
+        true.into(),
+    ));
+    let collect = CString::new("constraints/countsols/collect").unwrap();
+    scip_call!(ffi::SCIPsetBoolParam(
+        scip.raw,
+        collect.as_ptr(),
+        options.collect.into()
+    ));
+    if options.collect {
+        // fixed and aggregated variables are not part of the collected solutions
+        scip_call!(ffi::SCIPsetPresolving(
+            scip.raw,
+            ffi::SCIP_ParamSetting_SCIP_PARAMSETTING_OFF,
+            true.into(),
+        ));
+    }
+    if let Some(limit) = options.limit {
+        let sollimit = CString::new("constraints/countsols/sollimit").unwrap();
+        scip_call!(ffi::SCIPsetLongintParam(
+            scip.raw,
+            sollimit.as_ptr(),
+            limit.min(i64::MAX as u64) as i64
+        ));
+    }
+    scip.count()?;
+
+    let mut valid = 0;
+    unsafe { ffi::SCIPgetNCountedSols(scip.raw, &mut valid) };
+    let solutions = if options.collect {
+        sparse_solutions(scip)
+    } else {
+        Vec::new()
+    };
+    Ok(CountResult {
+        count: count_str(scip)?,
+        overflow: valid == 0,
+        status: scip.status(),
+        solutions,
+    })
+}
+
+fn count_str(scip: &ScipPtr) -> Result<String, ScipError> {
+    let mut size = 64;
+    loop {
+        let mut buffer = vec![0 as c_char; size];
+        let mut buffer_ptr = buffer.as_mut_ptr();
+        let mut required_size = 0;
+        unsafe {
+            ffi::SCIPgetNCountedSolsstr(scip.raw, &mut buffer_ptr, size as i32, &mut required_size)
+        };
+        if required_size as usize >= size {
+            size = required_size as usize + 1;
+            continue;
+        }
+        let count = unsafe { CStr::from_ptr(buffer_ptr) };
+        return Ok(count.to_string_lossy().into_owned());
+    }
+}
+
+fn sparse_solutions(scip: &Rc<ScipPtr>) -> Vec<SparseSolution> {
+    // the solutions are collected on the transformed variables
+    let orig_vars: HashMap<*mut ffi::SCIP_VAR, *mut ffi::SCIP_VAR> = scip
+        .vars(true, false)
+        .into_values()
+        .map(|var| (unsafe { ffi::SCIPvarGetTransVar(var) }, var))
+        .collect();
+
+    let mut vars = std::ptr::null_mut();
+    let mut n_vars = 0;
+    let mut sols = std::ptr::null_mut();
+    let mut n_sols = 0;
+    unsafe {
+        ffi::SCIPgetCountedSparseSols(scip.raw, &mut vars, &mut n_vars, &mut sols, &mut n_sols)
+    };
+
+    (0..n_sols as usize)
+        .map(|i| {
+            let sol = unsafe { *sols.add(i) };
+            let n_vars = unsafe { ffi::SCIPsparseSolGetNVars(sol) } as usize;
+            let vars = unsafe { ffi::SCIPsparseSolGetVars(sol) };
+            let lbs = unsafe { ffi::SCIPsparseSolGetLbs(sol) };
+            let ubs = unsafe { ffi::SCIPsparseSolGetUbs(sol) };
+            let ranges = (0..n_vars)
+                .filter_map(|j| {
+                    let var = unsafe { *vars.add(j) };
+                    let orig = *orig_vars.get(&var)?;
+                    let var = Variable {
+                        raw: orig,
+                        scip: scip.clone(),
+                    };
+                    Some(unsafe { (var, *lbs.add(j), *ubs.add(j)) })
+                })
+                .collect();
+            SparseSolution { ranges }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::{Counting, ModelWithProblem};
+
+    #[test]
+    fn count_solutions() {
+        // x + y + z <= 1 over binaries has 4 solutions
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .create_prob("count");
+        let vars: Vec<_> = ["x", "y", "z"]
+            .iter()
+            .map(|name| model.add_var(0., 1., 1., name, VarType::Binary))
+            .collect();
+        model.add_cons(
+            vars.iter().collect(),
+            &[1., 1., 1.],
+            -f64::INFINITY,
+            1.,
+            "c",
+        );
+        let presolving = model.int_param("presolving/maxrounds");
+
+        let result = model.count_solutions(Counting::new().collect(true));
+        let complete = result.status;
+        assert_eq!(result.count, "4");
+        assert_eq!(result.n_sols(), Some(4));
+        assert!(!result.overflow);
+        let collected: u128 = result.solutions.iter().map(|sol| sol.n_sols()).sum();
+        assert_eq!(collected, 4);
+
+        for sol in &result.solutions {
+            assert_eq!(sol.ranges.len(), 3);
+        }
+
+        let result = model.count_solutions(Counting::new().limit(2));
+        assert_eq!(result.n_sols(), Some(2));
+        assert_ne!(result.status, complete);
+        assert!(result.solutions.is_empty());
+
+        // the settings are reset and the problem can still be solved
+        assert_eq!(model.int_param("presolving/maxrounds"), presolving);
+        assert_eq!(model.n_vars(), 3);
+        let solved = model.maximize().solve();
+        assert_eq!(solved.obj_val(), 1.);
+    }
+}
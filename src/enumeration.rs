@@ -0,0 +1,255 @@
+use crate::error::SolveError;
+use crate::retcode::{Retcode, ScipError};
+use crate::scip::ScipPtr;
+use crate::solution::{Solution, SolutionData};
+use crate::status::Status;
+use crate::{ffi, scip_call};
+use std::ffi::CString;
+use std::mem::MaybeUninit;
+use std::rc::Rc;
+
+/// The options of `Model::enumerate_solutions`.
+///
+/// Solutions are told apart by their binary variables only: general integer variables are not supported, and
+/// continuous variables are not enumerated, so a problem without binary variables yields at most one solution.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Enumeration {
+    /// The maximum number of solutions to return.
+    pub(crate) k: usize,
+    /// The maximum absolute difference between the objective value of a returned solution and the optimum.
+    pub(crate) obj_tolerance: f64,
+    /// The minimum number of binary variables in which any two returned solutions differ.
+    pub(crate) min_distance: usize,
+}
+
+impl Enumeration {
+    /// Creates the options for enumerating the `k` best solutions.
+    pub fn new(k: usize) -> Self {
+        Enumeration {
+            k,
+            obj_tolerance: f64::INFINITY,
+            min_distance: 1,
+        }
+    }
+
+    /// Only returns solutions whose objective value is within `tol` of the optimum.
+    pub fn obj_tolerance(mut self, tol: f64) -> Self {
+        self.obj_tolerance = tol;
+        self
+    }
+
+    /// Requires every two returned solutions to differ in at least `distance` binary variables, i.e. to have a
+    /// Hamming distance of at least `distance` on the binary variables.
+    pub fn min_distance(mut self, distance: usize) -> Self {
+        self.min_distance = distance.max(1);
+        self
+    }
+}
+
+/// Enumerates solutions by repeatedly solving the problem and excluding the binary assignment of each found
+/// solution with a no-good constraint. The no-good constraints are removed again before returning, leaving the
+/// problem unchanged.
+///
+/// Enumerating stops once a solve does not end optimal, since the objective tolerance is measured from the optimum.
+pub(crate) fn enumerate(
+    scip: &Rc<ScipPtr>,
+    options: &Enumeration,
+) -> Result<Vec<SolutionData>, SolveError> {
+    let vars = scip.vars(true, false);
+    let is_binary = |var| unsafe { ffi::SCIPvarIsBinary(var) } != 0;
+    if vars.values().any(|&var| {
+        !is_binary(var)
+            && unsafe { ffi::SCIPvarGetType(var) } == ffi::SCIP_Vartype_SCIP_VARTYPE_INTEGER
+    }) {
+        // the no-goods cannot exclude an assignment of a general integer variable
+        return Err(ScipError::new(Retcode::InvalidData, "").into());
+    }
+    let binaries: Vec<_> = vars.into_values().filter(|&var| is_binary(var)).collect();
+
+    let mut solutions = Vec::new();
+    let mut no_goods = NoGoods {
+        scip,
+        conss: Vec::new(),
+    };
+    let result = loop {
+        if solutions.len() >= options.k {
+            break Ok(());
+        }
+        if let Err(error) = scip.solve() {
+            break Err(error);
+        }
+        if scip.status() != Status::Optimal {
+            break Ok(());
+        }
+        let Some(raw) = scip.best_sol() else {
+            break Ok(());
+        };
+        let sol = Solution {
+            raw,
+            scip_ptr: scip.clone(),
+        }
+        .snapshot();
+        let optimum = solutions
+            .first()
+            .map_or(sol.obj_val, |first: &SolutionData| first.obj_val);
+        if (sol.obj_val - optimum).abs() > options.obj_tolerance {
+            break Ok(());
+        }
+
+        // sum_{x* = 0} x + sum_{x* = 1} (1 - x) >= d
+        let mut coefs = Vec::with_capacity(binaries.len());
+        let mut n_ones = 0;
+        for &var in &binaries {
+            let val = unsafe { ffi::SCIPgetSolVal(scip.raw, raw, var) };
+            if val > 0.5 {
+                coefs.push(-1.);
+                n_ones += 1;
+            } else {
+                coefs.push(1.);
+            }
+        }
+        solutions.push(sol);
+        if binaries.is_empty() {
+            break Ok(());
+        }
+
+        if let Err(error) = scip.free_transform() {
+            break Err(error.into());
+        }
+        let lhs = options.min_distance as f64 - n_ones as f64;
+        match add_no_good(scip, &binaries, &coefs, lhs, no_goods.conss.len()) {
+            Ok(cons) => no_goods.conss.push(cons),
+            Err(error) => break Err(error.into()),
+        }
+    };
+
+    let removed = no_goods.remove();
+    result?;
+    removed?;
+    Ok(solutions)
+}
+
+/// The no-good constraints added to the problem, which are removed again when dropped so that the problem is left
+/// unchanged even if a plugin panics during the solve.
+struct NoGoods<'a> {
+    scip: &'a ScipPtr,
+    conss: Vec<*mut ffi::SCIP_CONS>,
+}
+
+impl NoGoods<'_> {
+    /// Frees the transformed problem and deletes the no-good constraints, returning the first error.
+    fn remove(&mut self) -> Result<(), ScipError> {
+        let freed = self.scip.free_transform();
+        let delete = |cons| -> Result<(), ScipError> {
+            scip_call!(ffi::SCIPdelCons(self.scip.raw, cons));
+            Ok(())
+        };
+        let deleted = self.conss.drain(..).map(delete).fold(Ok(()), Result::and);
+        freed.and(deleted)
+    }
+}
+
+impl Drop for NoGoods<'_> {
+    fn drop(&mut self) {
+        let _ = self.remove();
+    }
+}
+
+fn add_no_good(
+    scip: &ScipPtr,
+    vars: &[*mut ffi::SCIP_VAR],
+    coefs: &[f64],
+    lhs: f64,
+    index: usize,
+) -> Result<*mut ffi::SCIP_CONS, ScipError> {
+    let name = CString::new(format!("russcip_nogood_{index}")).unwrap();
+    let mut cons = MaybeUninit::uninit();
+    scip_call!(ffi::SCIPcreateConsBasicLinear(
+        scip.raw,
+        cons.as_mut_ptr(),
+        name.as_ptr(),
+        vars.len() as i32,
+        vars.as_ptr() as *mut _,
+        coefs.as_ptr() as *mut _,
+        lhs,
+        ffi::SCIPinfinity(scip.raw),
+    ));
+    let mut cons = unsafe { cons.assume_init() };
+    scip_call!(ffi::SCIPaddCons(scip.raw, cons));
+    // the problem holds the constraint until it is deleted
+    let raw = cons;
+    scip_call!(ffi::SCIPreleaseCons(scip.raw, &mut cons));
+    Ok(raw)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::{Enumeration, ModelWithProblem, ProblemCreated};
+
+    fn knapsack() -> Model<ProblemCreated> {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .create_prob("knapsack")
+            .maximize();
+        let weights = [2., 3., 4., 5.];
+        let values = [3., 4., 5., 6.];
+        let vars: Vec<_> = values
+            .iter()
+            .enumerate()
+            .map(|(i, &value)| model.add_var(0., 1., value, &format!("x{i}"), VarType::Binary))
+            .collect();
+        model.add_cons(
+            vars.iter().collect(),
+            &weights,
+            -f64::INFINITY,
+            5.,
+            "capacity",
+        );
+        model
+    }
+
+    #[test]
+    fn k_best() {
+        let mut model = knapsack();
+        let sols = model.enumerate_solutions(Enumeration::new(3));
+        let objs: Vec<_> = sols.iter().map(|sol| sol.obj_val).collect();
+        assert_eq!(objs, [7., 6., 5.]);
+        assert_eq!(sols[0].val("x0"), Some(1.));
+        assert_eq!(sols[0].val("x1"), Some(1.));
+
+        // the problem is left unchanged
+        assert_eq!(model.n_conss(), 1);
+        let solved = model.solve();
+        assert_eq!(solved.obj_val(), 7.);
+    }
+
+    #[test]
+    fn general_integers() {
+        let mut model = knapsack();
+        model.add_var(0., 3., 1., "y", VarType::Integer);
+        assert!(model.try_enumerate_solutions(Enumeration::new(3)).is_err());
+    }
+
+    #[test]
+    fn tolerance_and_diversity() {
+        let mut model = knapsack();
+        let sols = model.enumerate_solutions(Enumeration::new(10).obj_tolerance(1.));
+        assert_eq!(sols.len(), 2);
+
+        let sols = model.enumerate_solutions(Enumeration::new(10).min_distance(3));
+        for (i, a) in sols.iter().enumerate() {
+            for b in &sols[i + 1..] {
+                let distance = a
+                    .values
+                    .iter()
+                    .zip(&b.values)
+                    .filter(|(x, y)| (x.value - y.value).abs() > 0.5)
+                    .count();
+                assert!(distance >= 3);
+            }
+        }
+        assert_eq!(sols[0].obj_val, 7.);
+    }
+}
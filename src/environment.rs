@@ -0,0 +1,343 @@
+use crate::{
+    BipartiteObservation, BranchRule, BranchingCandidate, BranchingResult, Model, ProblemCreated,
+    SCIPBranchRule, SolutionData, Solving, Status, WithSolutions, WithSolvingStats, ffi,
+};
+use std::error::Error;
+use std::fmt;
+use std::sync::mpsc::{Receiver, Sender, channel};
+use std::thread::JoinHandle;
+
+/// The state of the solver at a branching decision, returned by `BranchingEnv::reset` and `BranchingEnv::step`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Observation {
+    /// The bipartite graph of the LP relaxation at the focus node.
+    pub graph: BipartiteObservation,
+    /// The branching candidates, one of which has to be passed to `BranchingEnv::step`.
+    pub candidates: Vec<BranchingCandidate>,
+}
+
+/// The outcome of a finished episode, returned by `BranchingEnv::result`.
+///
+/// The model itself stays on the solving thread, since it is not `Send`, so only owned data is handed back.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EpisodeResult {
+    /// The status of the solve.
+    pub status: Status,
+    /// The number of nodes processed in the solve.
+    pub n_nodes: usize,
+    /// The best solution found, if any.
+    pub best_sol: Option<SolutionData>,
+}
+
+/// The error returned by `BranchingEnv::step`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EnvError {
+    /// No episode is running, either because `reset` was not called or because the episode is done.
+    NoEpisode,
+    /// The action is not one of the candidates of the current observation.
+    InvalidAction(BranchingCandidate),
+}
+
+impl fmt::Display for EnvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EnvError::NoEpisode => write!(f, "no running episode, call reset first"),
+            EnvError::InvalidAction(action) => write!(
+                f,
+                "variable {} is not a branching candidate",
+                action.var_prob_id
+            ),
+        }
+    }
+}
+
+impl Error for EnvError {}
+
+/// An environment in the style of Ecole that hands the branching decisions of a solve to the caller.
+///
+/// The solve runs on a separate thread and is suspended inside a branch rule whenever a branching decision
+/// has to be taken, until the caller picks one of the candidates with `step`. The reward of a step is the
+/// negated number of nodes processed since the previous decision, so that maximizing the return minimizes
+/// the size of the branch-and-bound tree.
+///
+/// # Example
+///
+/// ```no_run
+/// use russcip::prelude::*;
+/// use russcip::BranchingEnv;
+///
+/// let mut env = BranchingEnv::new();
+/// let mut obs = env.reset(|| {
+///     Model::new()
+///         .hide_output()
+///         .include_default_plugins()
+///         .read_prob("data/test/gen-ip054.mps")
+///         .unwrap()
+/// });
+/// while !env.is_done() {
+///     let action = obs.candidates[0].clone();
+///     let (next_obs, _reward, _done) = env.step(action).unwrap();
+///     obs = next_obs;
+/// }
+/// let result = env.result().unwrap();
+/// ```
+#[derive(Default)]
+pub struct BranchingEnv {
+    actions: Option<Sender<BranchingCandidate>>,
+    events: Option<Receiver<EnvEvent>>,
+    worker: Option<JoinHandle<()>>,
+    candidates: Vec<BranchingCandidate>,
+    result: Option<EpisodeResult>,
+    n_nodes: usize,
+}
+
+impl BranchingEnv {
+    /// Creates a new environment without a running episode.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts a new episode by building a model with `make_model` and solving it until the first branching
+    /// decision.
+    ///
+    /// The model is built and solved on a separate thread, so `make_model` has to create everything the model
+    /// uses, including its plugins, instead of capturing handles from the calling thread. The model never leaves
+    /// that thread, the outcome of the episode is available through `result` once it is done. A running episode
+    /// is aborted first. If the model is solved without branching, the returned observation is empty and the
+    /// episode is done right away.
+    pub fn reset(
+        &mut self,
+        make_model: impl FnOnce() -> Model<ProblemCreated> + Send + 'static,
+    ) -> Observation {
+        self.close();
+
+        let (action_tx, action_rx) = channel();
+        let (event_tx, event_rx) = channel();
+        let worker = std::thread::spawn(move || {
+            let mut model = make_model();
+            model.include_branch_rule(
+                "russcip_env",
+                "Suspends the solve until the environment is stepped",
+                i32::MAX,
+                -1,
+                1.0,
+                Box::new(EnvBranchRule {
+                    events: event_tx.clone(),
+                    actions: action_rx,
+                }),
+            );
+            let solved = model.solve();
+            let result = EpisodeResult {
+                status: solved.status(),
+                n_nodes: solved.n_nodes(),
+                best_sol: solved.best_sol().map(|sol| sol.snapshot()),
+            };
+            let _ = event_tx.send(EnvEvent::Done(result));
+        });
+
+        self.actions = Some(action_tx);
+        self.events = Some(event_rx);
+        self.worker = Some(worker);
+        self.n_nodes = 0;
+        self.wait().0
+    }
+
+    /// Branches on the given candidate and resumes the solve until the next branching decision.
+    ///
+    /// # Returns
+    ///
+    /// The next observation, the reward of the step and whether the episode is done, or an error if no episode
+    /// is running or the action is not one of the candidates of the current observation.
+    pub fn step(
+        &mut self,
+        action: BranchingCandidate,
+    ) -> Result<(Observation, f64, bool), EnvError> {
+        let actions = self.actions.as_ref().ok_or(EnvError::NoEpisode)?;
+        if !self.candidates.contains(&action) {
+            return Err(EnvError::InvalidAction(action));
+        }
+        if actions.send(action).is_err() {
+            self.propagate_panic();
+        }
+        let (obs, reward) = self.wait();
+        Ok((obs, reward, self.is_done()))
+    }
+
+    /// Returns whether the current episode is done, i.e. the solve has terminated.
+    pub fn is_done(&self) -> bool {
+        self.result.is_some()
+    }
+
+    /// Returns the outcome of the finished episode, or `None` if no episode has finished.
+    pub fn result(&self) -> Option<&EpisodeResult> {
+        self.result.as_ref()
+    }
+
+    /// Waits for the next event of the solving thread and returns the observation and the reward.
+    fn wait(&mut self) -> (Observation, f64) {
+        let event = self.events.as_ref().unwrap().recv();
+        let (obs, n_nodes) = match event {
+            Ok(EnvEvent::Branch(obs, n_nodes)) => (*obs, n_nodes),
+            Ok(EnvEvent::Done(result)) => {
+                let n_nodes = result.n_nodes;
+                self.finish();
+                self.result = Some(result);
+                (Observation::default(), n_nodes)
+            }
+            Err(_) => self.propagate_panic(),
+        };
+        self.candidates = obs.candidates.clone();
+        let reward = -(n_nodes.saturating_sub(self.n_nodes) as f64);
+        self.n_nodes = n_nodes;
+        (obs, reward)
+    }
+
+    /// Joins the solving thread after it disconnected without finishing the episode, resuming its panic.
+    fn propagate_panic(&mut self) -> ! {
+        self.actions = None;
+        self.events = None;
+        match self.worker.take().map(JoinHandle::join) {
+            Some(Err(payload)) => std::panic::resume_unwind(payload),
+            _ => panic!("The solving thread of the environment terminated unexpectedly"),
+        }
+    }
+
+    /// Joins the solving thread of a finished episode.
+    fn finish(&mut self) {
+        self.actions = None;
+        self.events = None;
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+
+    /// Aborts the running episode, if any, and forgets the result of the previous one.
+    fn close(&mut self) {
+        // dropping the sender makes the branch rule interrupt the solve
+        self.actions = None;
+        if let Some(events) = self.events.take() {
+            while events.recv().is_ok() {}
+        }
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+        self.candidates.clear();
+        self.result = None;
+    }
+}
+
+impl Drop for BranchingEnv {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+enum EnvEvent {
+    Branch(Box<Observation>, usize),
+    Done(EpisodeResult),
+}
+
+struct EnvBranchRule {
+    events: Sender<EnvEvent>,
+    actions: Receiver<BranchingCandidate>,
+}
+
+impl BranchRule for EnvBranchRule {
+    fn execute(
+        &mut self,
+        model: Model<Solving>,
+        _branchrule: SCIPBranchRule,
+        candidates: Vec<BranchingCandidate>,
+    ) -> BranchingResult {
+        let obs = Observation {
+            graph: BipartiteObservation::extract(&model),
+            candidates,
+        };
+        let sent = self
+            .events
+            .send(EnvEvent::Branch(Box::new(obs), model.n_nodes()))
+            .is_ok();
+        match self.actions.recv() {
+            Ok(action) if sent => BranchingResult::BranchOn(action),
+            _ => {
+                // the environment was dropped or reset, abort the solve
+                unsafe { ffi::SCIPinterruptSolve(model.scip.raw) };
+                BranchingResult::DidNotRun
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model() -> Model<ProblemCreated> {
+        Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/gen-ip054.mps")
+            .unwrap()
+            .set_longint_param("limits/nodes", 30)
+            .unwrap()
+    }
+
+    #[test]
+    fn branching_env_episode() {
+        let mut env = BranchingEnv::new();
+        let mut obs = env.reset(model);
+        assert!(!env.is_done());
+
+        let mut total_reward = 0.0;
+        let mut n_steps = 0;
+        while !env.is_done() {
+            assert!(!obs.candidates.is_empty());
+            assert!(obs.graph.n_vars > 0);
+            let action = obs.candidates[0].clone();
+            let (next_obs, reward, done) = env.step(action).unwrap();
+            assert!(reward <= 0.0);
+            assert_eq!(done, env.is_done());
+            total_reward += reward;
+            n_steps += 1;
+            obs = next_obs;
+        }
+        assert!(n_steps > 0);
+        assert_eq!(obs, Observation::default());
+
+        let result = env.result().unwrap();
+        assert_eq!(result.status, Status::NodeLimit);
+        assert!(-total_reward <= result.n_nodes as f64);
+        let action = BranchingCandidate {
+            var_prob_id: 0,
+            lp_sol_val: 0.5,
+            frac: 0.5,
+        };
+        assert_eq!(env.step(action), Err(EnvError::NoEpisode));
+    }
+
+    #[test]
+    fn branching_env_invalid_action() {
+        let mut env = BranchingEnv::new();
+        let obs = env.reset(model);
+        let mut action = obs.candidates[0].clone();
+        action.var_prob_id = usize::MAX;
+        assert_eq!(
+            env.step(action.clone()),
+            Err(EnvError::InvalidAction(action))
+        );
+        // the episode continues with a valid action
+        assert!(env.step(obs.candidates[0].clone()).is_ok());
+    }
+
+    #[test]
+    fn branching_env_reset_aborts_episode() {
+        let mut env = BranchingEnv::new();
+        let obs = env.reset(model);
+        assert!(!obs.candidates.is_empty());
+
+        let obs = env.reset(model);
+        assert!(!obs.candidates.is_empty());
+        env.step(obs.candidates[0].clone()).unwrap();
+        drop(env);
+    }
+}
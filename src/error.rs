@@ -0,0 +1,48 @@
+use crate::ScipError;
+use std::error::Error;
+use std::fmt;
+
+/// The error returned by the fallible plugin callbacks, e.g. `TryHeuristic::try_execute`.
+pub type PluginError = Box<dyn Error + Send + Sync>;
+
+/// Adapts a boxed infallible plugin, e.g. a `Box<dyn Heuristic>`, to its fallible trait, since trait objects
+/// cannot be converted into each other.
+pub(crate) struct Infallible<P: ?Sized>(pub(crate) Box<P>);
+
+/// The error returned by `Model::try_solve`.
+#[derive(Debug)]
+pub enum SolveError {
+    /// A plugin callback returned an error, which aborted the solve.
+    Plugin {
+        /// The name of the plugin.
+        plugin: String,
+        /// The error returned by the plugin.
+        error: PluginError,
+    },
+    /// A SCIP call failed.
+    Scip(ScipError),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Plugin { plugin, error } => write!(f, "plugin {plugin} failed: {error}"),
+            SolveError::Scip(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for SolveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SolveError::Plugin { error, .. } => Some(error.as_ref()),
+            SolveError::Scip(error) => Some(error),
+        }
+    }
+}
+
+impl From<ScipError> for SolveError {
+    fn from(error: ScipError) -> Self {
+        SolveError::Scip(error)
+    }
+}
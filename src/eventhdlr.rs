@@ -1,5 +1,6 @@
+use crate::error::Infallible;
 use crate::scip::ScipPtr;
-use crate::{Model, Solving, Variable, ffi};
+use crate::{Model, PluginError, Solving, Variable, ffi};
 use scip_sys::SCIPeventGetVar;
 use std::ops::{BitOr, BitOrAssign};
 use std::rc::Rc;
@@ -18,6 +19,55 @@ pub trait Eventhdlr {
     fn execute(&mut self, model: Model<Solving>, eventhdlr: SCIPEventhdlr, event: Event);
 }
 
+/// A fallible variant of `Eventhdlr`, for event handlers that can fail (e.g. because they call external code).
+///
+/// Every `Eventhdlr` is a `TryEventhdlr` that never fails. An error aborts the solve and is returned by
+/// `Model::try_solve`.
+pub trait TryEventhdlr {
+    /// Returns the type of the event handler, see `Eventhdlr::get_type`.
+    fn event_type(&self) -> EventMask;
+
+    /// Executes the event handler, see `Eventhdlr::execute`.
+    fn try_execute(
+        &mut self,
+        model: Model<Solving>,
+        eventhdlr: SCIPEventhdlr,
+        event: Event,
+    ) -> Result<(), PluginError>;
+}
+
+impl<E: Eventhdlr + ?Sized> TryEventhdlr for E {
+    fn event_type(&self) -> EventMask {
+        self.get_type()
+    }
+
+    fn try_execute(
+        &mut self,
+        model: Model<Solving>,
+        eventhdlr: SCIPEventhdlr,
+        event: Event,
+    ) -> Result<(), PluginError> {
+        self.execute(model, eventhdlr, event);
+        Ok(())
+    }
+}
+
+impl TryEventhdlr for Infallible<dyn Eventhdlr> {
+    fn event_type(&self) -> EventMask {
+        self.0.get_type()
+    }
+
+    fn try_execute(
+        &mut self,
+        model: Model<Solving>,
+        eventhdlr: SCIPEventhdlr,
+        event: Event,
+    ) -> Result<(), PluginError> {
+        self.0.execute(model, eventhdlr, event);
+        Ok(())
+    }
+}
+
 /// The EventMask represents different states or actions within an optimization problem.
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct EventMask(u64);
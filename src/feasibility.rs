@@ -0,0 +1,333 @@
+use crate::retcode::ScipError;
+use crate::scip::{ScipPtr, cstr_to_string, resume_callback_panics};
+use crate::{ffi, scip_call};
+use scip_sys::{SCIP_CONS, SCIP_SOL};
+use std::ffi::c_int;
+
+/// A constraint violated by a solution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsViolation {
+    /// The name of the constraint.
+    pub name: String,
+    /// The name of the constraint handler, e.g. `linear` or `setppc`.
+    pub handler: String,
+    /// The activity of the constraint in the solution.
+    pub activity: Option<f64>,
+    /// The left-hand side of the constraint.
+    pub lhs: Option<f64>,
+    /// The right-hand side of the constraint.
+    pub rhs: Option<f64>,
+    /// The amount by which the activity exceeds the violated side.
+    pub abs_violation: Option<f64>,
+    /// The violation relative to the magnitude of the violated side and the activity.
+    pub rel_violation: Option<f64>,
+}
+
+/// A variable whose value in a solution lies outside of its bounds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundViolation {
+    /// The name of the variable.
+    pub name: String,
+    /// The value of the variable in the solution.
+    pub value: f64,
+    /// The lower bound of the variable.
+    pub lb: f64,
+    /// The upper bound of the variable.
+    pub ub: f64,
+    /// The distance of the value to the violated bound.
+    pub violation: f64,
+}
+
+/// An integer variable with a fractional value in a solution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntegralityViolation {
+    /// The name of the variable.
+    pub name: String,
+    /// The value of the variable in the solution.
+    pub value: f64,
+    /// The distance of the value to the nearest integer.
+    pub violation: f64,
+}
+
+/// The result of checking a solution, returned by `ProblemOrSolving::check_sol_detailed`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeasibilityReport {
+    /// Whether SCIP considers the solution feasible.
+    pub feasible: bool,
+    /// The violated constraints.
+    ///
+    /// The activity, sides and violation are only known for constraints that can be represented as a single
+    /// linear constraint. Before solving, only those constraints are checked individually, see `unchecked`.
+    pub conss: Vec<ConsViolation>,
+    /// The names of the constraints that could not be checked individually, i.e. the constraints without a linear
+    /// representation before solving. If the solution is infeasible, one of them may be the reason.
+    pub unchecked: Vec<String>,
+    /// The variables violating their bounds.
+    pub bounds: Vec<BoundViolation>,
+    /// The integer variables with a fractional value.
+    pub integrality: Vec<IntegralityViolation>,
+}
+
+impl FeasibilityReport {
+    /// Returns whether the solution is feasible.
+    pub fn is_feasible(&self) -> bool {
+        self.feasible
+    }
+
+    /// Checks the given solution against the problem it belongs to, i.e. the original problem for original
+    /// solutions and the transformed problem otherwise.
+    ///
+    /// The reasons for infeasibility are also printed by SCIP.
+    pub(crate) fn collect(scip_ptr: &ScipPtr, sol: *mut SCIP_SOL) -> Result<Self, ScipError> {
+        let scip = scip_ptr.raw;
+        let is_orig = unsafe { ffi::SCIPsolIsOriginal(sol) } != 0;
+        let mut feasible = 0;
+        // the checks of constraint handlers implemented in Rust may panic
+        resume_callback_panics(|| -> Result<(), ScipError> {
+            if is_orig {
+                scip_call!(ffi::SCIPcheckSolOrig(
+                    scip,
+                    sol,
+                    &mut feasible,
+                    true.into(),
+                    true.into(),
+                ));
+            } else {
+                scip_call!(ffi::SCIPcheckSol(
+                    scip,
+                    sol,
+                    true.into(),
+                    true.into(),
+                    true.into(),
+                    true.into(),
+                    false.into(),
+                    &mut feasible,
+                ));
+            }
+            Ok(())
+        })?;
+
+        let (vars, conss) = unsafe {
+            if is_orig {
+                (
+                    slice(ffi::SCIPgetOrigVars(scip), ffi::SCIPgetNOrigVars(scip)),
+                    slice(ffi::SCIPgetOrigConss(scip), ffi::SCIPgetNOrigConss(scip)),
+                )
+            } else {
+                (
+                    slice(ffi::SCIPgetVars(scip), ffi::SCIPgetNVars(scip)),
+                    slice(ffi::SCIPgetConss(scip), ffi::SCIPgetNConss(scip)),
+                )
+            }
+        };
+
+        let mut bounds = Vec::new();
+        let mut integrality = Vec::new();
+        for &var in vars {
+            let value = unsafe { ffi::SCIPgetSolVal(scip, sol, var) };
+            let (lb, ub) = unsafe {
+                if is_orig {
+                    (
+                        ffi::SCIPvarGetLbOriginal(var),
+                        ffi::SCIPvarGetUbOriginal(var),
+                    )
+                } else {
+                    (ffi::SCIPvarGetLbGlobal(var), ffi::SCIPvarGetUbGlobal(var))
+                }
+            };
+            if unsafe { ffi::SCIPisFeasLT(scip, value, lb) } != 0 {
+                bounds.push(BoundViolation {
+                    name: cstr_to_string(unsafe { ffi::SCIPvarGetName(var) }),
+                    value,
+                    lb,
+                    ub,
+                    violation: lb - value,
+                });
+            } else if unsafe { ffi::SCIPisFeasGT(scip, value, ub) } != 0 {
+                bounds.push(BoundViolation {
+                    name: cstr_to_string(unsafe { ffi::SCIPvarGetName(var) }),
+                    value,
+                    lb,
+                    ub,
+                    violation: value - ub,
+                });
+            }
+            if unsafe { ffi::SCIPvarIsIntegral(var) } != 0
+                && unsafe { ffi::SCIPisFeasIntegral(scip, value) } == 0
+            {
+                integrality.push(IntegralityViolation {
+                    name: cstr_to_string(unsafe { ffi::SCIPvarGetName(var) }),
+                    value,
+                    violation: (value - value.round()).abs(),
+                });
+            }
+        }
+
+        // single constraints can only be checked by their handlers once the problem is transformed
+        let can_check = unsafe { ffi::SCIPgetStage(scip) } != ffi::SCIP_Stage_SCIP_STAGE_PROBLEM;
+        let mut violated = Vec::new();
+        let mut unchecked = Vec::new();
+        for &cons in conss {
+            let mut linear = None;
+            let is_violated = if can_check {
+                let mut result = ffi::SCIP_Result_SCIP_DIDNOTRUN;
+                resume_callback_panics(|| -> Result<(), ScipError> {
+                    scip_call!(ffi::SCIPcheckCons(
+                        scip,
+                        cons,
+                        sol,
+                        true.into(),
+                        true.into(),
+                        false.into(),
+                        &mut result,
+                    ));
+                    Ok(())
+                })?;
+                result == ffi::SCIP_Result_SCIP_INFEASIBLE
+            } else {
+                linear = linear_activity(scip_ptr, cons, sol);
+                let Some((activity, lhs, rhs)) = linear else {
+                    unchecked.push(cstr_to_string(unsafe { ffi::SCIPconsGetName(cons) }));
+                    continue;
+                };
+                unsafe {
+                    ffi::SCIPisFeasLT(scip, activity, lhs) != 0
+                        || ffi::SCIPisFeasGT(scip, activity, rhs) != 0
+                }
+            };
+            if !is_violated {
+                continue;
+            }
+            if can_check {
+                linear = linear_activity(scip_ptr, cons, sol);
+            }
+
+            let handler = unsafe { ffi::SCIPconsGetHdlr(cons) };
+            let mut violation = ConsViolation {
+                name: cstr_to_string(unsafe { ffi::SCIPconsGetName(cons) }),
+                handler: cstr_to_string(unsafe { ffi::SCIPconshdlrGetName(handler) }),
+                activity: None,
+                lhs: None,
+                rhs: None,
+                abs_violation: None,
+                rel_violation: None,
+            };
+            if let Some((activity, lhs, rhs)) = linear {
+                let (abs, rel) = if activity < lhs {
+                    (lhs - activity, unsafe { ffi::SCIPrelDiff(lhs, activity) })
+                } else if activity > rhs {
+                    (activity - rhs, unsafe { ffi::SCIPrelDiff(activity, rhs) })
+                } else {
+                    (0.0, 0.0)
+                };
+                violation.activity = Some(activity);
+                violation.lhs = Some(lhs);
+                violation.rhs = Some(rhs);
+                violation.abs_violation = Some(abs);
+                violation.rel_violation = Some(rel);
+            }
+            violated.push(violation);
+        }
+
+        Ok(FeasibilityReport {
+            feasible: feasible != 0,
+            conss: violated,
+            unchecked,
+            bounds,
+            integrality,
+        })
+    }
+}
+
+/// Returns the activity and sides of a constraint that can be represented as a single linear constraint.
+pub(crate) fn linear_activity(
+    scip: &ScipPtr,
+    cons: *mut SCIP_CONS,
+    sol: *mut SCIP_SOL,
+) -> Option<(f64, f64, f64)> {
+    let row = scip.cons_linear_row(cons)?;
+    let activity = row
+        .vars
+        .iter()
+        .zip(row.vals)
+        .map(|(&var, val)| val * unsafe { ffi::SCIPgetSolVal(scip.raw, sol, var) })
+        .sum();
+    Some((activity, row.lhs, row.rhs))
+}
+
+unsafe fn slice<'a, T>(ptr: *mut *mut T, len: c_int) -> &'a [*mut T] {
+    if len == 0 {
+        return &[];
+    }
+    unsafe { std::slice::from_raw_parts(ptr, len as usize) }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SolError;
+    use crate::prelude::*;
+
+    #[test]
+    fn check_sol_detailed() {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .create_prob("test")
+            .set_obj_sense(ObjSense::Maximize);
+        let x = model.add_var(0., 5., 1., "x", VarType::Integer);
+        let y = model.add_var(0., 5., 1., "y", VarType::Continuous);
+        model.add_cons(vec![&x, &y], &[1., 2.], -f64::INFINITY, 6., "c1");
+        model.add_cons(vec![&x, &y], &[1., -1.], 0., f64::INFINITY, "c2");
+
+        let sol = model.create_orig_sol();
+        sol.set_val(&x, 2.);
+        sol.set_val(&y, 1.);
+        let report = model.check_sol_detailed(&sol);
+        assert!(report.is_feasible());
+        assert!(report.conss.is_empty());
+        assert!(report.unchecked.is_empty());
+
+        sol.set_val(&x, 2.5);
+        sol.set_val(&y, 6.);
+        let report = model.check_sol_detailed(&sol);
+        assert!(!report.is_feasible());
+
+        let names: Vec<_> = report.conss.iter().map(|cons| cons.name.as_str()).collect();
+        assert_eq!(names, ["c1", "c2"]);
+        let c1 = &report.conss[0];
+        assert_eq!(c1.handler, "linear");
+        assert_eq!(c1.activity, Some(14.5));
+        assert_eq!(c1.rhs, Some(6.));
+        assert_eq!(c1.abs_violation, Some(8.5));
+        assert!(c1.rel_violation.unwrap() > 0.);
+
+        assert_eq!(report.bounds.len(), 1);
+        assert_eq!(report.bounds[0].name, "y");
+        assert_eq!(report.bounds[0].violation, 1.);
+        assert_eq!(report.integrality.len(), 1);
+        assert_eq!(report.integrality[0].name, "x");
+        assert_eq!(report.integrality[0].violation, 0.5);
+
+        assert_eq!(model.add_sol(sol), Err(SolError::Infeasible));
+    }
+
+    #[test]
+    fn check_sol_detailed_unchecked() {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .create_prob("test");
+        let x = model.add_var(0., 1., 1., "x", VarType::Binary);
+        let y = model.add_var(0., 1., 1., "y", VarType::Binary);
+        model.add_cons_cardinality(vec![&x, &y], 1, "card");
+
+        // the cardinality constraint has no linear representation to check before solving
+        let sol = model.create_orig_sol();
+        sol.set_val(&x, 1.);
+        sol.set_val(&y, 1.);
+        let report = model.check_sol_detailed(&sol);
+        assert!(!report.is_feasible());
+        assert!(report.conss.is_empty());
+        assert_eq!(report.unchecked, ["card"]);
+    }
+}
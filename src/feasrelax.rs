@@ -0,0 +1,481 @@
+use crate::constraint::Constraint;
+use crate::error::SolveError;
+use crate::retcode::{Retcode, ScipError};
+use crate::scip::ScipPtr;
+use crate::solution::{Solution, SolutionData};
+use crate::status::Status;
+use crate::variable::Variable;
+use crate::{ffi, scip_call};
+use scip_sys::{SCIP_CONS, SCIP_VAR};
+use std::ffi::{CStr, CString};
+use std::mem::MaybeUninit;
+use std::rc::Rc;
+
+/// The constraints and variable bounds that `Model::feasibility_relaxation` may violate, each with the weight
+/// of a unit of violation in the objective of the relaxation.
+#[derive(Debug, Clone, Default)]
+pub struct RelaxationWeights {
+    pub(crate) conss: Vec<(Constraint, f64)>,
+    pub(crate) bounds: Vec<(Variable, f64)>,
+}
+
+impl RelaxationWeights {
+    /// Creates weights that relax nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allows violating both sides of the given linear constraint at the given cost per unit.
+    pub fn cons(mut self, cons: &Constraint, weight: f64) -> Self {
+        self.conss.push((cons.clone(), weight));
+        self
+    }
+
+    /// Allows violating both bounds of the given non-binary variable at the given cost per unit.
+    pub fn bounds(mut self, var: &Variable, weight: f64) -> Self {
+        self.bounds.push((var.clone(), weight));
+        self
+    }
+}
+
+/// The violation of a relaxed constraint in the relaxed solution.
+#[derive(Debug, Clone)]
+pub struct ConsRelaxation {
+    /// The original constraint.
+    pub cons: Constraint,
+    /// The amount by which the activity lies outside of the sides of the constraint.
+    pub violation: f64,
+}
+
+/// The violation of the relaxed bounds of a variable in the relaxed solution.
+#[derive(Debug, Clone)]
+pub struct BoundRelaxation {
+    /// The original variable.
+    pub var: Variable,
+    /// The amount by which the value lies outside of the bounds of the variable.
+    pub violation: f64,
+}
+
+/// The result of `Model::feasibility_relaxation`.
+#[derive(Debug, Clone)]
+pub struct FeasRelaxation {
+    /// The status of the solve of the relaxation.
+    pub status: Status,
+    /// The best relaxed solution over the original variables, if one was found. Its objective value is the
+    /// value of the original objective.
+    pub solution: Option<SolutionData>,
+    /// The weighted violation of the relaxed solution, or infinity if no solution was found.
+    pub total_violation: f64,
+    /// The violations of the relaxed constraints, empty if no solution was found.
+    pub conss: Vec<ConsRelaxation>,
+    /// The violations of the relaxed bounds, empty if no solution was found.
+    pub bounds: Vec<BoundRelaxation>,
+}
+
+impl FeasRelaxation {
+    /// Returns the constraints and variable bounds that are violated by the relaxed solution.
+    pub fn violated(&self) -> (Vec<&ConsRelaxation>, Vec<&BoundRelaxation>) {
+        (
+            self.conss.iter().filter(|c| c.violation > 0.).collect(),
+            self.bounds.iter().filter(|b| b.violation > 0.).collect(),
+        )
+    }
+}
+
+/// The changes made to the problem to make it elastic, undone by `Elastic::restore` or when dropped so that the
+/// problem is left unchanged even if a plugin panics during the solve.
+struct Elastic<'a> {
+    scip: &'a ScipPtr,
+    /// The original objective sense and offset.
+    objective: Option<(ffi::SCIP_OBJSENSE, f64)>,
+    /// The original variables with a nonzero objective coefficient.
+    obj_coefs: Vec<(*mut SCIP_VAR, f64)>,
+    /// The slack variables, captured until they are deleted.
+    slacks: Vec<*mut SCIP_VAR>,
+    /// The slack variables added to the relaxed constraints.
+    coefs: Vec<(*mut SCIP_CONS, *mut SCIP_VAR)>,
+    /// The original bounds of the relaxed variables.
+    bounds: Vec<(*mut SCIP_VAR, f64, f64)>,
+    /// The constraints replacing the relaxed bounds.
+    bound_conss: Vec<*mut SCIP_CONS>,
+}
+
+/// Minimizes the weighted violation of the relaxed constraints and bounds by adding nonnegative slack variables
+/// to the problem. The slack variables are removed and the objective and bounds restored before returning,
+/// leaving the problem unchanged.
+pub(crate) fn relax(
+    scip: &Rc<ScipPtr>,
+    weights: &RelaxationWeights,
+) -> Result<FeasRelaxation, SolveError> {
+    let mut elastic = Elastic::new(scip);
+    let result = make_elastic(scip, weights, &mut elastic)
+        .map_err(SolveError::from)
+        .and_then(|(cons_slacks, bound_slacks)| {
+            scip.solve()?;
+            Ok(collect(scip, &elastic, cons_slacks, bound_slacks))
+        });
+    let restored = elastic.restore();
+    let result = result?;
+    restored?;
+    Ok(result)
+}
+
+type Slacks<T> = Vec<(T, Vec<*mut SCIP_VAR>)>;
+
+fn make_elastic(
+    scip: &Rc<ScipPtr>,
+    weights: &RelaxationWeights,
+    elastic: &mut Elastic,
+) -> Result<(Slacks<Constraint>, Slacks<Variable>), ScipError> {
+    let negative = |weight: &f64| *weight < 0.;
+    if weights.conss.iter().map(|(_, w)| w).any(negative)
+        || weights.bounds.iter().map(|(_, w)| w).any(negative)
+    {
+        return Err(ScipError::new(
+            Retcode::ParameterWrongVal,
+            "feasibility_relaxation",
+        ));
+    }
+
+    // only the violation is minimized
+    let sense = unsafe { ffi::SCIPgetObjsense(scip.raw) };
+    let offset = unsafe { ffi::SCIPgetOrigObjoffset(scip.raw) };
+    elastic.objective = Some((sense, offset));
+    scip_call!(ffi::SCIPsetObjsense(
+        scip.raw,
+        ffi::SCIP_Objsense_SCIP_OBJSENSE_MINIMIZE
+    ));
+    scip_call!(ffi::SCIPaddOrigObjoffset(scip.raw, -offset));
+    for var in scip.vars(true, false).into_values() {
+        let obj = unsafe { ffi::SCIPvarGetObj(var) };
+        if obj != 0. {
+            elastic.obj_coefs.push((var, obj));
+            scip_call!(ffi::SCIPchgVarObj(scip.raw, var, 0.));
+        }
+    }
+
+    let inf = unsafe { ffi::SCIPinfinity(scip.raw) };
+    let mut cons_slacks = Vec::with_capacity(weights.conss.len());
+    for (cons, weight) in &weights.conss {
+        let handler =
+            unsafe { CStr::from_ptr(ffi::SCIPconshdlrGetName(ffi::SCIPconsGetHdlr(cons.raw))) };
+        if handler.to_bytes() != b"linear" {
+            return Err(ScipError::new(Retcode::InvalidData, "SCIPaddCoefLinear"));
+        }
+        let name = unsafe { CStr::from_ptr(ffi::SCIPconsGetName(cons.raw)) }.to_string_lossy();
+        let lhs = unsafe { ffi::SCIPgetLhsLinear(scip.raw, cons.raw) };
+        let rhs = unsafe { ffi::SCIPgetRhsLinear(scip.raw, cons.raw) };
+        let mut slacks = Vec::new();
+        for (side, is_finite, coef) in [("lhs", lhs > -inf, 1.), ("rhs", rhs < inf, -1.)] {
+            if !is_finite {
+                continue;
+            }
+            let slack = add_slack(scip, elastic, &format!("{name}_{side}"), *weight)?;
+            scip_call!(ffi::SCIPaddCoefLinear(scip.raw, cons.raw, slack, coef));
+            elastic.coefs.push((cons.raw, slack));
+            slacks.push(slack);
+        }
+        cons_slacks.push((cons.clone(), slacks));
+    }
+
+    let mut bound_slacks = Vec::with_capacity(weights.bounds.len());
+    for (var, weight) in &weights.bounds {
+        if unsafe { ffi::SCIPvarIsBinary(var.raw) } != 0 {
+            return Err(ScipError::new(Retcode::InvalidData, "SCIPchgVarLb"));
+        }
+        let name = unsafe { CStr::from_ptr(ffi::SCIPvarGetName(var.raw)) }.to_string_lossy();
+        let lb = unsafe { ffi::SCIPvarGetLbOriginal(var.raw) };
+        let ub = unsafe { ffi::SCIPvarGetUbOriginal(var.raw) };
+        elastic.bounds.push((var.raw, lb, ub));
+        let mut slacks = Vec::new();
+        // x + s >= lb and x - s <= ub
+        for (side, bound, coef) in [("lb", lb, 1.), ("ub", ub, -1.)] {
+            if bound.abs() >= inf {
+                continue;
+            }
+            let name = format!("{name}_{side}");
+            let slack = add_slack(scip, elastic, &name, *weight)?;
+            let (lhs, rhs) = if coef > 0. {
+                (bound, inf)
+            } else {
+                (-inf, bound)
+            };
+            let cons = add_bound_cons(scip, var.raw, slack, coef, lhs, rhs, &name)?;
+            elastic.bound_conss.push(cons);
+            slacks.push(slack);
+        }
+        scip_call!(ffi::SCIPchgVarLb(scip.raw, var.raw, -inf));
+        scip_call!(ffi::SCIPchgVarUb(scip.raw, var.raw, inf));
+        bound_slacks.push((var.clone(), slacks));
+    }
+
+    Ok((cons_slacks, bound_slacks))
+}
+
+fn add_slack(
+    scip: &ScipPtr,
+    elastic: &mut Elastic,
+    name: &str,
+    weight: f64,
+) -> Result<*mut SCIP_VAR, ScipError> {
+    let name = CString::new(format!("russcip_slack_{name}")).unwrap();
+    let mut var = MaybeUninit::uninit();
+    scip_call!(ffi::SCIPcreateVarBasic(
+        scip.raw,
+        var.as_mut_ptr(),
+        name.as_ptr(),
+        0.,
+        ffi::SCIPinfinity(scip.raw),
+        weight,
+        ffi::SCIP_Vartype_SCIP_VARTYPE_CONTINUOUS,
+    ));
+    let var = unsafe { var.assume_init() };
+    unsafe { ffi::SCIPvarMarkDeletable(var) };
+    // the variable stays captured until it is deleted again
+    elastic.slacks.push(var);
+    scip_call!(ffi::SCIPaddVar(scip.raw, var));
+    Ok(var)
+}
+
+fn add_bound_cons(
+    scip: &ScipPtr,
+    var: *mut SCIP_VAR,
+    slack: *mut SCIP_VAR,
+    coef: f64,
+    lhs: f64,
+    rhs: f64,
+    name: &str,
+) -> Result<*mut SCIP_CONS, ScipError> {
+    let name = CString::new(format!("russcip_elastic_{name}")).unwrap();
+    let mut vars = [var, slack];
+    let mut coefs = [1., coef];
+    let mut cons = MaybeUninit::uninit();
+    scip_call!(ffi::SCIPcreateConsBasicLinear(
+        scip.raw,
+        cons.as_mut_ptr(),
+        name.as_ptr(),
+        2,
+        vars.as_mut_ptr(),
+        coefs.as_mut_ptr(),
+        lhs,
+        rhs,
+    ));
+    let mut cons = unsafe { cons.assume_init() };
+    scip_call!(ffi::SCIPaddCons(scip.raw, cons));
+    // the problem holds the constraint until it is deleted
+    let raw = cons;
+    scip_call!(ffi::SCIPreleaseCons(scip.raw, &mut cons));
+    Ok(raw)
+}
+
+fn collect(
+    scip: &Rc<ScipPtr>,
+    elastic: &Elastic,
+    cons_slacks: Slacks<Constraint>,
+    bound_slacks: Slacks<Variable>,
+) -> FeasRelaxation {
+    let status = scip.status();
+    let Some(raw) = scip.best_sol() else {
+        return FeasRelaxation {
+            status,
+            solution: None,
+            total_violation: f64::INFINITY,
+            conss: Vec::new(),
+            bounds: Vec::new(),
+        };
+    };
+    let sol = Solution {
+        raw,
+        scip_ptr: scip.clone(),
+    };
+    let val = |var| unsafe { ffi::SCIPgetSolVal(scip.raw, raw, var) };
+    let violation = |slacks: &[*mut SCIP_VAR]| slacks.iter().map(|&slack| val(slack)).sum();
+
+    let total_violation = sol.obj_val();
+    let mut solution = sol.snapshot();
+    let slack_ids: Vec<_> = elastic
+        .slacks
+        .iter()
+        .map(|&slack| unsafe { ffi::SCIPvarGetIndex(slack) } as usize)
+        .collect();
+    solution
+        .values
+        .retain(|value| !slack_ids.contains(&value.id));
+    let (_, offset) = elastic.objective.unwrap_or_default();
+    solution.obj_val = offset
+        + elastic
+            .obj_coefs
+            .iter()
+            .map(|&(var, obj)| obj * val(var))
+            .sum::<f64>();
+
+    FeasRelaxation {
+        status,
+        solution: Some(solution),
+        total_violation,
+        conss: cons_slacks
+            .into_iter()
+            .map(|(cons, slacks)| ConsRelaxation {
+                cons,
+                violation: violation(&slacks),
+            })
+            .collect(),
+        bounds: bound_slacks
+            .into_iter()
+            .map(|(var, slacks)| BoundRelaxation {
+                var,
+                violation: violation(&slacks),
+            })
+            .collect(),
+    }
+}
+
+impl<'a> Elastic<'a> {
+    fn new(scip: &'a ScipPtr) -> Self {
+        Elastic {
+            scip,
+            objective: None,
+            obj_coefs: Vec::new(),
+            slacks: Vec::new(),
+            coefs: Vec::new(),
+            bounds: Vec::new(),
+            bound_conss: Vec::new(),
+        }
+    }
+
+    /// Frees the transformed problem and undoes the changes in reverse order. Every change is undone even if an
+    /// earlier one fails, the first error is returned.
+    fn restore(&mut self) -> Result<(), ScipError> {
+        let scip = self.scip;
+        let mut first_error = scip.free_transform().err();
+        let mut undo = |step: Result<(), ScipError>| {
+            if let Err(error) = step {
+                first_error.get_or_insert(error);
+            }
+        };
+
+        for cons in self.bound_conss.drain(..).rev() {
+            undo((|| {
+                scip_call!(ffi::SCIPdelCons(scip.raw, cons));
+                Ok(())
+            })());
+        }
+        for (var, lb, ub) in self.bounds.drain(..).rev() {
+            undo((|| {
+                scip_call!(ffi::SCIPchgVarLb(scip.raw, var, lb));
+                scip_call!(ffi::SCIPchgVarUb(scip.raw, var, ub));
+                Ok(())
+            })());
+        }
+        for (cons, slack) in self.coefs.drain(..).rev() {
+            undo((|| {
+                scip_call!(ffi::SCIPdelCoefLinear(scip.raw, cons, slack));
+                Ok(())
+            })());
+        }
+        for mut slack in self.slacks.drain(..).rev() {
+            undo((|| {
+                let mut deleted = 0;
+                scip_call!(ffi::SCIPdelVar(scip.raw, slack, &mut deleted));
+                Ok(())
+            })());
+            undo((|| {
+                scip_call!(ffi::SCIPreleaseVar(scip.raw, &mut slack));
+                Ok(())
+            })());
+        }
+        for (var, obj) in self.obj_coefs.drain(..).rev() {
+            undo((|| {
+                scip_call!(ffi::SCIPchgVarObj(scip.raw, var, obj));
+                Ok(())
+            })());
+        }
+        if let Some((sense, offset)) = self.objective.take() {
+            undo((|| {
+                scip_call!(ffi::SCIPaddOrigObjoffset(scip.raw, offset));
+                scip_call!(ffi::SCIPsetObjsense(scip.raw, sense));
+                Ok(())
+            })());
+        }
+        first_error.map_or(Ok(()), Err)
+    }
+}
+
+impl Drop for Elastic<'_> {
+    fn drop(&mut self) {
+        let _ = self.restore();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::{ModelWithProblem, RelaxationWeights};
+
+    #[test]
+    fn feasibility_relaxation() {
+        // demand c1 cannot be met within the capacity c2 and the bounds of y
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .create_prob("relax")
+            .maximize();
+        let x = model.add_var(0., 10., 1., "x", VarType::Integer);
+        let y = model.add_var(0., 3., 2., "y", VarType::Continuous);
+        let c1 = model.add_cons(vec![&x, &y], &[1., 1.], 8., f64::INFINITY, "demand");
+        let c2 = model.add_cons(vec![&x], &[1.], -f64::INFINITY, 2., "capacity");
+
+        // relaxing the demand is cheaper than relaxing the capacity
+        let weights = RelaxationWeights::new().cons(&c1, 1.).cons(&c2, 10.);
+        let relaxed = model.feasibility_relaxation(weights);
+        assert_eq!(relaxed.status, Status::Optimal);
+        assert_eq!(relaxed.total_violation, 3.);
+        assert_eq!(relaxed.conss[0].violation, 3.);
+        assert_eq!(relaxed.conss[1].violation, 0.);
+        let (conss, bounds) = relaxed.violated();
+        assert_eq!(conss.len(), 1);
+        assert_eq!(conss[0].cons.name(), "demand");
+        assert!(bounds.is_empty());
+        let solution = relaxed.solution.unwrap();
+        assert_eq!(solution.values.len(), 2);
+        assert_eq!(solution.val("x"), Some(2.));
+        assert_eq!(solution.val("y"), Some(3.));
+        assert_eq!(solution.obj_val, 8.);
+
+        // only the bound of y can be relaxed
+        let weights = RelaxationWeights::new().bounds(&y, 2.);
+        let relaxed = model.feasibility_relaxation(weights);
+        assert_eq!(relaxed.total_violation, 6.);
+        assert_eq!(relaxed.bounds[0].violation, 3.);
+
+        // the problem is left unchanged
+        assert_eq!(model.n_vars(), 2);
+        assert_eq!(model.n_conss(), 2);
+        assert_eq!(y.ub(), 3.);
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Infeasible);
+    }
+    #[test]
+    fn relax_same_var_twice() {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .create_prob("relax_twice");
+        let x = model.add_var(0., 10., 1., "x", VarType::Continuous);
+        let y = model.add_var(0., 3., 1., "y", VarType::Continuous);
+        model.add_cons(vec![&x, &y], &[1., 1.], 15., f64::INFINITY, "demand");
+
+        // the second entry sees the bounds already relaxed by the first one
+        let weights = RelaxationWeights::new().bounds(&y, 2.).bounds(&y, 5.);
+        let relaxed = model.feasibility_relaxation(weights);
+        assert_eq!(relaxed.status, Status::Optimal);
+        assert_eq!(relaxed.total_violation, 4.);
+        assert_eq!(relaxed.bounds[0].violation, 2.);
+
+        // the original bounds are restored
+        assert_eq!(model.n_vars(), 2);
+        assert_eq!(model.n_conss(), 1);
+        assert_eq!(y.lb(), 0.);
+        assert_eq!(y.ub(), 3.);
+    }
+}
@@ -0,0 +1,204 @@
+use crate::constraint::Constraint;
+use crate::error::SolveError;
+use crate::model::{Model, ParamSetting, Solved};
+use crate::retcode::{Retcode, ScipError};
+use crate::scip::ScipPtr;
+use crate::solution::Solution;
+use crate::{ffi, scip_call};
+use std::ffi::c_void;
+use std::rc::Rc;
+
+/// The LP obtained by fixing the integer variables of a problem at the values of a solution, returned by
+/// `Model::fixed_lp_duals`.
+#[derive(Debug)]
+pub struct FixedLp {
+    /// The solved copy of the problem with the integer variables fixed.
+    pub model: Model<Solved>,
+    /// The original constraints with their dual value in the fixed LP, i.e. the change of the optimal objective
+    /// value per unit increase of the active side, or `None` if the constraint is not linear or its dual is not
+    /// available.
+    pub duals: Vec<(Constraint, Option<f64>)>,
+}
+
+impl FixedLp {
+    /// Returns the dual value of the given original constraint in the fixed LP.
+    pub fn dual(&self, cons: &Constraint) -> Option<f64> {
+        self.duals
+            .iter()
+            .find(|(c, _)| c.raw == cons.raw)
+            .and_then(|(_, dual)| *dual)
+    }
+}
+
+/// Hashmap from the original problem to its copy, freed on drop.
+struct CopyMap(*mut ffi::SCIP_HASHMAP);
+
+impl CopyMap {
+    fn new(scip: &ScipPtr, size: usize) -> Result<Self, ScipError> {
+        let mut map = std::ptr::null_mut();
+        scip_call!(ffi::SCIPhashmapCreate(
+            &mut map,
+            ffi::SCIPblkmem(scip.raw),
+            size.max(1) as i32
+        ));
+        Ok(CopyMap(map))
+    }
+
+    fn image<T>(&self, origin: *mut T) -> *mut T {
+        unsafe { ffi::SCIPhashmapGetImage(self.0, origin as *mut c_void) as *mut T }
+    }
+}
+
+impl Drop for CopyMap {
+    fn drop(&mut self) {
+        unsafe { ffi::SCIPhashmapFree(&mut self.0) };
+    }
+}
+
+/// Copies the original problem, fixes its integer variables at their values in `sol`, solves the copy without
+/// reductions that invalidate the duals and maps the duals back to the original constraints.
+pub(crate) fn solve(scip: &Rc<ScipPtr>, sol: &Solution) -> Result<FixedLp, SolveError> {
+    let orig_vars = scip.vars(true, false);
+    let orig_conss = scip.orig_conss();
+    let varmap = CopyMap::new(scip, orig_vars.len())?;
+    let consmap = CopyMap::new(scip, orig_conss.len())?;
+
+    let copy = ScipPtr::new()?;
+    let mut valid = 0;
+    scip_call!(ffi::SCIPcopyOrig(
+        scip.raw,
+        copy.raw,
+        varmap.0,
+        consmap.0,
+        c"".as_ptr(),
+        false.into(),
+        false.into(),
+        true.into(),
+        &mut valid,
+    ));
+    if valid == 0 {
+        return Err(ScipError::new(Retcode::InvalidData, "SCIPcopyOrig").into());
+    }
+    // capture vars and cons since they were not created by the user (and SCIP will free them later)
+    copy.vars(false, true);
+    copy.conss(true);
+
+    for &var in orig_vars.values() {
+        let var_type = unsafe { ffi::SCIPvarGetType(var) };
+        if var_type != ffi::SCIP_Vartype_SCIP_VARTYPE_BINARY
+            && var_type != ffi::SCIP_Vartype_SCIP_VARTYPE_INTEGER
+        {
+            continue;
+        }
+        let copy_var = varmap.image(var);
+        if copy_var.is_null() {
+            return Err(ScipError::new(Retcode::InvalidData, "SCIPcopyOrig").into());
+        }
+        let val = unsafe { ffi::SCIPgetSolVal(scip.raw, sol.raw, var) }.round();
+        let mut infeasible = 0;
+        scip_call!(ffi::SCIPchgVarLb(copy.raw, copy_var, val));
+        scip_call!(ffi::SCIPchgVarUb(copy.raw, copy_var, val));
+        scip_call!(ffi::SCIPchgVarType(
+            copy.raw,
+            copy_var,
+            ffi::SCIP_Vartype_SCIP_VARTYPE_CONTINUOUS,
+            &mut infeasible,
+        ));
+    }
+
+    copy.set_presolving(ParamSetting::Off)?;
+    copy.set_heuristics(ParamSetting::Off)?;
+    copy.set_separating(ParamSetting::Off)?;
+    copy.set_int_param("propagating/maxrounds", 0)?;
+    copy.set_int_param("propagating/maxroundsroot", 0)?;
+    copy.set_bool_param("misc/allowstrongdualreds", false)?;
+    copy.set_bool_param("misc/allowweakdualreds", false)?;
+
+    let copy = Rc::new(copy);
+    copy.solve()?;
+
+    // the duals of the transformed problem refer to the minimization of the objective
+    let sense = unsafe { ffi::SCIPgetObjsense(copy.raw) } as f64;
+
+    let duals = orig_conss
+        .into_iter()
+        .map(|raw| {
+            let copy_cons = consmap.image(raw);
+            let dual = (!copy_cons.is_null())
+                .then(|| Constraint {
+                    raw: copy_cons,
+                    scip: copy.clone(),
+                })
+                .and_then(|cons| cons.transformed())
+                .and_then(|cons| cons.dual_sol())
+                .map(|dual| sense * dual);
+            let cons = Constraint {
+                raw,
+                scip: scip.clone(),
+            };
+            (cons, dual)
+        })
+        .collect();
+
+    Ok(FixedLp {
+        model: Model {
+            scip: copy,
+            state: Solved {},
+        },
+        duals,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn fixed_lp_duals() {
+        // the LP relaxation has y = 2.5 and c2 binding, fixing y = 2 leaves only c1 binding
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .create_prob("fixed_lp");
+        let x = model.add_var(0., 10., 3., "x", VarType::Continuous);
+        let y = model.add_var(0., 10., 2., "y", VarType::Integer);
+        let c1 = model.add_cons(vec![&x, &y], &[1., 1.], 3.5, f64::INFINITY, "c1");
+        let c2 = model.add_cons(vec![&y], &[1.], -f64::INFINITY, 2.5, "c2");
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        let sol = solved.best_sol().unwrap();
+        assert_eq!(sol.val(&y), 2.);
+
+        let fixed = solved.fixed_lp_duals(&sol);
+        assert_eq!(fixed.model.status(), Status::Optimal);
+        assert_eq!(fixed.model.obj_val(), 8.5);
+        assert_eq!(fixed.dual(&c1), Some(3.));
+        assert_eq!(fixed.dual(&c2), Some(0.));
+
+        // the original model is left unchanged
+        assert_eq!(solved.obj_val(), 8.5);
+        assert_eq!(y.ub(), 10.);
+    }
+
+    #[test]
+    fn fixed_lp_duals_maximize() {
+        // the same problem as above with the negated objective maximized
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .create_prob("fixed_lp_max")
+            .maximize();
+        let x = model.add_var(0., 10., -3., "x", VarType::Continuous);
+        let y = model.add_var(0., 10., -2., "y", VarType::Integer);
+        let c1 = model.add_cons(vec![&x, &y], &[1., 1.], 3.5, f64::INFINITY, "c1");
+        model.add_cons(vec![&y], &[1.], -f64::INFINITY, 2.5, "c2");
+
+        let solved = model.solve();
+        let sol = solved.best_sol().unwrap();
+        let fixed = solved.fixed_lp_duals(&sol);
+        assert_eq!(fixed.model.obj_val(), -8.5);
+        // raising the demand of c1 lowers the objective
+        assert_eq!(fixed.dual(&c1), Some(-3.));
+    }
+}
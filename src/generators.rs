@@ -0,0 +1,850 @@
+use crate::{LinearBlock, Model, ObjSense, ProblemCreated, SparseMatrix, VarType};
+use rand::rngs::StdRng;
+use rand::seq::index::sample;
+use rand::{Rng, SeedableRng};
+use std::collections::{BTreeSet, HashSet};
+
+/// A generator of random problem instances.
+///
+/// The same parameters and seed always produce the same instance.
+pub trait InstanceGenerator {
+    /// Generates a new instance from the given seed.
+    fn generate(&self, seed: u64) -> Model<ProblemCreated>;
+}
+
+/// Generates set cover instances following Balas & Ho (1980).
+///
+/// Each row has to be covered by at least one selected column, and the total cost of the selected columns is
+/// minimized. Generating panics unless there is at least one row and one column, the density is in `(0, 1]` and
+/// `max_coef` is at least 1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SetCover {
+    /// The number of rows (elements to cover).
+    pub n_rows: usize,
+    /// The number of columns (sets).
+    pub n_cols: usize,
+    /// The fraction of non-zero entries in the constraint matrix.
+    pub density: f64,
+    /// The maximum cost of a column, costs are drawn uniformly from `1..=max_coef`.
+    pub max_coef: u32,
+}
+
+impl Default for SetCover {
+    fn default() -> Self {
+        SetCover {
+            n_rows: 500,
+            n_cols: 1000,
+            density: 0.05,
+            max_coef: 100,
+        }
+    }
+}
+
+impl InstanceGenerator for SetCover {
+    fn generate(&self, seed: u64) -> Model<ProblemCreated> {
+        assert!(
+            self.n_rows > 0 && self.n_cols > 0,
+            "A set cover instance needs at least one row and one column"
+        );
+        assert!(
+            self.density > 0.0 && self.density <= 1.0,
+            "The density has to be in (0, 1]"
+        );
+        assert!(self.max_coef >= 1, "The maximum cost has to be at least 1");
+        let mut rng = StdRng::seed_from_u64(seed);
+        let nnz = (self.n_rows as f64 * self.n_cols as f64 * self.density) as usize;
+
+        // every column covers at least one row, the remaining entries are spread uniformly over the columns
+        let mut col_counts = vec![1; self.n_cols];
+        for _ in self.n_cols..nnz {
+            col_counts[rng.gen_range(0..self.n_cols)] += 1;
+        }
+        let mut row_cols = vec![Vec::new(); self.n_rows];
+        for (col, &count) in col_counts.iter().enumerate() {
+            for row in sample(&mut rng, self.n_rows, count.min(self.n_rows)) {
+                row_cols[row].push(col);
+            }
+        }
+        // every row can be covered by at least one column
+        for cols in row_cols.iter_mut().filter(|cols| cols.is_empty()) {
+            cols.push(rng.gen_range(0..self.n_cols));
+        }
+
+        let obj = (0..self.n_cols)
+            .map(|_| rng.gen_range(1..=self.max_coef) as f64)
+            .collect();
+        let mut rows = Rows::default();
+        for cols in row_cols {
+            rows.push(cols.into_iter().map(|col| (col, 1.0)), 1.0, f64::INFINITY);
+        }
+        let block = rows
+            .into_block(obj)
+            .bounds(vec![0.0; self.n_cols], vec![1.0; self.n_cols])
+            .var_types(vec![VarType::Binary; self.n_cols]);
+        build("setcover", ObjSense::Minimize, &block)
+    }
+}
+
+/// Generates combinatorial auction instances following the "arbitrary relationships" distribution of
+/// Leyton-Brown et al. (2000).
+///
+/// Each bid offers a price for a bundle of items, every item can be sold at most once, and bidders submitting
+/// several (substitutable) bids win at most one of them. The total price of the accepted bids is maximized.
+///
+/// Bids are drawn until `n_bids` distinct bundles were found or `100 * n_bids` bidders were drawn, so with few
+/// items (e.g. a single one) the instance has fewer bids. Generating panics if there are no items.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CombinatorialAuction {
+    /// The number of items.
+    pub n_items: usize,
+    /// The number of bids.
+    pub n_bids: usize,
+    /// The minimum common value of an item.
+    pub min_value: f64,
+    /// The maximum common value of an item.
+    pub max_value: f64,
+    /// The maximum deviation of a bidder's private value from the common value, relative to `max_value`.
+    pub value_deviation: f64,
+    /// The probability of adding another item to a bundle.
+    pub add_item_prob: f64,
+    /// The maximum number of substitutable bids per bidder.
+    pub max_n_sub_bids: usize,
+    /// The additivity of bundle prices, i.e. the superadditive bonus of a bundle of `n` items is `n^(1 + additivity)`.
+    pub additivity: f64,
+    /// The factor by which a substitutable bid may be more expensive than the bidder's first bid.
+    pub budget_factor: f64,
+    /// The minimum resale value of a substitutable bid, relative to the common value of the first bid.
+    pub resale_factor: f64,
+}
+
+impl Default for CombinatorialAuction {
+    fn default() -> Self {
+        CombinatorialAuction {
+            n_items: 100,
+            n_bids: 500,
+            min_value: 1.0,
+            max_value: 100.0,
+            value_deviation: 0.5,
+            add_item_prob: 0.65,
+            max_n_sub_bids: 5,
+            additivity: 0.2,
+            budget_factor: 1.5,
+            resale_factor: 0.5,
+        }
+    }
+}
+
+impl CombinatorialAuction {
+    /// Grows a bundle from the given item, adding items compatible with the bundle weighted by the bidder's interest.
+    fn bundle(
+        &self,
+        rng: &mut StdRng,
+        first: usize,
+        interests: &[f64],
+        compats: &[Vec<f64>],
+        size: Option<usize>,
+    ) -> BTreeSet<usize> {
+        let mut bundle = BTreeSet::from([first]);
+        loop {
+            let done = match size {
+                Some(size) => bundle.len() >= size,
+                None => !rng.gen_bool(self.add_item_prob),
+            };
+            if done || bundle.len() == self.n_items {
+                return bundle;
+            }
+            let weights: Vec<f64> = (0..self.n_items)
+                .map(|item| match bundle.contains(&item) {
+                    true => 0.0,
+                    false => {
+                        interests[item] * bundle.iter().map(|&b| compats[b][item]).sum::<f64>()
+                    }
+                })
+                .collect();
+            bundle.insert(choose_weighted(rng, &weights));
+        }
+    }
+}
+
+impl InstanceGenerator for CombinatorialAuction {
+    fn generate(&self, seed: u64) -> Model<ProblemCreated> {
+        assert!(
+            self.n_items > 0,
+            "A combinatorial auction needs at least one item"
+        );
+        let mut rng = StdRng::seed_from_u64(seed);
+        let n_items = self.n_items;
+        let values: Vec<f64> = (0..n_items)
+            .map(|_| self.min_value + (self.max_value - self.min_value) * rng.r#gen::<f64>())
+            .collect();
+        let upper: Vec<Vec<f64>> = (0..n_items)
+            .map(|i| {
+                (0..n_items)
+                    .map(|j| if j > i { rng.r#gen() } else { 0.0 })
+                    .collect()
+            })
+            .collect();
+        let compats: Vec<Vec<f64>> = (0..n_items)
+            .map(|i| (0..n_items).map(|j| upper[i.min(j)][i.max(j)]).collect())
+            .collect();
+
+        let mut bids: Vec<(BTreeSet<usize>, f64)> = Vec::with_capacity(self.n_bids);
+        let mut seen = HashSet::new();
+        let mut bidders: Vec<Vec<usize>> = Vec::new();
+        let mut attempts = 0;
+        while bids.len() < self.n_bids && attempts < 100 * self.n_bids {
+            attempts += 1;
+            let interests: Vec<f64> = (0..n_items).map(|_| rng.r#gen::<f64>()).collect();
+            let private_values: Vec<f64> = values
+                .iter()
+                .zip(&interests)
+                .map(|(value, interest)| {
+                    value + self.max_value * self.value_deviation * (2.0 * interest - 1.0)
+                })
+                .collect();
+            let price_of = |bundle: &BTreeSet<usize>| {
+                bundle.iter().map(|&i| private_values[i]).sum::<f64>()
+                    + (bundle.len() as f64).powf(1.0 + self.additivity)
+            };
+
+            let first = choose_weighted(&mut rng, &interests);
+            let bundle = self.bundle(&mut rng, first, &interests, &compats, None);
+            let price = price_of(&bundle);
+            if price <= 0.0 || seen.contains(&bundle) {
+                continue;
+            }
+
+            // substitutable bids of the same size, each grown from another item of the first bundle
+            let budget = self.budget_factor * price;
+            let min_resale = self.resale_factor * bundle.iter().map(|&i| values[i]).sum::<f64>();
+            let mut subs: Vec<(BTreeSet<usize>, f64)> = Vec::new();
+            for &item in bundle.iter().filter(|&&item| item != first) {
+                let sub = self.bundle(&mut rng, item, &interests, &compats, Some(bundle.len()));
+                let sub_price = price_of(&sub);
+                let resale = sub.iter().map(|&i| values[i]).sum::<f64>();
+                if sub != bundle
+                    && sub_price > 0.0
+                    && sub_price <= budget
+                    && resale >= min_resale
+                    && !seen.contains(&sub)
+                    && subs.iter().all(|(other, _)| other != &sub)
+                {
+                    subs.push((sub, sub_price));
+                }
+            }
+            subs.sort_by(|a, b| b.1.total_cmp(&a.1));
+            subs.truncate(self.max_n_sub_bids.min(self.n_bids - bids.len() - 1));
+
+            let mut bidder = Vec::with_capacity(subs.len() + 1);
+            for (bundle, price) in std::iter::once((bundle, price)).chain(subs) {
+                bidder.push(bids.len());
+                seen.insert(bundle.clone());
+                bids.push((bundle, price));
+            }
+            bidders.push(bidder);
+        }
+
+        let mut item_bids = vec![Vec::new(); n_items];
+        for (bid, (bundle, _)) in bids.iter().enumerate() {
+            for &item in bundle {
+                item_bids[item].push(bid);
+            }
+        }
+        let mut rows = Rows::default();
+        for bids in item_bids.into_iter().filter(|bids| !bids.is_empty()) {
+            rows.push(bids.into_iter().map(|bid| (bid, 1.0)), -f64::INFINITY, 1.0);
+        }
+        for bidder in bidders.into_iter().filter(|bidder| bidder.len() > 1) {
+            rows.push(
+                bidder.into_iter().map(|bid| (bid, 1.0)),
+                -f64::INFINITY,
+                1.0,
+            );
+        }
+
+        let n_bids = bids.len();
+        let obj = bids.into_iter().map(|(_, price)| price).collect();
+        let block = rows
+            .into_block(obj)
+            .bounds(vec![0.0; n_bids], vec![1.0; n_bids])
+            .var_types(vec![VarType::Binary; n_bids]);
+        build("auction", ObjSense::Maximize, &block)
+    }
+}
+
+/// Generates capacitated facility location instances following Cornuejols et al. (1991).
+///
+/// Every customer's demand has to be served by open facilities within their capacities, and the sum of the fixed
+/// opening costs and the transportation costs is minimized. Generating panics if there are no facilities or the
+/// ratio is not positive.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CapacitatedFacilityLocation {
+    /// The number of customers.
+    pub n_customers: usize,
+    /// The number of facilities.
+    pub n_facilities: usize,
+    /// The ratio of the total capacity to the total demand.
+    pub ratio: f64,
+    /// Whether customers can be served by several facilities (continuous assignment variables) or by one only.
+    pub continuous_assignment: bool,
+}
+
+impl Default for CapacitatedFacilityLocation {
+    fn default() -> Self {
+        CapacitatedFacilityLocation {
+            n_customers: 100,
+            n_facilities: 100,
+            ratio: 5.0,
+            continuous_assignment: true,
+        }
+    }
+}
+
+impl InstanceGenerator for CapacitatedFacilityLocation {
+    fn generate(&self, seed: u64) -> Model<ProblemCreated> {
+        assert!(
+            self.n_facilities > 0,
+            "A facility location instance needs at least one facility"
+        );
+        assert!(self.ratio > 0.0, "The capacity ratio has to be positive");
+        let mut rng = StdRng::seed_from_u64(seed);
+        let (n_customers, n_facilities) = (self.n_customers, self.n_facilities);
+        let customers: Vec<(f64, f64)> = (0..n_customers)
+            .map(|_| (rng.r#gen(), rng.r#gen()))
+            .collect();
+        let facilities: Vec<(f64, f64)> = (0..n_facilities)
+            .map(|_| (rng.r#gen(), rng.r#gen()))
+            .collect();
+        let demands: Vec<f64> = (0..n_customers)
+            .map(|_| rng.gen_range(5..=35) as f64)
+            .collect();
+        let mut capacities: Vec<f64> = (0..n_facilities)
+            .map(|_| rng.gen_range(10..=160) as f64)
+            .collect();
+        let fixed_costs: Vec<f64> = capacities
+            .iter()
+            .map(|cap| {
+                (rng.gen_range(100..=110) as f64 * cap.sqrt() + rng.gen_range(0..=90) as f64)
+                    .floor()
+            })
+            .collect();
+
+        let total_demand: f64 = demands.iter().sum();
+        let total_capacity: f64 = capacities.iter().sum();
+        for cap in capacities.iter_mut() {
+            *cap = (*cap * self.ratio * total_demand / total_capacity).floor();
+        }
+
+        // variables are the assignments x_ij (customer-major) followed by the openings y_j
+        let x = |i: usize, j: usize| i * n_facilities + j;
+        let y = |j: usize| n_customers * n_facilities + j;
+        let mut obj = Vec::with_capacity(y(n_facilities));
+        for (i, (cx, cy)) in customers.iter().enumerate() {
+            for (fx, fy) in &facilities {
+                let dist = ((cx - fx).powi(2) + (cy - fy).powi(2)).sqrt();
+                obj.push(dist * 10.0 * demands[i]);
+            }
+        }
+        obj.extend_from_slice(&fixed_costs);
+
+        let mut rows = Rows::default();
+        for i in 0..n_customers {
+            rows.push(
+                (0..n_facilities).map(|j| (x(i, j), 1.0)),
+                1.0,
+                f64::INFINITY,
+            );
+        }
+        for (j, &capacity) in capacities.iter().enumerate() {
+            let serve = (0..n_customers).map(|i| (x(i, j), demands[i]));
+            rows.push(serve.chain([(y(j), -capacity)]), -f64::INFINITY, 0.0);
+        }
+        rows.push(
+            (0..n_facilities).map(|j| (y(j), capacities[j])),
+            total_demand,
+            f64::INFINITY,
+        );
+        for i in 0..n_customers {
+            for j in 0..n_facilities {
+                rows.push([(x(i, j), 1.0), (y(j), -1.0)], -f64::INFINITY, 0.0);
+            }
+        }
+
+        let n_vars = obj.len();
+        let assignment_type = match self.continuous_assignment {
+            true => VarType::Continuous,
+            false => VarType::Binary,
+        };
+        let mut var_types = vec![assignment_type; n_customers * n_facilities];
+        var_types.resize(n_vars, VarType::Binary);
+        let block = rows
+            .into_block(obj)
+            .bounds(vec![0.0; n_vars], vec![1.0; n_vars])
+            .var_types(var_types);
+        build("facilities", ObjSense::Minimize, &block)
+    }
+}
+
+/// The random graph model used by `IndependentSet`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GraphType {
+    /// Barabási-Albert preferential attachment, every new node is connected to `affinity` existing nodes.
+    BarabasiAlbert {
+        /// The number of edges added with every new node.
+        affinity: usize,
+    },
+    /// Erdős-Rényi, every edge exists independently with the given probability.
+    ErdosRenyi {
+        /// The probability of an edge.
+        edge_probability: f64,
+    },
+}
+
+/// Generates maximum independent set instances on random graphs.
+///
+/// As in Bergman et al. (2016), the edge constraints are strengthened by a greedy clique partition of the graph.
+/// Generating panics if the affinity of a Barabási-Albert graph is not between 1 and the number of nodes, or the
+/// edge probability of an Erdős-Rényi graph is not in `[0, 1]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndependentSet {
+    /// The number of nodes of the graph.
+    pub n_nodes: usize,
+    /// The random graph model.
+    pub graph_type: GraphType,
+}
+
+impl Default for IndependentSet {
+    fn default() -> Self {
+        IndependentSet {
+            n_nodes: 500,
+            graph_type: GraphType::BarabasiAlbert { affinity: 4 },
+        }
+    }
+}
+
+impl IndependentSet {
+    fn graph(&self, rng: &mut StdRng) -> Vec<BTreeSet<usize>> {
+        let n = self.n_nodes;
+        let mut neighbors = vec![BTreeSet::new(); n];
+        match self.graph_type {
+            GraphType::ErdosRenyi { edge_probability } => {
+                assert!(
+                    (0.0..=1.0).contains(&edge_probability),
+                    "The edge probability has to be in [0, 1]"
+                );
+                for u in 0..n {
+                    for v in u + 1..n {
+                        if rng.gen_bool(edge_probability) {
+                            neighbors[u].insert(v);
+                            neighbors[v].insert(u);
+                        }
+                    }
+                }
+            }
+            GraphType::BarabasiAlbert { affinity } => {
+                assert!(
+                    affinity >= 1 && affinity < n,
+                    "The affinity has to be between 1 and the number of nodes"
+                );
+                // the first new node is connected to all initial nodes
+                for u in 0..affinity {
+                    neighbors[u].insert(affinity);
+                    neighbors[affinity].insert(u);
+                }
+                for v in affinity + 1..n {
+                    let degrees: Vec<f64> = neighbors[..v].iter().map(|n| n.len() as f64).collect();
+                    let mut targets = BTreeSet::new();
+                    while targets.len() < affinity {
+                        targets.insert(choose_weighted(rng, &degrees));
+                    }
+                    for u in targets {
+                        neighbors[u].insert(v);
+                        neighbors[v].insert(u);
+                    }
+                }
+            }
+        }
+        neighbors
+    }
+}
+
+impl InstanceGenerator for IndependentSet {
+    fn generate(&self, seed: u64) -> Model<ProblemCreated> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let neighbors = self.graph(&mut rng);
+        let degree = |u: usize| neighbors[u].len();
+
+        // greedy clique partition, starting every clique from the remaining node of highest degree
+        let mut leftover: BTreeSet<usize> = (0..self.n_nodes).collect();
+        let mut cliques = Vec::new();
+        while let Some(center) = leftover.iter().copied().max_by_key(|&u| (degree(u), u)) {
+            let mut candidates: Vec<usize> =
+                neighbors[center].intersection(&leftover).copied().collect();
+            candidates.sort_by_key(|&u| std::cmp::Reverse(degree(u)));
+            let mut clique = vec![center];
+            for u in candidates {
+                if clique.iter().all(|c| neighbors[u].contains(c)) {
+                    clique.push(u);
+                }
+            }
+            for u in &clique {
+                leftover.remove(u);
+            }
+            cliques.push(clique);
+        }
+
+        let mut clique_of = vec![0; self.n_nodes];
+        for (c, clique) in cliques.iter().enumerate() {
+            for &u in clique {
+                clique_of[u] = c;
+            }
+        }
+        let mut rows = Rows::default();
+        for clique in cliques.into_iter().filter(|clique| clique.len() > 1) {
+            rows.push(clique.into_iter().map(|u| (u, 1.0)), -f64::INFINITY, 1.0);
+        }
+        // edges not covered by a clique
+        for (u, adjacent) in neighbors.iter().enumerate() {
+            for &v in adjacent.range(u + 1..) {
+                if clique_of[u] != clique_of[v] {
+                    rows.push([(u, 1.0), (v, 1.0)], -f64::INFINITY, 1.0);
+                }
+            }
+        }
+
+        let n = self.n_nodes;
+        let block = rows
+            .into_block(vec![1.0; n])
+            .bounds(vec![0.0; n], vec![1.0; n])
+            .var_types(vec![VarType::Binary; n]);
+        build("indset", ObjSense::Maximize, &block)
+    }
+}
+
+/// Generates (multiple) knapsack instances with weakly correlated profits, following Fukunaga & Korf (2007).
+///
+/// Every item can be packed into at most one knapsack, and the total profit of the packed items is maximized.
+/// Generating panics if there are no knapsacks or `min_weight` exceeds `max_weight`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Knapsack {
+    /// The number of items.
+    pub n_items: usize,
+    /// The number of knapsacks.
+    pub n_knapsacks: usize,
+    /// The minimum weight of an item.
+    pub min_weight: u32,
+    /// The maximum weight of an item.
+    pub max_weight: u32,
+}
+
+impl Default for Knapsack {
+    fn default() -> Self {
+        Knapsack {
+            n_items: 100,
+            n_knapsacks: 1,
+            min_weight: 10,
+            max_weight: 100,
+        }
+    }
+}
+
+impl InstanceGenerator for Knapsack {
+    fn generate(&self, seed: u64) -> Model<ProblemCreated> {
+        assert!(
+            self.n_knapsacks > 0,
+            "A knapsack instance needs at least one knapsack"
+        );
+        assert!(
+            self.min_weight <= self.max_weight,
+            "The minimum weight cannot exceed the maximum weight"
+        );
+        let mut rng = StdRng::seed_from_u64(seed);
+        let (n_items, n_knapsacks) = (self.n_items, self.n_knapsacks);
+        let spread = (self.max_weight / 10) as i64;
+        let weights: Vec<f64> = (0..n_items)
+            .map(|_| rng.gen_range(self.min_weight..=self.max_weight) as f64)
+            .collect();
+        let profits: Vec<f64> = weights
+            .iter()
+            .map(|&w| (w as i64 + rng.gen_range(-spread..=spread)).max(1) as f64)
+            .collect();
+        let total_weight: f64 = weights.iter().sum();
+        let capacities: Vec<f64> = (0..n_knapsacks)
+            .map(|_| (rng.gen_range(0.4..0.6) * total_weight / n_knapsacks as f64).floor())
+            .collect();
+
+        // variables are the assignments x_ik (item-major)
+        let x = |i: usize, k: usize| i * n_knapsacks + k;
+        let mut rows = Rows::default();
+        if n_knapsacks > 1 {
+            for i in 0..n_items {
+                rows.push(
+                    (0..n_knapsacks).map(|k| (x(i, k), 1.0)),
+                    -f64::INFINITY,
+                    1.0,
+                );
+            }
+        }
+        for (k, &capacity) in capacities.iter().enumerate() {
+            rows.push(
+                (0..n_items).map(|i| (x(i, k), weights[i])),
+                -f64::INFINITY,
+                capacity,
+            );
+        }
+
+        let n_vars = n_items * n_knapsacks;
+        let obj = profits
+            .iter()
+            .flat_map(|&p| std::iter::repeat_n(p, n_knapsacks))
+            .collect();
+        let block = rows
+            .into_block(obj)
+            .bounds(vec![0.0; n_vars], vec![1.0; n_vars])
+            .var_types(vec![VarType::Binary; n_vars]);
+        build("knapsack", ObjSense::Maximize, &block)
+    }
+}
+
+/// Collects the rows of a generated constraint matrix in compressed sparse row format.
+struct Rows {
+    indptr: Vec<usize>,
+    indices: Vec<usize>,
+    values: Vec<f64>,
+    lhs: Vec<f64>,
+    rhs: Vec<f64>,
+}
+
+impl Default for Rows {
+    fn default() -> Self {
+        Rows {
+            indptr: vec![0],
+            indices: Vec::new(),
+            values: Vec::new(),
+            lhs: Vec::new(),
+            rhs: Vec::new(),
+        }
+    }
+}
+
+impl Rows {
+    fn push(&mut self, entries: impl IntoIterator<Item = (usize, f64)>, lhs: f64, rhs: f64) {
+        for (col, val) in entries {
+            self.indices.push(col);
+            self.values.push(val);
+        }
+        self.indptr.push(self.indices.len());
+        self.lhs.push(lhs);
+        self.rhs.push(rhs);
+    }
+
+    fn into_block(self, obj: Vec<f64>) -> LinearBlock {
+        let n_rows = self.lhs.len();
+        let matrix = SparseMatrix::csr(n_rows, obj.len(), self.indptr, self.indices, self.values);
+        LinearBlock::new(obj, matrix).lhs(self.lhs).rhs(self.rhs)
+    }
+}
+
+fn build(name: &str, sense: ObjSense, block: &LinearBlock) -> Model<ProblemCreated> {
+    let mut model = Model::new()
+        .include_default_plugins()
+        .create_prob(name)
+        .set_obj_sense(sense);
+    model.add_linear_block(block);
+    model
+}
+
+/// Draws an index with probability proportional to its (non-negative) weight, uniformly if all weights are zero.
+fn choose_weighted(rng: &mut StdRng, weights: &[f64]) -> usize {
+    let total: f64 = weights.iter().sum();
+    if total <= 0.0 {
+        return rng.gen_range(0..weights.len());
+    }
+    let mut target = rng.r#gen::<f64>() * total;
+    for (i, &w) in weights.iter().enumerate() {
+        if target < w {
+            return i;
+        }
+        target -= w;
+    }
+    weights.iter().rposition(|&w| w > 0.0).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ModelWithProblem, Status};
+
+    fn solve(model: Model<ProblemCreated>) -> Model<crate::Solved> {
+        let solved = model.hide_output().solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        solved
+    }
+
+    #[test]
+    fn set_cover() {
+        let generator = SetCover {
+            n_rows: 50,
+            n_cols: 100,
+            ..SetCover::default()
+        };
+        let model = generator.generate(0);
+        assert_eq!(model.n_vars(), 100);
+        assert_eq!(model.n_conss(), 50);
+
+        let matrices = model.to_matrices().unwrap();
+        assert!(
+            matrices
+                .block
+                .obj
+                .iter()
+                .all(|&c| (1.0..=100.0).contains(&c))
+        );
+        assert_eq!(
+            matrices.block,
+            generator.generate(0).to_matrices().unwrap().block
+        );
+        assert_ne!(
+            matrices.block,
+            generator.generate(1).to_matrices().unwrap().block
+        );
+
+        let solved = solve(model);
+        assert!(solved.obj_val() > 0.0);
+    }
+
+    #[test]
+    fn combinatorial_auction() {
+        let generator = CombinatorialAuction {
+            n_items: 20,
+            n_bids: 50,
+            ..CombinatorialAuction::default()
+        };
+        let model = generator.generate(42);
+        assert_eq!(model.n_vars(), 50);
+        assert!(model.n_conss() > 0);
+        assert_eq!(
+            model.to_matrices().unwrap().block,
+            generator.generate(42).to_matrices().unwrap().block
+        );
+        let solved = solve(model);
+        assert!(solved.obj_val() > 0.0);
+
+        // a single item allows a single distinct bundle
+        let generator = CombinatorialAuction {
+            n_items: 1,
+            n_bids: 5,
+            ..CombinatorialAuction::default()
+        };
+        assert_eq!(generator.generate(0).n_vars(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one row and one column")]
+    fn set_cover_without_cols() {
+        let generator = SetCover {
+            n_cols: 0,
+            ..SetCover::default()
+        };
+        generator.generate(0);
+    }
+
+    #[test]
+    fn capacitated_facility_location() {
+        let generator = CapacitatedFacilityLocation {
+            n_customers: 10,
+            n_facilities: 5,
+            ..CapacitatedFacilityLocation::default()
+        };
+        let model = generator.generate(7);
+        assert_eq!(model.n_vars(), 10 * 5 + 5);
+        assert_eq!(model.n_conss(), 10 + 5 + 1 + 10 * 5);
+        solve(model);
+
+        let binary = CapacitatedFacilityLocation {
+            continuous_assignment: false,
+            ..generator
+        };
+        let model = binary.generate(7);
+        assert!(model.vars().iter().all(|v| v.var_type() == VarType::Binary));
+        solve(model);
+    }
+
+    #[test]
+    fn independent_set() {
+        let generator = IndependentSet {
+            n_nodes: 60,
+            ..IndependentSet::default()
+        };
+        let solved = solve(generator.generate(3));
+        assert!(solved.obj_val() >= 1.0);
+
+        let erdos_renyi = IndependentSet {
+            n_nodes: 40,
+            graph_type: GraphType::ErdosRenyi {
+                edge_probability: 0.2,
+            },
+        };
+        let solved = solve(erdos_renyi.generate(3));
+        assert!(solved.obj_val() >= 1.0);
+    }
+
+    #[test]
+    fn knapsack() {
+        let generator = Knapsack {
+            n_items: 30,
+            n_knapsacks: 3,
+            ..Knapsack::default()
+        };
+        let model = generator.generate(5);
+        assert_eq!(model.n_vars(), 90);
+        assert_eq!(model.n_conss(), 30 + 3);
+        let solved = solve(model);
+        assert!(solved.obj_val() > 0.0);
+
+        let single = Knapsack::default().generate(5);
+        assert_eq!(single.n_conss(), 1);
+    }
+    #[test]
+    #[should_panic(expected = "at least one facility")]
+    fn facility_location_without_facilities() {
+        let generator = CapacitatedFacilityLocation {
+            n_facilities: 0,
+            ..CapacitatedFacilityLocation::default()
+        };
+        generator.generate(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "edge probability has to be in [0, 1]")]
+    fn independent_set_invalid_probability() {
+        let generator = IndependentSet {
+            n_nodes: 10,
+            graph_type: GraphType::ErdosRenyi {
+                edge_probability: 1.5,
+            },
+        };
+        generator.generate(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one knapsack")]
+    fn knapsack_without_knapsacks() {
+        let generator = Knapsack {
+            n_knapsacks: 0,
+            ..Knapsack::default()
+        };
+        generator.generate(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "minimum weight cannot exceed the maximum weight")]
+    fn knapsack_invalid_weights() {
+        let generator = Knapsack {
+            min_weight: 50,
+            max_weight: 10,
+            ..Knapsack::default()
+        };
+        generator.generate(0);
+    }
+}
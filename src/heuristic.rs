@@ -1,7 +1,8 @@
 use scip_sys::SCIP_Result;
 use std::ops::{BitOr, BitOrAssign};
 
-use crate::{Model, Solving, ffi};
+use crate::error::Infallible;
+use crate::{Model, PluginError, Solving, ffi};
 
 /// A trait for defining custom primal heuristics.
 pub trait Heuristic {
@@ -21,6 +22,42 @@ pub trait Heuristic {
     fn execute(&mut self, model: Model<Solving>, timing: HeurTiming, node_inf: bool) -> HeurResult;
 }
 
+/// A fallible variant of `Heuristic`, for heuristics that can fail (e.g. because they call external code).
+///
+/// Every `Heuristic` is a `TryHeuristic` that never fails. An error aborts the solve and is returned by
+/// `Model::try_solve`.
+pub trait TryHeuristic {
+    /// Executes the heuristic, see `Heuristic::execute`.
+    fn try_execute(
+        &mut self,
+        model: Model<Solving>,
+        timing: HeurTiming,
+        node_inf: bool,
+    ) -> Result<HeurResult, PluginError>;
+}
+
+impl<H: Heuristic + ?Sized> TryHeuristic for H {
+    fn try_execute(
+        &mut self,
+        model: Model<Solving>,
+        timing: HeurTiming,
+        node_inf: bool,
+    ) -> Result<HeurResult, PluginError> {
+        Ok(self.execute(model, timing, node_inf))
+    }
+}
+
+impl TryHeuristic for Infallible<dyn Heuristic> {
+    fn try_execute(
+        &mut self,
+        model: Model<Solving>,
+        timing: HeurTiming,
+        node_inf: bool,
+    ) -> Result<HeurResult, PluginError> {
+        Ok(self.0.execute(model, timing, node_inf))
+    }
+}
+
 /// The result of a primal heuristic execution.
 #[derive(Debug, PartialEq, Eq)]
 pub enum HeurResult {
@@ -104,7 +141,7 @@ impl From<HeurResult> for SCIP_Result {
 mod tests {
     use super::*;
     use crate::prelude::heur;
-    use crate::{Model, ModelWithProblem, ProblemOrSolving};
+    use crate::{Model, ModelWithProblem, ProblemOrSolving, SolveError};
 
     struct NoSolutionFoundHeur;
 
@@ -139,6 +176,46 @@ mod tests {
         model.solve();
     }
 
+    #[test]
+    fn include_boxed_heurs() {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap();
+
+        let infallible: Box<dyn Heuristic> = Box::new(NoSolutionFoundHeur);
+        model.include_heur(
+            "infallible",
+            "",
+            1,
+            'i',
+            1,
+            0,
+            -1,
+            HeurTiming::BEFORE_NODE,
+            false,
+            infallible,
+        );
+        let fallible: Box<dyn TryHeuristic> = Box::new(FailingHeur);
+        model.include_try_heur(
+            "fallible",
+            "",
+            0,
+            'f',
+            1,
+            0,
+            -1,
+            HeurTiming::BEFORE_NODE,
+            false,
+            fallible,
+        );
+        assert!(matches!(
+            model.try_solve(),
+            Err(SolveError::Plugin { plugin, .. }) if plugin == "fallible"
+        ));
+    }
+
     struct ImpostorHeur;
 
     impl Heuristic for ImpostorHeur {
@@ -171,6 +248,74 @@ mod tests {
         model.solve();
     }
 
+    struct PanickingHeur;
+
+    impl Heuristic for PanickingHeur {
+        fn execute(
+            &mut self,
+            _model: Model<Solving>,
+            _timing: HeurTiming,
+            _node_inf: bool,
+        ) -> HeurResult {
+            panic!("heuristic failed");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "heuristic failed")]
+    fn panicking_heur() {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap();
+
+        model.add(
+            heur(PanickingHeur)
+                .name("panicking_heur")
+                .timing(HeurTiming::BEFORE_NODE),
+        );
+        model.solve();
+    }
+
+    struct FailingHeur;
+
+    impl TryHeuristic for FailingHeur {
+        fn try_execute(
+            &mut self,
+            _model: Model<Solving>,
+            _timing: HeurTiming,
+            _node_inf: bool,
+        ) -> Result<HeurResult, PluginError> {
+            Err("external solver unavailable".into())
+        }
+    }
+
+    #[test]
+    fn failing_heur() {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap();
+
+        model.add(
+            heur(FailingHeur)
+                .name("failing_heur")
+                .timing(HeurTiming::BEFORE_NODE),
+        );
+        match model.try_solve() {
+            Err(SolveError::Plugin { plugin, error }) => {
+                assert_eq!(plugin, "failing_heur");
+                assert_eq!(error.to_string(), "external solver unavailable");
+            }
+            other => panic!(
+                "expected a plugin error, got {:?}",
+                other.map(|m| m.status())
+            ),
+        }
+    }
+
     struct DelayedHeur;
 
     impl Heuristic for DelayedHeur {
@@ -0,0 +1,172 @@
+use crate::ffi;
+use crate::scip::ScipPtr;
+use crate::solution::{SolType, Solution};
+use std::ffi::CStr;
+use std::rc::Rc;
+
+/// The objective value and discovery metadata of a stored solution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolutionRecord {
+    /// The objective value of the solution.
+    pub obj_val: f64,
+    /// The name of the heuristic that found the solution, if any.
+    pub heur: Option<String>,
+    /// The kind of plugin or method that found the solution.
+    pub sol_type: SolType,
+    /// The solving time in seconds at which the solution was found.
+    pub time: f64,
+    /// The number of the node at which the solution was found.
+    pub node_number: usize,
+    /// The depth of the node at which the solution was found, if it was found in the tree.
+    pub depth: Option<usize>,
+    /// The run in which the solution was found.
+    pub run: usize,
+}
+
+impl From<&Solution> for SolutionRecord {
+    fn from(sol: &Solution) -> Self {
+        SolutionRecord {
+            obj_val: sol.obj_val(),
+            heur: sol.heur_name(),
+            sol_type: sol.sol_type(),
+            time: sol.time(),
+            node_number: sol.node_number(),
+            depth: sol.depth(),
+            run: sol.run(),
+        }
+    }
+}
+
+/// The statistics of a primal heuristic.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeurStats {
+    /// The name of the heuristic.
+    pub name: String,
+    /// The number of times the heuristic was called.
+    pub calls: usize,
+    /// The time spent in the heuristic in seconds.
+    pub time: f64,
+    /// The number of solutions found by the heuristic.
+    pub sols_found: usize,
+    /// The number of new best solutions found by the heuristic.
+    pub best_sols_found: usize,
+}
+
+/// The solutions found during a solve and the heuristics that found them, returned by
+/// `WithSolvingStats::solution_history`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolutionHistory {
+    /// The solutions in the solution storage, ordered by the time they were found. Solutions that were discarded
+    /// because the storage was full (see `limits/maxsol`) are not included.
+    pub solutions: Vec<SolutionRecord>,
+    /// The heuristics that were called, ordered by priority.
+    pub heuristics: Vec<HeurStats>,
+}
+
+impl SolutionHistory {
+    /// Collects the solution history of the given SCIP instance.
+    pub(crate) fn collect(scip: &Rc<ScipPtr>) -> Self {
+        let mut solutions: Vec<SolutionRecord> = scip
+            .get_sols()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|raw| {
+                SolutionRecord::from(&Solution {
+                    raw,
+                    scip_ptr: scip.clone(),
+                })
+            })
+            .collect();
+        solutions.sort_by(|a, b| a.time.total_cmp(&b.time));
+
+        let n_heurs = unsafe { ffi::SCIPgetNHeurs(scip.raw) } as usize;
+        let heurs = unsafe { ffi::SCIPgetHeurs(scip.raw) };
+        let mut heuristics = Vec::new();
+        for i in 0..n_heurs {
+            let heur = unsafe { *heurs.add(i) };
+            let calls = unsafe { ffi::SCIPheurGetNCalls(heur) } as usize;
+            if calls == 0 {
+                continue;
+            }
+            heuristics.push(HeurStats {
+                name: unsafe { CStr::from_ptr(ffi::SCIPheurGetName(heur)) }
+                    .to_string_lossy()
+                    .into_owned(),
+                calls,
+                time: unsafe { ffi::SCIPheurGetTime(heur) },
+                sols_found: unsafe { ffi::SCIPheurGetNSolsFound(heur) } as usize,
+                best_sols_found: unsafe { ffi::SCIPheurGetNBestSolsFound(heur) } as usize,
+            });
+        }
+
+        SolutionHistory {
+            solutions,
+            heuristics,
+        }
+    }
+
+    /// Returns the statistics of the heuristic with the given name, if it was called.
+    pub fn heur(&self, name: &str) -> Option<&HeurStats> {
+        self.heuristics.iter().find(|stats| stats.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::{HeurResult, HeurTiming, Heuristic, SolType, Solving};
+
+    struct TrivialHeur;
+
+    impl Heuristic for TrivialHeur {
+        fn execute(
+            &mut self,
+            model: Model<Solving>,
+            _timing: HeurTiming,
+            _node_inf: bool,
+        ) -> HeurResult {
+            // all variables at zero is feasible for simple.lp
+            let sol = model.create_sol();
+            match model.add_sol(sol) {
+                Ok(()) => HeurResult::FoundSol,
+                Err(_) => HeurResult::NoSolFound,
+            }
+        }
+    }
+
+    #[test]
+    fn solution_history() {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap()
+            .set_presolving(ParamSetting::Off)
+            .set_heuristics(ParamSetting::Off);
+        model.add(
+            heur(TrivialHeur)
+                .name("trivial_rust")
+                .timing(HeurTiming::BEFORE_NODE),
+        );
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+
+        let sols = solved.get_sols().unwrap();
+        let trivial = sols
+            .iter()
+            .find(|sol| sol.heur_name().as_deref() == Some("trivial_rust"))
+            .unwrap();
+        assert_eq!(trivial.sol_type(), SolType::Heuristic);
+        assert_eq!(trivial.obj_val(), 0.);
+        assert_eq!(trivial.run(), 1);
+        assert_eq!(trivial.depth(), Some(0));
+
+        let history = solved.solution_history();
+        assert_eq!(history.solutions.len(), sols.len());
+        assert!(history.solutions.windows(2).all(|w| w[0].time <= w[1].time));
+        let stats = history.heur("trivial_rust").unwrap();
+        assert!(stats.calls > 0);
+        assert!(stats.sols_found > 0);
+        assert!(stats.best_sols_found > 0);
+    }
+}
@@ -0,0 +1,183 @@
+use crate::constraint::Constraint;
+use crate::retcode::{Retcode, ScipError};
+use crate::scip::ScipPtr;
+use crate::variable::Variable;
+use crate::{ffi, scip_call};
+use std::collections::HashMap;
+use std::ffi::{CStr, c_char};
+use std::rc::Rc;
+
+/// The bounds of a variable that are part of an irreducible infeasible subsystem.
+#[derive(Debug, Clone)]
+pub struct IisBound {
+    /// The original variable.
+    pub var: Variable,
+    /// The lower bound of the variable, if it is part of the subsystem.
+    pub lb: Option<f64>,
+    /// The upper bound of the variable, if it is part of the subsystem.
+    pub ub: Option<f64>,
+}
+
+/// An infeasible subsystem of the original problem, returned by `Model::compute_iis`.
+#[derive(Debug, Clone)]
+pub struct Iis {
+    /// The original constraints in the subsystem.
+    pub conss: Vec<Constraint>,
+    /// The variables with at least one bound in the subsystem.
+    pub bounds: Vec<IisBound>,
+    /// Whether the subsystem is irreducible, i.e. removing any constraint or bound makes it feasible. If the
+    /// IIS finder hit a limit, the subsystem is infeasible but possibly not minimal.
+    pub irreducible: bool,
+    /// The time spent computing the subsystem in seconds.
+    pub time: f64,
+}
+
+impl Iis {
+    /// Returns whether the given constraint is part of the subsystem.
+    pub fn contains_cons(&self, cons: &Constraint) -> bool {
+        self.conss.iter().any(|c| c.raw == cons.raw)
+    }
+}
+
+/// Returns the items of a SCIP array as a slice.
+fn scip_slice<'a, T>(items: *mut T, n: std::os::raw::c_int) -> &'a [T] {
+    if n == 0 {
+        return &[];
+    }
+    unsafe { std::slice::from_raw_parts(items, n as usize) }
+}
+
+/// Maps the names of the given items to the items, with `None` for names shared by several items.
+fn by_name<T: Copy>(items: &[T], name: impl Fn(T) -> *const c_char) -> HashMap<&CStr, Option<T>> {
+    let mut map = HashMap::with_capacity(items.len());
+    for &item in items {
+        map.entry(unsafe { CStr::from_ptr(name(item)) })
+            .and_modify(|found: &mut Option<T>| *found = None)
+            .or_insert(Some(item));
+    }
+    map
+}
+
+/// Returns the original item with the given name, or an error if no item or several items have that name.
+fn resolve<T: Copy>(orig: &HashMap<&CStr, Option<T>>, name: *const c_char) -> Result<T, ScipError> {
+    orig.get(unsafe { CStr::from_ptr(name) })
+        .copied()
+        .flatten()
+        .ok_or(ScipError::new(Retcode::InvalidData, "SCIPiisGetSubscip"))
+}
+
+/// Computes an infeasible subsystem of the original problem with the included IIS finders, and maps the
+/// constraints and bounds of the reduced copy back to the original problem by name.
+///
+/// SCIP does not expose the variable and constraint maps of the copy, so an error is returned if an item of
+/// the subsystem does not have a unique name in the original problem.
+pub(crate) fn compute(scip: &Rc<ScipPtr>) -> Result<Iis, ScipError> {
+    if unsafe { ffi::SCIPgetNIISfinders(scip.raw) } == 0 {
+        return Err(ScipError::new(Retcode::PluginNotFound, "SCIPgenerateIIS"));
+    }
+    scip_call!(ffi::SCIPgenerateIIS(scip.raw));
+    let iis = unsafe { ffi::SCIPgetIIS(scip.raw) };
+    if iis.is_null() || unsafe { ffi::SCIPiisIsSubscipInfeasible(iis) } == 0 {
+        // the problem is feasible or its infeasibility could not be proven
+        return Err(ScipError::new(Retcode::Error, "SCIPgenerateIIS"));
+    }
+    let subscip = unsafe { ffi::SCIPiisGetSubscip(iis) };
+
+    let orig_conss = scip_slice(unsafe { ffi::SCIPgetOrigConss(scip.raw) }, unsafe {
+        ffi::SCIPgetNOrigConss(scip.raw)
+    });
+    let orig_conss = by_name(orig_conss, |cons| unsafe { ffi::SCIPconsGetName(cons) });
+    let sub_conss = scip_slice(unsafe { ffi::SCIPgetOrigConss(subscip) }, unsafe {
+        ffi::SCIPgetNOrigConss(subscip)
+    });
+    let mut conss = Vec::with_capacity(sub_conss.len());
+    for &sub_cons in sub_conss {
+        let raw = resolve(&orig_conss, unsafe { ffi::SCIPconsGetName(sub_cons) })?;
+        conss.push(Constraint {
+            raw,
+            scip: scip.clone(),
+        });
+    }
+
+    let orig_vars = scip_slice(unsafe { ffi::SCIPgetOrigVars(scip.raw) }, unsafe {
+        ffi::SCIPgetNOrigVars(scip.raw)
+    });
+    let orig_vars = by_name(orig_vars, |var| unsafe { ffi::SCIPvarGetName(var) });
+    let sub_vars = scip_slice(unsafe { ffi::SCIPgetOrigVars(subscip) }, unsafe {
+        ffi::SCIPgetNOrigVars(subscip)
+    });
+    let mut bounds = Vec::new();
+    for &sub_var in sub_vars {
+        let lb = unsafe { ffi::SCIPvarGetLbOriginal(sub_var) };
+        let ub = unsafe { ffi::SCIPvarGetUbOriginal(sub_var) };
+        let lb = (unsafe { ffi::SCIPisInfinity(subscip, -lb) } == 0).then_some(lb);
+        let ub = (unsafe { ffi::SCIPisInfinity(subscip, ub) } == 0).then_some(ub);
+        if lb.is_none() && ub.is_none() {
+            continue;
+        }
+        let raw = resolve(&orig_vars, unsafe { ffi::SCIPvarGetName(sub_var) })?;
+        bounds.push(IisBound {
+            var: Variable {
+                raw,
+                scip: scip.clone(),
+            },
+            lb,
+            ub,
+        });
+    }
+
+    Ok(Iis {
+        conss,
+        bounds,
+        irreducible: unsafe { ffi::SCIPiisIsSubscipIrreducible(iis) } != 0,
+        time: unsafe { ffi::SCIPiisGetTime(iis) },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn compute_iis() {
+        // c1 needs x >= 3 with y <= 5, which contradicts c2, c3 is irrelevant
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .create_prob("iis");
+        let x = model.add_var(0., 10., 1., "x", VarType::Continuous);
+        let y = model.add_var(0., 5., 1., "y", VarType::Continuous);
+        let z = model.add_var(0., 10., 1., "z", VarType::Continuous);
+        let c1 = model.add_cons(vec![&x, &y], &[1., 1.], 8., f64::INFINITY, "c1");
+        let c2 = model.add_cons(vec![&x], &[1.], -f64::INFINITY, 2., "c2");
+        let c3 = model.add_cons(vec![&x, &z], &[1., 1.], -f64::INFINITY, 15., "c3");
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Infeasible);
+        let iis = solved.compute_iis();
+        assert!(iis.irreducible);
+        assert!(iis.contains_cons(&c1));
+        assert!(iis.contains_cons(&c2));
+        assert!(!iis.contains_cons(&c3));
+
+        // the upper bound of y is needed, the bounds of z are not
+        let y_bounds = iis.bounds.iter().find(|b| b.var == y).unwrap();
+        assert_eq!(y_bounds.ub, Some(5.));
+        assert!(iis.bounds.iter().all(|b| b.var != z));
+    }
+    #[test]
+    fn iis_with_repeated_names() {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .create_prob("iis");
+        let x = model.add_var(0., 10., 1., "x", VarType::Continuous);
+        let y = model.add_var(0., 5., 1., "y", VarType::Continuous);
+        model.add_cons(vec![&x, &y], &[1., 1.], 8., f64::INFINITY, "c");
+        model.add_cons(vec![&x], &[1.], -f64::INFINITY, 2., "c");
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Infeasible);
+        assert!(solved.try_compute_iis().is_err());
+    }
+}
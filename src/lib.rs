@@ -40,10 +40,14 @@ pub use model::*;
 pub mod pricer;
 pub use pricer::*;
 
-/// Contains the `Retcode` enum, which represents the return codes of SCIP functions.
+/// Contains the `Retcode` enum, which represents the return codes of SCIP functions, and `ScipError`, which names the failed function.
 pub mod retcode;
 pub use retcode::*;
 
+/// Contains `SolveError` and `PluginError`, the errors returned by fallible plugins and `Model::try_solve`.
+pub mod error;
+pub use error::*;
+
 /// Contains the `Solution` struct, which represents a solution to an optimization problem.
 pub mod solution;
 pub use solution::*;
@@ -52,6 +56,50 @@ pub use solution::*;
 pub mod status;
 pub use status::*;
 
+/// Contains the `Stage` enum, which represents the stage of a SCIP instance.
+pub mod stage;
+pub use stage::*;
+
+/// Contains `PresolveReport` and `Aggregation`, which describe what presolving did to a problem.
+pub mod presolve;
+pub use presolve::*;
+
+/// Contains `FeasibilityReport`, which lists the violations of a solution.
+pub mod feasibility;
+pub use feasibility::*;
+
+/// Contains `SolutionHistory`, which records when and by which heuristic solutions were found.
+pub mod history;
+pub use history::*;
+
+/// Contains `Enumeration`, the options for enumerating several good solutions.
+pub mod enumeration;
+pub use enumeration::Enumeration;
+
+/// Contains `Counting` and `CountResult`, for counting the feasible solutions of a problem.
+pub mod counting;
+pub use counting::*;
+
+/// Contains `Iis`, an irreducible infeasible subsystem of an infeasible problem.
+pub mod iis;
+pub use iis::*;
+
+/// Contains `RelaxationWeights` and `FeasRelaxation`, for minimizing the violation of an infeasible problem.
+pub mod feasrelax;
+pub use feasrelax::*;
+
+/// Contains `SensitivityReport`, the objective and right-hand side ranging of an optimal LP solution.
+pub mod sensitivity;
+pub use sensitivity::*;
+
+/// Contains `Lpi`, a standalone LP solved directly by SCIP's LP solver interface.
+pub mod lpi;
+pub use lpi::*;
+
+/// Contains `FixedLp`, the duals of the LP obtained by fixing the integer variables of a solution.
+pub mod fixedlp;
+pub use fixedlp::*;
+
 /// Contains the `Variable` struct, which represents a variable in an optimization problem.
 pub mod variable;
 pub use variable::*;
@@ -93,19 +141,46 @@ pub mod conshdlr;
 mod diving;
 mod probing;
 
+/// Contains `SparseMatrix` and `LinearBlock` for creating models from matrices in bulk.
+pub mod matrix;
+pub use matrix::*;
+
+/// Contains `BipartiteObservation`, the variable-constraint graph of the LP relaxation used for learning to branch.
+pub mod observation;
+pub use observation::*;
+
+/// Contains `BranchingEnv`, an environment that hands the branching decisions of a solve to the caller.
+pub mod environment;
+pub use environment::*;
+
+/// Contains seeded generators of benchmark instances, e.g. `SetCover` and `CombinatorialAuction`, behind the
+/// `generators` feature.
+#[cfg(feature = "generators")]
+pub mod generators;
+
+/// Contains `MessageHandler` for capturing the output of SCIP, and the routing of its error messages.
+pub mod message;
+pub use message::*;
+
+/// Contains `ProgressRow`, the typed records of the progress table SCIP displays while solving.
+pub mod progress;
+pub use progress::ProgressRow;
+
 pub use conshdlr::*;
 pub use diving::*;
 
 pub use row::*;
 
 /// A macro for calling a `SCIP` function and returning an error if the return code is not `SCIP_OKAY`.
+///
+/// The error is a `ScipError` naming the called function, which converts into a plain `Retcode` if needed.
 #[macro_export]
 macro_rules! scip_call {
     ($res:expr) => {
         let res = unsafe { $res };
         let retcode = $crate::retcode::Retcode::from(res);
         if retcode != $crate::retcode::Retcode::Okay {
-            return Err(retcode);
+            return Err($crate::retcode::ScipError::new(retcode, stringify!($res)).into());
         }
     };
 }
@@ -0,0 +1,465 @@
+use crate::model::ObjSense;
+use crate::retcode::{Retcode, ScipError};
+use crate::row::{BasisStatus, basis_stats};
+use crate::scip::c_string;
+use crate::{ffi, scip_call};
+use std::ffi::{CStr, c_int};
+use std::mem::MaybeUninit;
+
+/// An LP solved directly by the LP solver SCIP was built with (e.g. SoPlex), independent of any SCIP instance.
+///
+/// Columns and rows are referred to by the index returned when adding them. Infinite bounds and sides are given
+/// as the floating point infinities. Solution, basis and ray information is only available after the LP was
+/// solved and before it is modified again.
+///
+/// # Example
+///
+/// ```no_run
+/// use russcip::{Lpi, ObjSense};
+///
+/// // max x + y s.t. x + 2y <= 4, 3x + y <= 6
+/// let mut lpi = Lpi::new("lp").unwrap();
+/// lpi.set_obj_sense(ObjSense::Maximize).unwrap();
+/// let x = lpi.add_col(1., 0., f64::INFINITY, &[]).unwrap();
+/// let y = lpi.add_col(1., 0., f64::INFINITY, &[]).unwrap();
+/// lpi.add_row(-f64::INFINITY, 4., &[(x, 1.), (y, 2.)]).unwrap();
+/// lpi.add_row(-f64::INFINITY, 6., &[(x, 3.), (y, 1.)]).unwrap();
+/// lpi.solve_primal().unwrap();
+/// assert!(lpi.is_optimal());
+/// let sol = lpi.sol().unwrap();
+/// println!("x = {}, y = {}, duals = {:?}", sol.primal[x], sol.primal[y], sol.dual);
+/// ```
+#[derive(Debug)]
+pub struct Lpi {
+    raw: *mut ffi::SCIP_LPI,
+    n_cols: usize,
+    n_rows: usize,
+    solved: bool,
+}
+
+/// The primal and dual solution of a solved LP.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LpSol {
+    /// The objective value.
+    pub obj_val: f64,
+    /// The values of the columns.
+    pub primal: Vec<f64>,
+    /// The dual values of the rows, in the objective sense of the LP: the objective coefficients are the duals
+    /// times the matrix plus the reduced costs. The duals of binding `<=` rows are thus non-positive when
+    /// minimizing and non-negative when maximizing.
+    pub dual: Vec<f64>,
+    /// The activities of the rows.
+    pub activity: Vec<f64>,
+    /// The reduced costs of the columns.
+    pub redcost: Vec<f64>,
+}
+
+impl Lpi {
+    /// Creates an empty minimization LP.
+    pub fn new(name: &str) -> Result<Self, ScipError> {
+        let name = c_string(name)?;
+        let mut raw = MaybeUninit::uninit();
+        scip_call!(ffi::SCIPlpiCreate(
+            raw.as_mut_ptr(),
+            std::ptr::null_mut(),
+            name.as_ptr(),
+            ffi::SCIP_ObjSen_SCIP_OBJSEN_MINIMIZE,
+        ));
+        Ok(Lpi {
+            raw: unsafe { raw.assume_init() },
+            n_cols: 0,
+            n_rows: 0,
+            solved: false,
+        })
+    }
+
+    /// Returns the name and version of the LP solver.
+    pub fn solver_name() -> String {
+        let name = unsafe { CStr::from_ptr(ffi::SCIPlpiGetSolverName()) };
+        name.to_string_lossy().into_owned()
+    }
+
+    /// Returns the number of columns.
+    pub fn n_cols(&self) -> usize {
+        self.n_cols
+    }
+
+    /// Returns the number of rows.
+    pub fn n_rows(&self) -> usize {
+        self.n_rows
+    }
+
+    fn to_lpi_inf(&self, val: f64) -> f64 {
+        if val.is_infinite() {
+            val.signum() * unsafe { ffi::SCIPlpiInfinity(self.raw) }
+        } else {
+            val
+        }
+    }
+
+    fn check_solved(&self, function: &'static str) -> Result<(), ScipError> {
+        if self.solved {
+            Ok(())
+        } else {
+            Err(ScipError::new(Retcode::InvalidCall, function))
+        }
+    }
+
+    /// Sets the objective sense.
+    pub fn set_obj_sense(&mut self, sense: ObjSense) -> Result<(), ScipError> {
+        scip_call!(ffi::SCIPlpiChgObjsen(self.raw, sense.into()));
+        self.solved = false;
+        Ok(())
+    }
+
+    /// Adds the column `x` with `lb <= x <= ub` and the given coefficients `(row, coef)` in existing rows, and
+    /// returns its index.
+    pub fn add_col(
+        &mut self,
+        obj: f64,
+        lb: f64,
+        ub: f64,
+        entries: &[(usize, f64)],
+    ) -> Result<usize, ScipError> {
+        if entries.iter().any(|&(row, _)| row >= self.n_rows) {
+            return Err(ScipError::new(Retcode::InvalidData, "SCIPlpiAddCols"));
+        }
+        let (lb, ub) = (self.to_lpi_inf(lb), self.to_lpi_inf(ub));
+        let beg = 0;
+        let ind: Vec<c_int> = entries.iter().map(|&(row, _)| row as c_int).collect();
+        let val: Vec<f64> = entries.iter().map(|&(_, val)| val).collect();
+        scip_call!(ffi::SCIPlpiAddCols(
+            self.raw,
+            1,
+            &obj,
+            &lb,
+            &ub,
+            std::ptr::null_mut(),
+            entries.len() as c_int,
+            &beg,
+            ind.as_ptr(),
+            val.as_ptr(),
+        ));
+        self.n_cols += 1;
+        self.solved = false;
+        Ok(self.n_cols - 1)
+    }
+
+    /// Adds the row `lhs <= sum(coef * x[col]) <= rhs` over existing columns and returns its index.
+    pub fn add_row(
+        &mut self,
+        lhs: f64,
+        rhs: f64,
+        entries: &[(usize, f64)],
+    ) -> Result<usize, ScipError> {
+        if entries.iter().any(|&(col, _)| col >= self.n_cols) {
+            return Err(ScipError::new(Retcode::InvalidData, "SCIPlpiAddRows"));
+        }
+        let (lhs, rhs) = (self.to_lpi_inf(lhs), self.to_lpi_inf(rhs));
+        let beg = 0;
+        let ind: Vec<c_int> = entries.iter().map(|&(col, _)| col as c_int).collect();
+        let val: Vec<f64> = entries.iter().map(|&(_, val)| val).collect();
+        scip_call!(ffi::SCIPlpiAddRows(
+            self.raw,
+            1,
+            &lhs,
+            &rhs,
+            std::ptr::null_mut(),
+            entries.len() as c_int,
+            &beg,
+            ind.as_ptr(),
+            val.as_ptr(),
+        ));
+        self.n_rows += 1;
+        self.solved = false;
+        Ok(self.n_rows - 1)
+    }
+
+    /// Solves the LP with the primal simplex.
+    pub fn solve_primal(&mut self) -> Result<(), ScipError> {
+        self.solved = false;
+        scip_call!(ffi::SCIPlpiSolvePrimal(self.raw));
+        self.solved = true;
+        Ok(())
+    }
+
+    /// Solves the LP with the dual simplex.
+    pub fn solve_dual(&mut self) -> Result<(), ScipError> {
+        self.solved = false;
+        scip_call!(ffi::SCIPlpiSolveDual(self.raw));
+        self.solved = true;
+        Ok(())
+    }
+
+    /// Returns whether the LP was solved to optimality.
+    pub fn is_optimal(&self) -> bool {
+        self.solved && unsafe { ffi::SCIPlpiIsOptimal(self.raw) } != 0
+    }
+
+    /// Returns whether the LP was proven to be infeasible.
+    pub fn is_primal_infeasible(&self) -> bool {
+        self.solved && unsafe { ffi::SCIPlpiIsPrimalInfeasible(self.raw) } != 0
+    }
+
+    /// Returns whether the LP was proven to be unbounded.
+    pub fn is_primal_unbounded(&self) -> bool {
+        self.solved && unsafe { ffi::SCIPlpiIsPrimalUnbounded(self.raw) } != 0
+    }
+
+    /// Returns the number of simplex iterations of the last solve.
+    pub fn n_iterations(&self) -> Result<usize, ScipError> {
+        self.check_solved("SCIPlpiGetIterations")?;
+        let mut iterations = 0;
+        scip_call!(ffi::SCIPlpiGetIterations(self.raw, &mut iterations));
+        Ok(iterations as usize)
+    }
+
+    /// Returns the solution of the last solve.
+    pub fn sol(&self) -> Result<LpSol, ScipError> {
+        self.check_solved("SCIPlpiGetSol")?;
+        let mut sol = LpSol {
+            obj_val: 0.,
+            primal: vec![0.; self.n_cols],
+            dual: vec![0.; self.n_rows],
+            activity: vec![0.; self.n_rows],
+            redcost: vec![0.; self.n_cols],
+        };
+        scip_call!(ffi::SCIPlpiGetSol(
+            self.raw,
+            &mut sol.obj_val,
+            sol.primal.as_mut_ptr(),
+            sol.dual.as_mut_ptr(),
+            sol.activity.as_mut_ptr(),
+            sol.redcost.as_mut_ptr(),
+        ));
+        Ok(sol)
+    }
+
+    /// Returns a primal ray proving that the LP is unbounded, or an error if the LP solver did not provide one.
+    pub fn primal_ray(&self) -> Result<Vec<f64>, ScipError> {
+        self.check_solved("SCIPlpiGetPrimalRay")?;
+        if unsafe { ffi::SCIPlpiHasPrimalRay(self.raw) } == 0 {
+            return Err(ScipError::new(Retcode::LpError, "SCIPlpiGetPrimalRay"));
+        }
+        let mut ray = vec![0.; self.n_cols];
+        scip_call!(ffi::SCIPlpiGetPrimalRay(self.raw, ray.as_mut_ptr()));
+        Ok(ray)
+    }
+
+    /// Returns the dual multipliers of the rows in a Farkas proof that the LP is infeasible, or an error if the
+    /// LP solver did not provide one.
+    pub fn dual_farkas(&self) -> Result<Vec<f64>, ScipError> {
+        self.check_solved("SCIPlpiGetDualfarkas")?;
+        if unsafe { ffi::SCIPlpiHasDualRay(self.raw) } == 0 {
+            return Err(ScipError::new(Retcode::LpError, "SCIPlpiGetDualfarkas"));
+        }
+        let mut farkas = vec![0.; self.n_rows];
+        scip_call!(ffi::SCIPlpiGetDualfarkas(self.raw, farkas.as_mut_ptr()));
+        Ok(farkas)
+    }
+
+    /// Returns the basis status of the columns and rows after the last solve.
+    pub fn base(&self) -> Result<(Vec<BasisStatus>, Vec<BasisStatus>), ScipError> {
+        self.check_solved("SCIPlpiGetBase")?;
+        let mut cstat: Vec<c_int> = vec![0; self.n_cols];
+        let mut rstat: Vec<c_int> = vec![0; self.n_rows];
+        scip_call!(ffi::SCIPlpiGetBase(
+            self.raw,
+            cstat.as_mut_ptr(),
+            rstat.as_mut_ptr()
+        ));
+        let to_status = |stat: Vec<c_int>| {
+            stat.into_iter()
+                .map(|stat| (stat as ffi::SCIP_BASESTAT).into())
+                .collect()
+        };
+        Ok((to_status(cstat), to_status(rstat)))
+    }
+
+    /// Sets the basis the next solve starts from, e.g. the basis of a related LP to warm start it. The slices
+    /// need to have one status per column and row.
+    pub fn set_base(
+        &mut self,
+        cols: &[BasisStatus],
+        rows: &[BasisStatus],
+    ) -> Result<(), ScipError> {
+        if cols.len() != self.n_cols || rows.len() != self.n_rows {
+            return Err(ScipError::new(Retcode::InvalidData, "SCIPlpiSetBase"));
+        }
+        let (cstat, rstat) = (basis_stats(cols), basis_stats(rows));
+        scip_call!(ffi::SCIPlpiSetBase(
+            self.raw,
+            cstat.as_ptr(),
+            rstat.as_ptr()
+        ));
+        self.solved = false;
+        Ok(())
+    }
+
+    /// Returns the column (`n >= 0`) or row (`-1 - m`) that is basic at each position of the basis.
+    pub(crate) fn basis_ind(&self) -> Result<Vec<c_int>, ScipError> {
+        self.check_solved("SCIPlpiGetBasisInd")?;
+        let mut bind = vec![0; self.n_rows];
+        scip_call!(ffi::SCIPlpiGetBasisInd(self.raw, bind.as_mut_ptr()));
+        Ok(bind)
+    }
+
+    /// Returns the dense row `r` of the inverse basis matrix, where row slack variables have coefficient +1.
+    pub(crate) fn binv_row(&self, r: usize) -> Result<Vec<f64>, ScipError> {
+        self.check_solved("SCIPlpiGetBInvRow")?;
+        let mut coef = vec![0.; self.n_rows];
+        scip_call!(ffi::SCIPlpiGetBInvRow(
+            self.raw,
+            r as c_int,
+            coef.as_mut_ptr(),
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        ));
+        Ok(coef)
+    }
+
+    /// Returns the dense column `c` of the inverse basis matrix, where row slack variables have coefficient +1.
+    pub(crate) fn binv_col(&self, c: usize) -> Result<Vec<f64>, ScipError> {
+        self.check_solved("SCIPlpiGetBInvCol")?;
+        let mut coef = vec![0.; self.n_rows];
+        scip_call!(ffi::SCIPlpiGetBInvCol(
+            self.raw,
+            c as c_int,
+            coef.as_mut_ptr(),
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        ));
+        Ok(coef)
+    }
+
+    /// Returns the dense row `r` of the inverse basis matrix times the constraint matrix, reusing the row of the
+    /// inverse basis matrix if it is known.
+    pub(crate) fn binv_a_row(
+        &self,
+        r: usize,
+        binv_row: Option<&[f64]>,
+    ) -> Result<Vec<f64>, ScipError> {
+        self.check_solved("SCIPlpiGetBInvARow")?;
+        let mut coef = vec![0.; self.n_cols];
+        scip_call!(ffi::SCIPlpiGetBInvARow(
+            self.raw,
+            r as c_int,
+            binv_row.map_or(std::ptr::null(), |row| row.as_ptr()),
+            coef.as_mut_ptr(),
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        ));
+        Ok(coef)
+    }
+}
+
+impl Drop for Lpi {
+    fn drop(&mut self) {
+        unsafe { ffi::SCIPlpiFree(&mut self.raw) };
+    }
+}
+
+/// A state (e.g. the basis) of an LP solver, freed on drop unless ownership was passed on and the pointer reset.
+pub(crate) struct LpiState {
+    pub(crate) lpi: *mut ffi::SCIP_LPI,
+    pub(crate) blkmem: *mut ffi::BMS_BLKMEM,
+    pub(crate) raw: *mut ffi::SCIP_LPISTATE,
+}
+
+impl LpiState {
+    /// Stores the current state of the LP solver.
+    pub(crate) fn get(
+        lpi: *mut ffi::SCIP_LPI,
+        blkmem: *mut ffi::BMS_BLKMEM,
+    ) -> Result<Self, ScipError> {
+        let mut state = LpiState {
+            lpi,
+            blkmem,
+            raw: std::ptr::null_mut(),
+        };
+        scip_call!(ffi::SCIPlpiGetState(lpi, blkmem, &mut state.raw));
+        Ok(state)
+    }
+}
+
+impl Drop for LpiState {
+    fn drop(&mut self) {
+        if !self.raw.is_null() {
+            unsafe { ffi::SCIPlpiFreeState(self.lpi, self.blkmem, &mut self.raw) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lp() -> (Lpi, usize, usize) {
+        // max x + y s.t. x + 2y <= 4, 3x + y <= 6
+        let mut lpi = Lpi::new("lp").unwrap();
+        lpi.set_obj_sense(ObjSense::Maximize).unwrap();
+        let x = lpi.add_col(1., 0., f64::INFINITY, &[]).unwrap();
+        let y = lpi.add_col(1., 0., f64::INFINITY, &[]).unwrap();
+        lpi.add_row(-f64::INFINITY, 4., &[(x, 1.), (y, 2.)])
+            .unwrap();
+        lpi.add_row(-f64::INFINITY, 6., &[(x, 3.), (y, 1.)])
+            .unwrap();
+        (lpi, x, y)
+    }
+
+    #[test]
+    fn solve_and_warm_start() {
+        let (mut lpi, x, y) = lp();
+        assert!(lpi.sol().is_err());
+        lpi.solve_dual().unwrap();
+        assert!(lpi.is_optimal());
+        let sol = lpi.sol().unwrap();
+        assert!((sol.obj_val - 2.8).abs() < 1e-9);
+        assert!((sol.primal[x] - 1.6).abs() < 1e-9);
+        assert!((sol.primal[y] - 1.2).abs() < 1e-9);
+        assert!((sol.dual[0] - 0.4).abs() < 1e-9);
+        assert!((sol.dual[1] - 0.2).abs() < 1e-9);
+
+        let (cols, rows) = lpi.base().unwrap();
+        assert_eq!(cols, vec![BasisStatus::Basic; 2]);
+        assert!(rows.iter().all(|&stat| stat != BasisStatus::Basic));
+
+        // the optimal basis of the same LP needs no further iterations
+        let (mut warm, _, _) = lp();
+        assert!(warm.set_base(&cols, &rows[..1]).is_err());
+        warm.set_base(&cols, &rows).unwrap();
+        warm.solve_primal().unwrap();
+        assert!(warm.is_optimal());
+        assert_eq!(warm.n_iterations().unwrap(), 0);
+        assert_eq!(warm.sol().unwrap().primal, sol.primal);
+    }
+
+    #[test]
+    fn name_with_nul() {
+        assert_eq!(Lpi::new("l\0p").unwrap_err().retcode, Retcode::InvalidData);
+    }
+
+    #[test]
+    fn rays() {
+        // x >= 2 contradicts the upper bound of x
+        let mut lpi = Lpi::new("infeasible").unwrap();
+        let x = lpi.add_col(1., 0., 1., &[]).unwrap();
+        assert!(lpi.add_row(2., f64::INFINITY, &[(x + 1, 1.)]).is_err());
+        lpi.add_row(2., f64::INFINITY, &[(x, 1.)]).unwrap();
+        lpi.solve_dual().unwrap();
+        assert!(lpi.is_primal_infeasible());
+        assert_ne!(lpi.dual_farkas().unwrap()[0], 0.);
+
+        // min -x s.t. x - y <= 1 is unbounded when increasing y along with x
+        let mut lpi = Lpi::new("unbounded").unwrap();
+        let x = lpi.add_col(-1., 0., f64::INFINITY, &[]).unwrap();
+        let y = lpi.add_col(0., 0., f64::INFINITY, &[]).unwrap();
+        lpi.add_row(-f64::INFINITY, 1., &[(x, 1.), (y, -1.)])
+            .unwrap();
+        lpi.solve_primal().unwrap();
+        assert!(lpi.is_primal_unbounded());
+        let ray = lpi.primal_ray().unwrap();
+        assert!(ray[x] > 0.);
+        assert!(ray[y] >= ray[x] - 1e-9);
+    }
+}
@@ -0,0 +1,537 @@
+use crate::scip::ScipPtr;
+use crate::{Constraint, ObjSense, VarType, Variable, ffi};
+use std::borrow::Cow;
+use std::rc::Rc;
+
+/// The storage order of a `SparseMatrix`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SparseFormat {
+    /// Compressed sparse row format, `indptr` has one entry per row (plus one).
+    Csr,
+    /// Compressed sparse column format, `indptr` has one entry per column (plus one).
+    Csc,
+}
+
+/// A sparse matrix in compressed row or column format (the same layout as scipy's `csr_matrix`/`csc_matrix`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseMatrix {
+    /// The storage order of the matrix.
+    pub format: SparseFormat,
+    /// The number of rows.
+    pub n_rows: usize,
+    /// The number of columns.
+    pub n_cols: usize,
+    /// Offsets into `indices` and `values` where each row (or column) starts.
+    pub indptr: Vec<usize>,
+    /// The column (or row) index of each non-zero entry.
+    pub indices: Vec<usize>,
+    /// The value of each non-zero entry.
+    pub values: Vec<f64>,
+}
+
+impl SparseMatrix {
+    /// Creates a new matrix in compressed sparse row format.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the arrays are inconsistent with the given dimensions.
+    pub fn csr(
+        n_rows: usize,
+        n_cols: usize,
+        indptr: Vec<usize>,
+        indices: Vec<usize>,
+        values: Vec<f64>,
+    ) -> Self {
+        let matrix = SparseMatrix {
+            format: SparseFormat::Csr,
+            n_rows,
+            n_cols,
+            indptr,
+            indices,
+            values,
+        };
+        matrix.check();
+        matrix
+    }
+
+    /// Creates a new matrix in compressed sparse column format.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the arrays are inconsistent with the given dimensions.
+    pub fn csc(
+        n_rows: usize,
+        n_cols: usize,
+        indptr: Vec<usize>,
+        indices: Vec<usize>,
+        values: Vec<f64>,
+    ) -> Self {
+        let matrix = SparseMatrix {
+            format: SparseFormat::Csc,
+            n_rows,
+            n_cols,
+            indptr,
+            indices,
+            values,
+        };
+        matrix.check();
+        matrix
+    }
+
+    /// Returns the number of stored entries.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the same matrix in compressed sparse row format, borrowing it if it already is.
+    pub fn to_csr(&self) -> Cow<'_, SparseMatrix> {
+        match self.format {
+            SparseFormat::Csr => Cow::Borrowed(self),
+            SparseFormat::Csc => {
+                Cow::Owned(self.transposed_storage(self.n_rows, SparseFormat::Csr))
+            }
+        }
+    }
+
+    /// Returns the same matrix in compressed sparse column format, borrowing it if it already is.
+    pub fn to_csc(&self) -> Cow<'_, SparseMatrix> {
+        match self.format {
+            SparseFormat::Csc => Cow::Borrowed(self),
+            SparseFormat::Csr => {
+                Cow::Owned(self.transposed_storage(self.n_cols, SparseFormat::Csc))
+            }
+        }
+    }
+
+    /// Returns the column indices and values of the given row.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the matrix is not in CSR format.
+    pub fn row(&self, row: usize) -> (&[usize], &[f64]) {
+        assert_eq!(self.format, SparseFormat::Csr);
+        let range = self.indptr[row]..self.indptr[row + 1];
+        (&self.indices[range.clone()], &self.values[range])
+    }
+
+    fn major_dim(&self) -> usize {
+        match self.format {
+            SparseFormat::Csr => self.n_rows,
+            SparseFormat::Csc => self.n_cols,
+        }
+    }
+
+    fn minor_dim(&self) -> usize {
+        match self.format {
+            SparseFormat::Csr => self.n_cols,
+            SparseFormat::Csc => self.n_rows,
+        }
+    }
+
+    fn check(&self) {
+        assert_eq!(
+            self.indptr.len(),
+            self.major_dim() + 1,
+            "indptr needs one entry per row (CSR) or column (CSC) plus one"
+        );
+        assert_eq!(self.indptr[0], 0, "indptr has to start at 0");
+        assert!(
+            self.indptr.windows(2).all(|w| w[0] <= w[1]),
+            "indptr has to be non-decreasing"
+        );
+        assert_eq!(
+            *self.indptr.last().unwrap(),
+            self.indices.len(),
+            "indptr has to end at the number of entries"
+        );
+        assert_eq!(
+            self.indices.len(),
+            self.values.len(),
+            "indices and values need the same length"
+        );
+        let minor_dim = self.minor_dim();
+        assert!(
+            self.indices.iter().all(|&i| i < minor_dim),
+            "indices have to be less than the number of columns (CSR) or rows (CSC)"
+        );
+    }
+
+    /// Converts between CSR and CSC, `n_major` being the major dimension of the result.
+    fn transposed_storage(&self, n_major: usize, format: SparseFormat) -> SparseMatrix {
+        let mut indptr = vec![0; n_major + 1];
+        for &i in &self.indices {
+            indptr[i + 1] += 1;
+        }
+        for i in 0..n_major {
+            indptr[i + 1] += indptr[i];
+        }
+
+        let mut next = indptr.clone();
+        let mut indices = vec![0; self.nnz()];
+        let mut values = vec![0.0; self.nnz()];
+        for major in 0..self.major_dim() {
+            for k in self.indptr[major]..self.indptr[major + 1] {
+                let minor = self.indices[k];
+                indices[next[minor]] = major;
+                values[next[minor]] = self.values[k];
+                next[minor] += 1;
+            }
+        }
+
+        SparseMatrix {
+            format,
+            n_rows: self.n_rows,
+            n_cols: self.n_cols,
+            indptr,
+            indices,
+            values,
+        }
+    }
+}
+
+/// A block of linear constraints `lhs <= A x <= rhs` over new variables `lb <= x <= ub` with objective `c`.
+///
+/// # Example
+///
+/// ```rust
+/// use russcip::prelude::*;
+/// use russcip::{LinearBlock, SparseMatrix};
+///
+/// // max 3x + 2y  s.t.  x + y <= 4,  x + 3y <= 6,  x <= 3
+/// let a = SparseMatrix::csr(3, 2, vec![0, 2, 4, 5], vec![0, 1, 0, 1, 0], vec![1.0, 1.0, 1.0, 3.0, 1.0]);
+/// let block = LinearBlock::new(vec![3.0, 2.0], a).rhs(vec![4.0, 6.0, 3.0]);
+///
+/// let (model, handle) = Model::from_matrices(&block);
+/// let solved = model.maximize().hide_output().solve();
+/// assert_eq!(handle.n_vars(), 2);
+/// assert!((solved.obj_val() - 11.0).abs() < 1e-6);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearBlock {
+    /// The objective coefficient of each variable.
+    pub obj: Vec<f64>,
+    /// The constraint matrix, one row per constraint and one column per variable.
+    pub matrix: SparseMatrix,
+    /// The left-hand side of each constraint.
+    pub lhs: Vec<f64>,
+    /// The right-hand side of each constraint.
+    pub rhs: Vec<f64>,
+    /// The lower bound of each variable.
+    pub lb: Vec<f64>,
+    /// The upper bound of each variable.
+    pub ub: Vec<f64>,
+    /// The type of each variable.
+    pub var_types: Vec<VarType>,
+}
+
+impl LinearBlock {
+    /// Creates a new block from an objective vector and a constraint matrix.
+    ///
+    /// Constraints are unbounded on both sides and variables are continuous with bounds `[0, inf)`,
+    /// these defaults can be changed with the other methods of this struct.
+    pub fn new(obj: Vec<f64>, matrix: SparseMatrix) -> Self {
+        let n_vars = obj.len();
+        let n_conss = matrix.n_rows;
+        LinearBlock {
+            obj,
+            matrix,
+            lhs: vec![f64::NEG_INFINITY; n_conss],
+            rhs: vec![f64::INFINITY; n_conss],
+            lb: vec![0.0; n_vars],
+            ub: vec![f64::INFINITY; n_vars],
+            var_types: vec![VarType::Continuous; n_vars],
+        }
+    }
+
+    /// Sets the left-hand sides of the constraints.
+    pub fn lhs(mut self, lhs: Vec<f64>) -> Self {
+        self.lhs = lhs;
+        self
+    }
+
+    /// Sets the right-hand sides of the constraints.
+    pub fn rhs(mut self, rhs: Vec<f64>) -> Self {
+        self.rhs = rhs;
+        self
+    }
+
+    /// Sets the lower and upper bounds of the variables.
+    pub fn bounds(mut self, lb: Vec<f64>, ub: Vec<f64>) -> Self {
+        self.lb = lb;
+        self.ub = ub;
+        self
+    }
+
+    /// Sets the types of the variables.
+    pub fn var_types(mut self, var_types: Vec<VarType>) -> Self {
+        self.var_types = var_types;
+        self
+    }
+
+    /// Marks the variables with a `true` flag as integer and all others as continuous.
+    pub fn integrality(mut self, integral: &[bool]) -> Self {
+        self.var_types = integral
+            .iter()
+            .map(|&is_int| {
+                if is_int {
+                    VarType::Integer
+                } else {
+                    VarType::Continuous
+                }
+            })
+            .collect();
+        self
+    }
+
+    /// Returns the number of variables in the block.
+    pub fn n_vars(&self) -> usize {
+        self.obj.len()
+    }
+
+    /// Returns the number of constraints in the block.
+    pub fn n_conss(&self) -> usize {
+        self.matrix.n_rows
+    }
+
+    pub(crate) fn check(&self) {
+        let n_vars = self.n_vars();
+        let n_conss = self.n_conss();
+        assert_eq!(
+            self.matrix.n_cols, n_vars,
+            "the matrix needs one column per objective coefficient"
+        );
+        assert_eq!(self.lhs.len(), n_conss, "lhs needs one entry per row");
+        assert_eq!(self.rhs.len(), n_conss, "rhs needs one entry per row");
+        assert_eq!(self.lb.len(), n_vars, "lb needs one entry per column");
+        assert_eq!(self.ub.len(), n_vars, "ub needs one entry per column");
+        assert_eq!(
+            self.var_types.len(),
+            n_vars,
+            "var_types needs one entry per column"
+        );
+    }
+}
+
+/// Index-based access to the variables and constraints created from a `LinearBlock`.
+///
+/// Variable `i` corresponds to column `i` of the block matrix and constraint `j` to row `j`.
+/// `Variable` and `Constraint` handles are only created on demand.
+#[derive(Debug, Clone)]
+pub struct LinearBlockHandle {
+    pub(crate) vars: Vec<*mut ffi::SCIP_VAR>,
+    pub(crate) conss: Vec<*mut ffi::SCIP_CONS>,
+    pub(crate) scip: Rc<ScipPtr>,
+}
+
+impl LinearBlockHandle {
+    /// Returns the number of variables created from the block.
+    pub fn n_vars(&self) -> usize {
+        self.vars.len()
+    }
+
+    /// Returns the number of constraints created from the block.
+    pub fn n_conss(&self) -> usize {
+        self.conss.len()
+    }
+
+    /// Returns the variable of the given column, if it exists.
+    pub fn var(&self, col: usize) -> Option<Variable> {
+        self.vars.get(col).map(|&raw| Variable {
+            raw,
+            scip: self.scip.clone(),
+        })
+    }
+
+    /// Returns the constraint of the given row, if it exists.
+    pub fn cons(&self, row: usize) -> Option<Constraint> {
+        self.conss.get(row).map(|&raw| Constraint {
+            raw,
+            scip: self.scip.clone(),
+        })
+    }
+
+    /// Returns all variables of the block, in column order.
+    pub fn vars(&self) -> Vec<Variable> {
+        (0..self.n_vars()).filter_map(|i| self.var(i)).collect()
+    }
+
+    /// Returns all constraints of the block, in row order.
+    pub fn conss(&self) -> Vec<Constraint> {
+        (0..self.n_conss()).filter_map(|i| self.cons(i)).collect()
+    }
+}
+
+/// The matrix representation of a model, as returned by `ModelWithProblem::to_matrices`.
+#[derive(Debug, Clone)]
+pub struct ModelMatrices {
+    /// The objective sense of the coefficients in `block.obj` (the transformed problem is always minimized).
+    pub obj_sense: ObjSense,
+    /// The objective, constraint matrix (in CSR format), sides, bounds and types.
+    pub block: LinearBlock,
+    /// The variable of each column of the matrix.
+    pub vars: Vec<Variable>,
+    /// The constraint of each row of the matrix.
+    pub conss: Vec<Constraint>,
+}
+
+/// An error returned when a model cannot be represented as matrices.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum MatrixError {
+    /// The model contains constraints that cannot be expressed linearly.
+    NonLinear(Vec<Constraint>),
+    /// The model contains constraints on multi-aggregated variables, which are not columns of the matrix.
+    MultiAggregated(Vec<Constraint>),
+}
+
+/// The linear representation `lhs <= vals * vars <= rhs` of a constraint.
+pub(crate) struct LinearRow {
+    pub(crate) vars: Vec<*mut ffi::SCIP_VAR>,
+    pub(crate) vals: Vec<f64>,
+    pub(crate) lhs: f64,
+    pub(crate) rhs: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Model, ModelWithProblem, ProblemOrSolving, Status, WithSolutions, minimal_model};
+
+    fn small_lp() -> LinearBlock {
+        // max 3x + 2y  s.t.  x + y <= 4,  x + 3y <= 6,  x <= 3
+        let a = SparseMatrix::csr(
+            3,
+            2,
+            vec![0, 2, 4, 5],
+            vec![0, 1, 0, 1, 0],
+            vec![1.0, 1.0, 1.0, 3.0, 1.0],
+        );
+        LinearBlock::new(vec![3.0, 2.0], a).rhs(vec![4.0, 6.0, 3.0])
+    }
+
+    #[test]
+    fn csr_csc_roundtrip() {
+        let csr = small_lp().matrix;
+        let csc = csr.to_csc();
+        assert_eq!(csc.format, SparseFormat::Csc);
+        assert_eq!(csc.indptr, vec![0, 3, 5]);
+        assert_eq!(csc.indices, vec![0, 1, 2, 0, 1]);
+        assert_eq!(csc.values, vec![1.0, 1.0, 1.0, 1.0, 3.0]);
+        assert_eq!(*csc.to_csr(), csr);
+        assert_eq!(csr.row(1), (&[0, 1][..], &[1.0, 3.0][..]));
+    }
+
+    #[test]
+    fn from_matrices() {
+        let (model, handle) = Model::from_matrices(&small_lp());
+        assert_eq!(model.n_vars(), 2);
+        assert_eq!(model.n_conss(), 3);
+        assert_eq!(handle.n_vars(), 2);
+        assert_eq!(handle.n_conss(), 3);
+        assert!(handle.var(2).is_none());
+
+        let solved = model.maximize().hide_output().solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!((solved.obj_val() - 11.0).abs() < 1e-6);
+
+        let sol = solved.best_sol().unwrap();
+        assert!((sol.val(&handle.var(0).unwrap()) - 3.0).abs() < 1e-6);
+        assert!((sol.val(&handle.var(1).unwrap()) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn add_csc_block_with_integrality() {
+        let mut block = small_lp();
+        block.matrix = block.matrix.to_csc().into_owned();
+        let block = block.rhs(vec![4.5, 6.0, 3.0]).integrality(&[true, true]);
+
+        let mut model = minimal_model().maximize();
+        let x = model.add_var(0.0, 1.0, 1.0, "x", VarType::Binary);
+        let handle = model.add_linear_block(&block);
+        assert_eq!(model.n_vars(), 3);
+        assert_eq!(handle.var(0).unwrap().var_type(), VarType::Integer);
+        assert_eq!(handle.cons(0).unwrap().name(), "c0");
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!((solved.obj_val() - 12.0).abs() < 1e-6);
+        assert!((solved.best_sol().unwrap().val(&x) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "rhs needs one entry per row")]
+    fn dimension_mismatch() {
+        let block = small_lp().rhs(vec![1.0]);
+        minimal_model().add_linear_block(&block);
+    }
+
+    #[test]
+    #[should_panic(expected = "indices have to be less than the number of columns")]
+    fn index_out_of_bounds() {
+        SparseMatrix::csr(1, 2, vec![0, 1], vec![2], vec![1.0]);
+    }
+
+    #[test]
+    fn to_matrices_roundtrip() {
+        let block = small_lp();
+        let (model, handle) = Model::from_matrices(&block);
+        let matrices = model.maximize().to_matrices().unwrap();
+
+        assert_eq!(matrices.obj_sense, ObjSense::Maximize);
+        assert_eq!(matrices.block, block);
+        assert_eq!(matrices.vars, handle.vars());
+        assert_eq!(matrices.conss[2].name(), "c2");
+    }
+
+    #[test]
+    fn to_matrices_special_constraints() {
+        let mut model = minimal_model();
+        let x = model.add_var(0.0, 1.0, 1.0, "x", VarType::Binary);
+        let y = model.add_var(0.0, 1.0, 1.0, "y", VarType::Binary);
+        let z = model.add_var(0.0, 1.0, 1.0, "z", VarType::Binary);
+        model.add_cons_set_part(vec![&x, &y], "part");
+        model.add_cons_set_pack(vec![&y, &z], "pack");
+        model.add_cons(vec![&x, &z], &[2.0, -1.0], 0.0, 5.0, "lin");
+
+        let matrices = model.to_matrices().unwrap();
+        let a = &matrices.block.matrix;
+        assert_eq!(a.row(0), (&[0, 1][..], &[1.0, 1.0][..]));
+        assert_eq!(a.row(1), (&[1, 2][..], &[1.0, 1.0][..]));
+        assert_eq!(a.row(2), (&[0, 2][..], &[2.0, -1.0][..]));
+        assert_eq!(matrices.block.lhs, vec![1.0, f64::NEG_INFINITY, 0.0]);
+        assert_eq!(matrices.block.rhs, vec![1.0, 1.0, 5.0]);
+        assert_eq!(matrices.block.var_types, vec![VarType::Binary; 3]);
+        assert_eq!(matrices.conss[0].conshdlr_name(), "setppc");
+
+        model.add_cons_cardinality(vec![&x, &y, &z], 1, "card");
+        match model.to_matrices() {
+            Err(MatrixError::NonLinear(conss)) => {
+                assert_eq!(conss.len(), 1);
+                assert_eq!(conss[0].name(), "card");
+            }
+            Err(error) => panic!("unexpected error {error:?}"),
+            Ok(_) => panic!("cardinality constraint should not be exported"),
+        }
+    }
+
+    #[test]
+    fn to_matrices_cancelling_duplicates() {
+        let mut model = minimal_model();
+        let x = model.add_var(0.0, 1.0, 1.0, "x", VarType::Binary);
+        let y = model.add_var(0.0, 1.0, 1.0, "y", VarType::Binary);
+        model.add_cons(vec![&x, &y, &x], &[1.0, 1.0, -1.0], 0.0, 1.0, "c");
+
+        let matrices = model.to_matrices().unwrap();
+        assert_eq!(matrices.block.matrix.row(0), (&[1][..], &[1.0][..]));
+    }
+
+    #[test]
+    fn to_matrices_transformed() {
+        let (model, _) = Model::from_matrices(&small_lp());
+        let solved = model.maximize().hide_output().solve();
+        let matrices = solved.to_matrices().unwrap();
+        assert_eq!(matrices.obj_sense, ObjSense::Minimize);
+        assert_eq!(matrices.block.n_vars(), matrices.vars.len());
+        assert_eq!(matrices.block.n_conss(), matrices.conss.len());
+    }
+}
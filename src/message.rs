@@ -0,0 +1,161 @@
+use crate::ffi;
+use std::ffi::{CStr, c_char, c_void};
+use std::sync::RwLock;
+
+/// The kind of a message printed by SCIP.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageKind {
+    /// Regular output, e.g. the solving log and statistics (filtered by the `display/verblevel` parameter).
+    Info,
+    /// A warning.
+    Warning,
+    /// Output of the interactive shell.
+    Dialog,
+}
+
+/// A handler for the messages printed by a SCIP instance, installed with `Model::set_message_handler`.
+///
+/// Closures taking the kind and the text of a message implement this trait.
+pub trait MessageHandler {
+    /// Handles a single line printed by SCIP, without the trailing newline.
+    fn message(&mut self, kind: MessageKind, msg: &str);
+}
+
+impl<F: FnMut(MessageKind, &str)> MessageHandler for F {
+    fn message(&mut self, kind: MessageKind, msg: &str) {
+        self(kind, msg)
+    }
+}
+
+/// A message handler forwarding SCIP output to the `log` facade with target `scip`.
+///
+/// Info messages are logged at level `info`, warnings at level `warn` and dialog output at level `debug`.
+#[cfg(feature = "log")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogHandler;
+
+#[cfg(feature = "log")]
+impl MessageHandler for LogHandler {
+    fn message(&mut self, kind: MessageKind, msg: &str) {
+        let level = match kind {
+            MessageKind::Info => log::Level::Info,
+            MessageKind::Warning => log::Level::Warn,
+            MessageKind::Dialog => log::Level::Debug,
+        };
+        log::log!(target: "scip", level, "{msg}");
+    }
+}
+
+type ErrorPrinter = Box<dyn Fn(&str) + Send + Sync>;
+
+static ERROR_PRINTER: RwLock<Option<ErrorPrinter>> = RwLock::new(None);
+
+/// Routes the error messages of SCIP to the given function instead of stderr.
+///
+/// Unlike the other messages, SCIP prints errors through a single process-wide method, so the printer applies to
+/// all models and replaces any previously set printer.
+pub fn set_error_printer(printer: impl Fn(&str) + Send + Sync + 'static) {
+    *ERROR_PRINTER.write().unwrap() = Some(Box::new(printer));
+    unsafe { ffi::SCIPmessageSetErrorPrinting(Some(print_error), std::ptr::null_mut()) };
+}
+
+/// Routes the error messages of SCIP to the `log` facade at level `error` with target `scip`.
+#[cfg(feature = "log")]
+pub fn log_errors() {
+    set_error_printer(|msg| log::error!(target: "scip", "{msg}"));
+}
+
+/// Restores printing the error messages of SCIP to stderr.
+pub fn reset_error_printer() {
+    unsafe { ffi::SCIPmessageSetErrorPrintingDefault() };
+    *ERROR_PRINTER.write().unwrap() = None;
+}
+
+unsafe extern "C" fn print_error(_data: *mut c_void, _file: *mut ffi::FILE, msg: *const c_char) {
+    if msg.is_null() {
+        return;
+    }
+    let msg = unsafe { CStr::from_ptr(msg) }.to_string_lossy();
+    // errors are reported while SCIP unwinds its call stack, a panicking printer must not unwind into it
+    let _ = std::panic::catch_unwind(|| {
+        if let Some(printer) = ERROR_PRINTER.read().unwrap().as_ref() {
+            for line in msg.lines() {
+                printer(line);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::{MessageKind, minimal_model};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn message_handler_receives_log() {
+        let lines = Rc::new(RefCell::new(Vec::new()));
+        let captured = lines.clone();
+        let mut model = minimal_model().set_message_handler(move |kind, msg: &str| {
+            captured.borrow_mut().push((kind, msg.to_string()))
+        });
+        let x = model.add_var(0., 1., 1., "x", VarType::Binary);
+        model.add_cons(vec![&x], &[1.], 1., 1., "c");
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        drop(solved);
+
+        let lines = lines.borrow();
+        assert!(lines.iter().all(|(_, msg)| !msg.ends_with('\n')));
+        assert!(
+            lines
+                .iter()
+                .any(|(kind, msg)| *kind == MessageKind::Info && msg.contains("SCIP Status"))
+        );
+    }
+
+    #[test]
+    fn log_file() {
+        let path = std::env::temp_dir().join("russcip_log_file_test.log");
+        let path = path.to_str().unwrap();
+        let model = minimal_model()
+            .set_message_handler(|_, _: &str| {})
+            .set_log_file(path)
+            .solve();
+        drop(model);
+
+        let log = std::fs::read_to_string(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+        assert!(log.contains("SCIP Status"));
+    }
+
+    #[test]
+    fn write_with_message_handler() {
+        let lines = Rc::new(RefCell::new(Vec::new()));
+        let captured = lines.clone();
+        let mut model = minimal_model()
+            .set_message_handler(move |_, msg: &str| captured.borrow_mut().push(msg.to_string()));
+        let x = model.add_var(0., 1., 1., "x", VarType::Binary);
+        model.add_cons(vec![&x], &[1.], 1., 1., "c");
+
+        let path = std::env::temp_dir().join("russcip_message_handler_write_test.lp");
+        let path = path.to_str().unwrap();
+        model.write(path, "lp").unwrap();
+        let written = std::fs::read_to_string(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+
+        // the problem is written to the file, not to the handler
+        assert!(written.contains("Subject to"));
+        assert!(lines.borrow().iter().all(|msg| !msg.contains("Subject to")));
+    }
+    #[test]
+    #[should_panic(expected = "message failed")]
+    fn panicking_message_handler_outside_solve() {
+        let model = minimal_model().set_message_handler(|_, _: &str| panic!("message failed"));
+
+        // the panic of the handler is resumed when writing the problem reports the written file
+        let path = std::env::temp_dir().join("russcip_panicking_message_handler_test.lp");
+        let _ = model.write(path.to_str().unwrap(), "lp");
+    }
+}
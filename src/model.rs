@@ -1,19 +1,39 @@
 use crate::builder::CanBeAddedToModel;
 use crate::builder::cons::ConsBuilder;
 use crate::constraint::Constraint;
-use crate::eventhdlr::Eventhdlr;
+use crate::counting::{CountResult, Counting};
+use crate::enumeration::Enumeration;
+use crate::error::Infallible;
+use crate::error::SolveError;
+use crate::eventhdlr::TryEventhdlr;
+use crate::feasibility::FeasibilityReport;
+use crate::feasrelax::{FeasRelaxation, RelaxationWeights};
+use crate::fixedlp::FixedLp;
+use crate::history::SolutionHistory;
+use crate::iis::Iis;
+use crate::matrix::{LinearBlock, LinearBlockHandle, MatrixError, ModelMatrices};
+#[cfg(feature = "log")]
+use crate::message::LogHandler;
+use crate::message::MessageHandler;
 use crate::node::Node;
 use crate::param::ScipParameter;
+use crate::presolve::{Aggregation, PresolveReport};
 use crate::probing::Prober;
-use crate::retcode::Retcode;
+use crate::progress::ProgressRow;
+use crate::retcode::{Retcode, ScipError};
 use crate::scip::ScipPtr;
-use crate::solution::{SolError, Solution};
+use crate::sensitivity::SensitivityReport;
+use crate::solution::{SolError, Solution, SolutionData};
+use crate::stage::Stage;
 use crate::status::Status;
 use crate::variable::{VarId, VarType, Variable};
-use crate::{BranchRule, HeurTiming, Heuristic, Pricer};
-use crate::{Conshdlr, Diver};
-use crate::{Row, Separator, ffi, scip_call_panic};
+use crate::{BasisStatus, Col, Row, TrySeparator, ffi, scip_call};
+use crate::{BranchRule, Conshdlr, Eventhdlr, Heuristic, Pricer, Separator};
+use crate::{Diver, TryConshdlr};
+use crate::{HeurTiming, TryBranchRule, TryHeuristic, TryPricer};
 use scip_sys::SCIP;
+use std::collections::HashMap;
+use std::ffi::CStr;
 use std::rc::Rc;
 
 /// Represents an optimization model.
@@ -37,6 +57,10 @@ pub struct PluginsIncluded;
 #[derive(Debug, Clone)]
 pub struct ProblemCreated;
 
+/// Represents the state of an optimization model that has been presolved but not solved yet.
+#[derive(Debug)]
+pub struct Presolved;
+
 /// Represents the state of an optimization model during the solving process (to be used in plugins).
 #[derive(Debug)]
 pub struct Solving;
@@ -55,7 +79,7 @@ impl Model<Unsolved> {
     ///
     /// Returns a `Result` with the new `Model` instance on success, or a `Retcode` error on failure.
     pub fn try_new() -> Result<Self, Retcode> {
-        let scip_ptr = ScipPtr::new();
+        let scip_ptr = ScipPtr::new().map_err(|error| error.retcode)?;
         Ok(Model {
             scip: Rc::new(scip_ptr),
             state: Unsolved {},
@@ -75,13 +99,21 @@ impl Model<PluginsIncluded> {
     /// This method panics if the problem cannot be created in the current state.
     #[allow(unused_mut)]
     pub fn create_prob(mut self, name: &str) -> Model<ProblemCreated> {
-        let mut scip = self.scip.clone();
-        scip.create_prob(name)
-            .expect("Failed to create problem in state PluginsIncluded");
-        Model {
-            scip,
+        self.try_create_prob(name)
+            .expect("Failed to create problem in state PluginsIncluded")
+    }
+
+    /// Same as `create_prob`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ScipError` if the problem cannot be created in the current state.
+    pub fn try_create_prob(self, name: &str) -> Result<Model<ProblemCreated>, ScipError> {
+        self.scip.create_prob(name)?;
+        Ok(Model {
+            scip: self.scip,
             state: ProblemCreated {},
-        }
+        })
     }
 
     /// Reads a problem from the given file and returns a new `Model` instance with a `ProblemCreated` state.
@@ -115,12 +147,20 @@ impl Model<ProblemCreated> {
     /// # Panics
     ///
     /// This method panics if the objective sense cannot be set in the current state.
+    #[allow(unused_mut)]
     pub fn set_obj_sense(mut self, sense: ObjSense) -> Self {
-        let scip = self.scip.clone();
-        scip.set_obj_sense(sense)
-            .expect("Failed to set objective sense in state ProblemCreated");
-        self.scip = scip;
-        self
+        self.try_set_obj_sense(sense)
+            .expect("Failed to set objective sense in state ProblemCreated")
+    }
+
+    /// Same as `set_obj_sense`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ScipError` if the objective sense cannot be set in the current state.
+    pub fn try_set_obj_sense(self, sense: ObjSense) -> Result<Self, ScipError> {
+        self.scip.set_obj_sense(sense)?;
+        Ok(self)
     }
 
     /// Sets the objective sense of the model to maximize
@@ -138,10 +178,18 @@ impl Model<ProblemCreated> {
     /// Informs the SCIP instance that the objective value is always integral and returns the same `Model` instance.
     #[allow(unused_mut)]
     pub fn set_obj_integral(mut self) -> Self {
-        self.scip
-            .set_obj_integral()
-            .expect("Failed to set the objective value as integral");
-        self
+        self.try_set_obj_integral()
+            .expect("Failed to set the objective value as integral")
+    }
+
+    /// Same as `set_obj_integral`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ScipError` if the objective cannot be marked as integral in the current state.
+    pub fn try_set_obj_integral(self) -> Result<Self, ScipError> {
+        self.scip.set_obj_integral()?;
+        Ok(self)
     }
 
     /// Adds a new variable to the model with the given lower bound, upper bound, objective coefficient, name, and type.
@@ -169,15 +217,88 @@ impl Model<ProblemCreated> {
         name: &str,
         var_type: VarType,
     ) -> Variable {
-        let var = self
-            .scip
-            .create_var(lb, ub, obj, name, var_type)
-            .expect("Failed to create variable in state ProblemCreated");
+        self.try_add_var(lb, ub, obj, name, var_type)
+            .expect("Failed to create variable in state ProblemCreated")
+    }
 
-        Variable {
+    /// Same as `add_var`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ScipError` if the variable cannot be created in the current state.
+    pub fn try_add_var(
+        &mut self,
+        lb: f64,
+        ub: f64,
+        obj: f64,
+        name: &str,
+        var_type: VarType,
+    ) -> Result<Variable, ScipError> {
+        let var = self.scip.create_var(lb, ub, obj, name, var_type)?;
+
+        Ok(Variable {
             raw: var,
             scip: self.scip.clone(),
-        }
+        })
+    }
+
+    /// Creates a new model (with default plugins) containing only the variables and constraints of the given block.
+    ///
+    /// # Returns
+    ///
+    /// The created model together with a `LinearBlockHandle` to access its variables and constraints by index.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the dimensions of the block are inconsistent or the problem cannot be created.
+    pub fn from_matrices(block: &LinearBlock) -> (Self, LinearBlockHandle) {
+        let mut model = Model::default();
+        let handle = model.add_linear_block(block);
+        (model, handle)
+    }
+
+    /// Adds all variables and linear constraints of the given block to the model in one pass.
+    ///
+    /// Unlike adding them one by one, no `Variable`/`Constraint` handle is allocated per object.
+    /// Variables are named `x{i}` and constraints `c{j}`, where `i` and `j` are their positions in the problem.
+    ///
+    /// # Arguments
+    ///
+    /// * `block` - The objective, constraint matrix, sides, bounds and types of the new variables and constraints.
+    ///
+    /// # Returns
+    ///
+    /// A `LinearBlockHandle` mapping columns and rows of the block to the created variables and constraints.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the dimensions of the block are inconsistent or the objects cannot be created in the current state.
+    pub fn add_linear_block(&mut self, block: &LinearBlock) -> LinearBlockHandle {
+        self.try_add_linear_block(block)
+            .expect("Failed to add linear block in state ProblemCreated")
+    }
+
+    /// Same as `add_linear_block`, but returns an error instead of panicking if SCIP fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ScipError` if the objects cannot be created in the current state.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the dimensions of the block are inconsistent.
+    pub fn try_add_linear_block(
+        &mut self,
+        block: &LinearBlock,
+    ) -> Result<LinearBlockHandle, ScipError> {
+        block.check();
+        let (vars, conss) = self.scip.add_linear_block(block)?;
+
+        Ok(LinearBlockHandle {
+            vars,
+            conss,
+            scip: self.scip.clone(),
+        })
     }
 
     /// Includes a new branch rule in the model with the given name, description, priority, maximum depth, maximum bound distance, and implementation.
@@ -202,6 +323,45 @@ impl Model<ProblemCreated> {
         maxdepth: i32,
         maxbounddist: f64,
         rule: Box<dyn BranchRule>,
+    ) {
+        self.try_include_branch_rule(name, desc, priority, maxdepth, maxbounddist, rule)
+            .expect("Failed to include branch rule at state ProblemCreated");
+    }
+
+    /// Same as `include_branch_rule`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ScipError` if the branching rule cannot be included, e.g. because its name is already taken.
+    pub fn try_include_branch_rule(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        maxdepth: i32,
+        maxbounddist: f64,
+        rule: Box<dyn BranchRule>,
+    ) -> Result<(), ScipError> {
+        self.scip.include_branch_rule(
+            name,
+            desc,
+            priority,
+            maxdepth,
+            maxbounddist,
+            Box::new(Infallible(rule)),
+        )
+    }
+
+    /// Same as `include_branch_rule`, but for a fallible `TryBranchRule`. An error returned by the branch rule aborts
+    /// the solve and is returned by `Model::try_solve`.
+    pub fn include_try_branch_rule(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        maxdepth: i32,
+        maxbounddist: f64,
+        rule: Box<dyn TryBranchRule>,
     ) {
         self.scip
             .include_branch_rule(name, desc, priority, maxdepth, maxbounddist, rule)
@@ -234,6 +394,68 @@ impl Model<ProblemCreated> {
         timing: HeurTiming,
         usessubscip: bool,
         heur: Box<dyn Heuristic>,
+    ) {
+        self.try_include_heur(
+            name,
+            desc,
+            priority,
+            dispchar,
+            freq,
+            freqofs,
+            maxdepth,
+            timing,
+            usessubscip,
+            heur,
+        )
+        .expect("Failed to include heuristic at state ProblemCreated");
+    }
+
+    /// Same as `include_heur`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ScipError` if the heuristic cannot be included, e.g. because its name is already taken.
+    pub fn try_include_heur(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        dispchar: char,
+        freq: i32,
+        freqofs: i32,
+        maxdepth: i32,
+        timing: HeurTiming,
+        usessubscip: bool,
+        heur: Box<dyn Heuristic>,
+    ) -> Result<(), ScipError> {
+        self.scip.include_heur(
+            name,
+            desc,
+            priority,
+            dispchar,
+            freq,
+            freqofs,
+            maxdepth,
+            timing,
+            usessubscip,
+            Box::new(Infallible(heur)),
+        )
+    }
+
+    /// Same as `include_heur`, but for a fallible `TryHeuristic`. An error returned by the heuristic aborts the solve
+    /// and is returned by `Model::try_solve`.
+    pub fn include_try_heur(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        dispchar: char,
+        freq: i32,
+        freqofs: i32,
+        maxdepth: i32,
+        timing: HeurTiming,
+        usessubscip: bool,
+        heur: Box<dyn TryHeuristic>,
     ) {
         self.scip
             .include_heur(
@@ -273,6 +495,60 @@ impl Model<ProblemCreated> {
         usesubscip: bool,
         delay: bool,
         separator: Box<dyn Separator>,
+    ) {
+        self.try_include_separator(
+            name,
+            desc,
+            priority,
+            freq,
+            maxbounddist,
+            usesubscip,
+            delay,
+            separator,
+        )
+        .expect("Failed to include separator at state ProblemCreated");
+    }
+
+    /// Same as `include_separator`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ScipError` if the separator cannot be included, e.g. because its name is already taken.
+    pub fn try_include_separator(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        freq: i32,
+        maxbounddist: f64,
+        usesubscip: bool,
+        delay: bool,
+        separator: Box<dyn Separator>,
+    ) -> Result<(), ScipError> {
+        self.scip.include_separator(
+            name,
+            desc,
+            priority,
+            freq,
+            maxbounddist,
+            usesubscip,
+            delay,
+            Box::new(Infallible(separator)),
+        )
+    }
+
+    /// Same as `include_separator`, but for a fallible `TrySeparator`. An error returned by the separator aborts the
+    /// solve and is returned by `Model::try_solve`.
+    pub fn include_try_separator(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        freq: i32,
+        maxbounddist: f64,
+        usesubscip: bool,
+        delay: bool,
+        separator: Box<dyn TrySeparator>,
     ) {
         self.scip
             .include_separator(
@@ -296,6 +572,33 @@ impl Model<ProblemCreated> {
     /// * `desc` - A brief description of the event handler. This is used for informational purposes.
     /// * `eventhdlr` - The event handler to be included. This should be a mutable reference to an object that implements the `EventHdlr` trait, and represents the event handling logic.
     pub fn include_eventhdlr(&mut self, name: &str, desc: &str, eventhdlr: Box<dyn Eventhdlr>) {
+        self.try_include_eventhdlr(name, desc, eventhdlr)
+            .expect("Failed to include event handler at state ProblemCreated");
+    }
+
+    /// Same as `include_eventhdlr`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ScipError` if the event handler cannot be included, e.g. because its name is already taken.
+    pub fn try_include_eventhdlr(
+        &mut self,
+        name: &str,
+        desc: &str,
+        eventhdlr: Box<dyn Eventhdlr>,
+    ) -> Result<(), ScipError> {
+        self.scip
+            .include_eventhdlr(name, desc, Box::new(Infallible(eventhdlr)))
+    }
+
+    /// Same as `include_eventhdlr`, but for a fallible `TryEventhdlr`. An error returned by the event handler aborts
+    /// the solve and is returned by `Model::try_solve`.
+    pub fn include_try_eventhdlr(
+        &mut self,
+        name: &str,
+        desc: &str,
+        eventhdlr: Box<dyn TryEventhdlr>,
+    ) {
         self.scip
             .include_eventhdlr(name, desc, eventhdlr)
             .expect("Failed to include event handler at state ProblemCreated");
@@ -321,6 +624,37 @@ impl Model<ProblemCreated> {
         priority: i32,
         delay: bool,
         pricer: Box<dyn Pricer>,
+    ) {
+        self.try_include_pricer(name, desc, priority, delay, pricer)
+            .expect("Failed to include pricer at state ProblemCreated");
+    }
+
+    /// Same as `include_pricer`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ScipError` if the pricer cannot be included, e.g. because its name is already taken.
+    pub fn try_include_pricer(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        delay: bool,
+        pricer: Box<dyn Pricer>,
+    ) -> Result<(), ScipError> {
+        self.scip
+            .include_pricer(name, desc, priority, delay, Box::new(Infallible(pricer)))
+    }
+
+    /// Same as `include_pricer`, but for a fallible `TryPricer`. An error returned by the pricer aborts the solve and
+    /// is returned by `Model::try_solve`.
+    pub fn include_try_pricer(
+        &mut self,
+        name: &str,
+        desc: &str,
+        priority: i32,
+        delay: bool,
+        pricer: Box<dyn TryPricer>,
     ) {
         self.scip
             .include_pricer(name, desc, priority, delay, pricer)
@@ -355,12 +689,189 @@ impl Model<ProblemCreated> {
         enfopriority: i32,
         checkpriority: i32,
         conshdlr: Box<dyn Conshdlr>,
+    ) {
+        self.try_include_conshdlr(name, desc, enfopriority, checkpriority, conshdlr)
+            .expect("Failed to include constraint handler at state ProblemCreated");
+    }
+
+    /// Same as `include_conshdlr`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ScipError` if the constraint handler cannot be included, e.g. because its name is already taken.
+    pub fn try_include_conshdlr(
+        &mut self,
+        name: &str,
+        desc: &str,
+        enfopriority: i32,
+        checkpriority: i32,
+        conshdlr: Box<dyn Conshdlr>,
+    ) -> Result<(), ScipError> {
+        self.scip.include_conshdlr(
+            name,
+            desc,
+            enfopriority,
+            checkpriority,
+            Box::new(Infallible(conshdlr)),
+        )
+    }
+
+    /// Same as `include_conshdlr`, but for a fallible `TryConshdlr`. An error returned by the constraint handler aborts
+    /// the solve and is returned by `Model::try_solve`.
+    pub fn include_try_conshdlr(
+        &mut self,
+        name: &str,
+        desc: &str,
+        enfopriority: i32,
+        checkpriority: i32,
+        conshdlr: Box<dyn TryConshdlr>,
     ) {
         self.scip
             .include_conshdlr(name, desc, enfopriority, checkpriority, conshdlr)
             .expect("Failed to include constraint handler at state ProblemCreated");
     }
 
+    /// Records the progress table of the solve, which is returned by `Model::progress` once the model is solved.
+    ///
+    /// A row is recorded at the root node, every `display/freq` nodes, whenever a new best solution is found and
+    /// at the end of the solve, independently of the display verbosity.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the event handler recording the progress cannot be included.
+    pub fn track_progress(self) -> Self {
+        self.scip
+            .track_progress(None)
+            .expect("Failed to include progress event handler in state ProblemCreated");
+        self
+    }
+
+    /// Records the progress table of the solve like `track_progress`, and calls the given callback with every
+    /// row as soon as it is recorded.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the event handler recording the progress cannot be included.
+    pub fn on_progress(self, callback: impl FnMut(&ProgressRow) + 'static) -> Self {
+        self.scip
+            .track_progress(Some(Box::new(callback)))
+            .expect("Failed to include progress event handler in state ProblemCreated");
+        self
+    }
+
+    /// Presolves the model and returns a new `Model` instance with a `Presolved` state, in which the presolved
+    /// problem can be inspected before solving it.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the problem cannot be presolved in the current state or a plugin returned an error.
+    /// If a plugin callback panicked, the panic is resumed here with its original payload.
+    pub fn presolve(self) -> Model<Presolved> {
+        self.try_presolve()
+            .expect("Failed to presolve problem in state ProblemCreated")
+    }
+
+    /// Same as `presolve`, but returns an error instead of panicking.
+    pub fn try_presolve(self) -> Result<Model<Presolved>, SolveError> {
+        self.scip.presolve()?;
+        Ok(Model {
+            scip: self.scip,
+            state: Presolved {},
+        })
+    }
+
+    /// Returns up to `k` of the best solutions, ordered by objective value, which differ in their binary variables.
+    ///
+    /// The solutions are enumerated by repeatedly solving the problem and excluding each found solution with a
+    /// no-good constraint on the binary variables, which is removed again afterwards. Enumerating stops at the first
+    /// solve that does not end optimal, e.g. because a limit was hit.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use russcip::prelude::*;
+    /// use russcip::Enumeration;
+    ///
+    /// let mut model = Model::new()
+    ///     .include_default_plugins()
+    ///     .read_prob("data/test/gen-ip054.mps")
+    ///     .unwrap();
+    /// // the 5 best solutions within 10 of the optimum that differ in at least 2 binary variables
+    /// let sols = model.enumerate_solutions(Enumeration::new(5).obj_tolerance(10.).min_distance(2));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the problem has general integer variables, one of the solves failed or a plugin
+    /// returned an error. If a plugin callback panicked, the panic is resumed here with its original payload.
+    pub fn enumerate_solutions(&mut self, options: Enumeration) -> Vec<SolutionData> {
+        self.try_enumerate_solutions(options)
+            .expect("Failed to enumerate solutions in state ProblemCreated")
+    }
+
+    /// Same as `enumerate_solutions`, but returns an error instead of panicking.
+    pub fn try_enumerate_solutions(
+        &mut self,
+        options: Enumeration,
+    ) -> Result<Vec<SolutionData>, SolveError> {
+        crate::enumeration::enumerate(&self.scip, &options)
+    }
+
+    /// Counts the feasible solutions of the problem with the counter emphasis settings, which only apply during
+    /// counting. The objective is ignored and all variables must be integral.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if counting failed or a plugin returned an error. If a plugin callback panicked, the
+    /// panic is resumed here with its original payload.
+    pub fn count_solutions(&mut self, options: Counting) -> CountResult {
+        self.try_count_solutions(options)
+            .expect("Failed to count solutions in state ProblemCreated")
+    }
+
+    /// Same as `count_solutions`, but returns an error instead of panicking.
+    pub fn try_count_solutions(&mut self, options: Counting) -> Result<CountResult, SolveError> {
+        crate::counting::count(&self.scip, &options)
+    }
+
+    /// Finds the "least infeasible" solution of the problem, minimizing the weighted violation of the constraints
+    /// and variable bounds selected in `weights` while all other constraints and bounds stay hard.
+    ///
+    /// Each relaxed linear constraint gets a nonnegative slack variable per finite side and each relaxed variable
+    /// bound is replaced by a constraint with a slack variable. The elastic problem is solved with the original
+    /// objective ignored, after which the slack variables are removed again, leaving the problem unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use russcip::prelude::*;
+    /// use russcip::RelaxationWeights;
+    ///
+    /// let mut model = Model::new().include_default_plugins().create_prob("plan");
+    /// let x = model.add_var(0., 10., 1., "x", VarType::Integer);
+    /// let demand = model.add_cons(vec![&x], &[1.], 12., f64::INFINITY, "demand");
+    /// let relaxed = model.feasibility_relaxation(RelaxationWeights::new().cons(&demand, 1.));
+    /// assert_eq!(relaxed.total_violation, 2.);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method panics if a weight is negative, a relaxed constraint is not linear, a relaxed variable is
+    /// binary, or the solve failed. If a plugin callback panicked, the panic is resumed here with its original
+    /// payload.
+    pub fn feasibility_relaxation(&mut self, weights: RelaxationWeights) -> FeasRelaxation {
+        self.try_feasibility_relaxation(weights)
+            .expect("Failed to solve feasibility relaxation in state ProblemCreated")
+    }
+
+    /// Same as `feasibility_relaxation`, but returns an error instead of panicking.
+    pub fn try_feasibility_relaxation(
+        &mut self,
+        weights: RelaxationWeights,
+    ) -> Result<FeasRelaxation, SolveError> {
+        crate::feasrelax::relax(&self.scip, &weights)
+    }
+
     /// Solves the model and returns a new `Model` instance with a `Solved` state.
     ///
     /// # Returns
@@ -369,16 +880,55 @@ impl Model<ProblemCreated> {
     ///
     /// # Panics
     ///
-    /// This method panics if the problem cannot be solved in the current state.
+    /// This method panics if the problem cannot be solved in the current state or a plugin returned an error.
+    /// If a plugin callback panicked, SCIP aborts the solve and the panic is resumed here with its original payload.
     #[allow(unused_mut)]
     pub fn solve(mut self) -> Model<Solved> {
-        self.scip
-            .solve()
-            .expect("Failed to solve problem in state ProblemCreated");
-        Model {
+        self.try_solve()
+            .expect("Failed to solve problem in state ProblemCreated")
+    }
+
+    /// Solves the model and returns a new `Model` instance with a `Solved` state, or the error that aborted the
+    /// solve.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Model<Solved>)` if the solve terminated, e.g. with an optimal solution or by reaching a limit.
+    /// * `Err(SolveError::Plugin)` if a fallible plugin (e.g. a `TryHeuristic`) returned an error.
+    /// * `Err(SolveError::Scip)` if SCIP failed.
+    ///
+    /// # Panics
+    ///
+    /// If a plugin callback panicked, SCIP aborts the solve and the panic is resumed here with its original payload.
+    pub fn try_solve(self) -> Result<Model<Solved>, SolveError> {
+        self.scip.solve()?;
+        Ok(Model {
             scip: self.scip,
             state: Solved {},
-        }
+        })
+    }
+}
+
+impl Model<Presolved> {
+    /// Solves the presolved model and returns a new `Model` instance with a `Solved` state.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the problem cannot be solved in the current state or a plugin returned an error.
+    /// If a plugin callback panicked, SCIP aborts the solve and the panic is resumed here with its original payload.
+    pub fn solve(self) -> Model<Solved> {
+        self.try_solve()
+            .expect("Failed to solve problem in state Presolved")
+    }
+
+    /// Same as `solve`, but returns the error that aborted the solve instead of panicking, see
+    /// `Model::<ProblemCreated>::try_solve`.
+    pub fn try_solve(self) -> Result<Model<Solved>, SolveError> {
+        self.scip.solve()?;
+        Ok(Model {
+            scip: self.scip,
+            state: Solved {},
+        })
     }
 }
 
@@ -397,60 +947,88 @@ impl Model<Solving> {
     ///
     /// The created `Variable`
     ///
-    /// # Panics
+    /// # Panics
+    ///
+    /// This method panics if the variable cannot be created in the current state.
+    pub fn add_var(
+        &mut self,
+        lb: f64,
+        ub: f64,
+        obj: f64,
+        name: &str,
+        var_type: VarType,
+    ) -> Variable {
+        self.try_add_var(lb, ub, obj, name, var_type)
+            .expect("Failed to create variable in state ProblemCreated")
+    }
+
+    /// Same as `add_var`, but returns an error instead of panicking.
+    ///
+    /// # Errors
     ///
-    /// This method panics if the variable cannot be created in the current state.
-    pub fn add_var(
+    /// Returns a `ScipError` if the variable cannot be created in the current state.
+    pub fn try_add_var(
         &mut self,
         lb: f64,
         ub: f64,
         obj: f64,
         name: &str,
         var_type: VarType,
-    ) -> Variable {
-        let var = self
-            .scip
-            .create_var_solving(lb, ub, obj, name, var_type)
-            .expect("Failed to create variable in state ProblemCreated");
+    ) -> Result<Variable, ScipError> {
+        let var = self.scip.create_var_solving(lb, ub, obj, name, var_type)?;
 
-        Variable {
+        Ok(Variable {
             raw: var,
             scip: self.scip.clone(),
-        }
+        })
     }
 
     /// Creates a new solution initialized to zero.
     pub fn create_sol(&self) -> Solution {
-        let sol_ptr = self
-            .scip
-            .create_sol(false)
-            .expect("Failed to create solution in state ProblemCreated");
-        Solution {
+        self.try_create_sol()
+            .expect("Failed to create solution in state ProblemCreated")
+    }
+
+    /// Same as `create_sol`, but returns an error instead of panicking.
+    pub fn try_create_sol(&self) -> Result<Solution, ScipError> {
+        let sol_ptr = self.scip.create_sol(false)?;
+        Ok(Solution {
             scip_ptr: self.scip.clone(),
             raw: sol_ptr,
-        }
+        })
     }
 
     /// Returns the current node of the model.
     pub fn focus_node(&self) -> Node {
-        let scip_node = self.scip.focus_node().expect("Failed to get focus node");
-        Node {
+        self.try_focus_node().expect("Failed to get focus node")
+    }
+
+    /// Same as `focus_node`, but returns an error instead of panicking, e.g. if there is no focus node.
+    pub fn try_focus_node(&self) -> Result<Node, ScipError> {
+        let scip_node = self
+            .scip
+            .focus_node()
+            .ok_or(ScipError::new(Retcode::InvalidCall, "SCIPgetFocusNode"))?;
+        Ok(Node {
             raw: scip_node,
             scip: self.scip.clone(),
-        }
+        })
     }
 
     /// Creates a new child node of the current node and returns it.
     pub fn create_child(&mut self) -> Node {
-        let node_ptr = self
-            .scip
-            .create_child()
-            .expect("Failed to create child node in state ProblemCreated");
+        self.try_create_child()
+            .expect("Failed to create child node in state ProblemCreated")
+    }
+
+    /// Same as `create_child`, but returns an error instead of panicking.
+    pub fn try_create_child(&mut self) -> Result<Node, ScipError> {
+        let node_ptr = self.scip.create_child()?;
 
-        Node {
+        Ok(Node {
             raw: node_ptr,
             scip: self.scip.clone(),
-        }
+        })
     }
 
     /// Adds a new priced variable to the SCIP data structure.
@@ -474,15 +1052,29 @@ impl Model<Solving> {
         name: &str,
         var_type: VarType,
     ) -> Variable {
-        let var = self
-            .scip
-            .create_priced_var(lb, ub, obj, name, var_type)
-            .expect("Failed to create variable in state ProblemCreated");
+        self.try_add_priced_var(lb, ub, obj, name, var_type)
+            .expect("Failed to create variable in state ProblemCreated")
+    }
+
+    /// Same as `add_priced_var`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ScipError` if the variable cannot be created or added to the pricing storage.
+    pub fn try_add_priced_var(
+        &mut self,
+        lb: f64,
+        ub: f64,
+        obj: f64,
+        name: &str,
+        var_type: VarType,
+    ) -> Result<Variable, ScipError> {
+        let var = self.scip.create_priced_var(lb, ub, obj, name, var_type)?;
 
-        Variable {
+        Ok(Variable {
             raw: var,
             scip: self.scip.clone(),
-        }
+        })
     }
 
     /// Locally adds a constraint to the current node and its subnodes.
@@ -499,25 +1091,32 @@ impl Model<Solving> {
     ///
     /// This method panics if the constraint cannot be created in the current state.
     pub fn add_cons_local(&mut self, cons: &ConsBuilder) -> Constraint {
+        self.try_add_cons_local(cons)
+            .expect("Failed to create constraint in state Solving")
+    }
+
+    /// Same as `add_cons_local`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ScipError` if the constraint cannot be created in the current state.
+    pub fn try_add_cons_local(&mut self, cons: &ConsBuilder) -> Result<Constraint, ScipError> {
         let vars: Vec<&Variable> = cons.coefs.iter().map(|(var, _)| *var).collect();
         let coefs: Vec<f64> = cons.coefs.iter().map(|(_, coef)| *coef).collect();
 
-        let cons = self
-            .scip
-            .create_cons(
-                None,
-                vars,
-                &coefs,
-                cons.lhs,
-                cons.rhs,
-                cons.name.unwrap_or(""),
-                true,
-            )
-            .expect("Failed to create constraint in state Solving");
-        Constraint {
+        let cons = self.scip.create_cons(
+            None,
+            vars,
+            &coefs,
+            cons.lhs,
+            cons.rhs,
+            cons.name.unwrap_or(""),
+            true,
+        )?;
+        Ok(Constraint {
             raw: cons,
             scip: self.scip.clone(),
-        }
+        })
     }
 
     /// Locally adds a constraint to a given node and its children.
@@ -535,26 +1134,37 @@ impl Model<Solving> {
     ///
     /// This method panics if the constraint cannot be created in the current state.
     pub fn add_cons_node(&mut self, node: &Node, cons: &ConsBuilder) -> Constraint {
+        self.try_add_cons_node(node, cons)
+            .expect("Failed to create constraint in state ProblemCreated")
+    }
+
+    /// Same as `add_cons_node`, but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ScipError` if the constraint cannot be created or added to the node.
+    pub fn try_add_cons_node(
+        &mut self,
+        node: &Node,
+        cons: &ConsBuilder,
+    ) -> Result<Constraint, ScipError> {
         let vars: Vec<&Variable> = cons.coefs.iter().map(|(var, _)| *var).collect();
         let coefs: Vec<f64> = cons.coefs.iter().map(|(_, coef)| *coef).collect();
 
-        let cons = self
-            .scip
-            .create_cons(
-                Some(node),
-                vars,
-                &coefs,
-                cons.lhs,
-                cons.rhs,
-                cons.name.unwrap_or(""),
-                true,
-            )
-            .expect("Failed to create constraint in state ProblemCreated");
-
-        Constraint {
+        let cons = self.scip.create_cons(
+            Some(node),
+            vars,
+            &coefs,
+            cons.lhs,
+            cons.rhs,
+            cons.name.unwrap_or(""),
+            true,
+        )?;
+
+        Ok(Constraint {
             raw: cons,
             scip: self.scip.clone(),
-        }
+        })
     }
 
     /// Returns the number of added constraints to the given nodes
@@ -593,11 +1203,15 @@ impl Model<Solving> {
     /// # Returns
     /// A boolean indicating whether the row is infeasible from the local bounds.
     pub fn add_cut(&mut self, cut: Row, force_cut: bool) -> bool {
-        self.scip
-            .add_row(cut, force_cut)
+        self.try_add_cut(cut, force_cut)
             .expect("Failed to add row in state ProblemCreated")
     }
 
+    /// Same as `add_cut`, but returns an error instead of panicking.
+    pub fn try_add_cut(&mut self, cut: Row, force_cut: bool) -> Result<bool, ScipError> {
+        self.scip.add_row(cut, force_cut)
+    }
+
     /// Returns the value of a variable in the current LP/pseudo solution.
     ///
     /// #Arguments
@@ -643,28 +1257,131 @@ impl Model<Solving> {
         self.scip.lp_status()
     }
 
+    /// Returns the columns of the current LP, ordered by their position in the LP.
+    pub fn lp_cols(&self) -> Vec<Col> {
+        self.scip
+            .lp_cols()
+            .into_iter()
+            .map(|raw| Col {
+                raw,
+                scip: self.scip.clone(),
+            })
+            .collect()
+    }
+
+    /// Returns the rows of the current LP, ordered by their position in the LP.
+    pub fn lp_rows(&self) -> Vec<Row> {
+        self.scip
+            .lp_rows()
+            .into_iter()
+            .map(|raw| Row {
+                raw,
+                scip: self.scip.clone(),
+            })
+            .collect()
+    }
+
+    /// Returns the basis status of the columns and rows of the current LP, ordered like `lp_cols` and `lp_rows`,
+    /// or `None` if the LP is not solved to optimality with a basic solution.
+    ///
+    /// The basis can be passed to `Prober::set_lp_basis` to warm start the LP of a probing node, or to
+    /// `Lpi::set_base` for a standalone LP with the same columns and rows. Diving LPs cannot be warm started.
+    pub fn lp_basis(&self) -> Option<(Vec<BasisStatus>, Vec<BasisStatus>)> {
+        self.scip.lp_basis()
+    }
+
     /// Changes the upper bound of the variable in a given node.
     pub fn set_ub_node(&mut self, node: &Node, var: &Variable, ub: f64) {
-        scip_call_panic!(ffi::SCIPchgVarUbNode(
+        self.try_set_ub_node(node, var, ub)
+            .expect("Failed to set upper bound of variable in node in state Solving");
+    }
+
+    /// Same as `set_ub_node`, but returns an error instead of panicking.
+    pub fn try_set_ub_node(
+        &mut self,
+        node: &Node,
+        var: &Variable,
+        ub: f64,
+    ) -> Result<(), ScipError> {
+        scip_call!(ffi::SCIPchgVarUbNode(
             self.scip.raw,
             node.inner(),
             var.inner(),
             ub
         ));
+        Ok(())
     }
 
     /// Changes the lower bound of the variable in a given node.
     pub fn set_lb_node(&mut self, node: &Node, var: &Variable, lb: f64) {
-        scip_call_panic!(ffi::SCIPchgVarLbNode(
+        self.try_set_lb_node(node, var, lb)
+            .expect("Failed to set lower bound of variable in node in state Solving");
+    }
+
+    /// Same as `set_lb_node`, but returns an error instead of panicking.
+    pub fn try_set_lb_node(
+        &mut self,
+        node: &Node,
+        var: &Variable,
+        lb: f64,
+    ) -> Result<(), ScipError> {
+        scip_call!(ffi::SCIPchgVarLbNode(
             self.scip.raw,
             node.inner(),
             var.inner(),
             lb
         ));
+        Ok(())
     }
 }
 
 impl Model<Solved> {
+    /// Continues an interrupted solve with the given limits and returns the model once it stops again.
+    ///
+    /// The search tree, solutions and statistics of the previous solve are kept, so a solve that hit a limit
+    /// (e.g. `Status::TimeLimit` or `Status::NodeLimit`) continues where it stopped. The limits are totals for the
+    /// whole solve, so they have to be raised above the values reached so far. Resuming a solve that finished
+    /// (e.g. with `Status::Optimal`) returns right away.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use russcip::prelude::*;
+    /// use russcip::Limits;
+    ///
+    /// let mut solved = Model::new()
+    ///     .hide_output()
+    ///     .include_default_plugins()
+    ///     .read_prob("data/test/gen-ip054.mps")
+    ///     .unwrap()
+    ///     .set_time_limit(10)
+    ///     .solve();
+    /// let mut time = 10.0;
+    /// while solved.status() == Status::TimeLimit {
+    ///     // checkpoint the best solution here
+    ///     time += 10.0;
+    ///     solved = solved.resume(Limits {
+    ///         time: Some(time),
+    ///         ..Limits::default()
+    ///     });
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method panics if a limit cannot be set or the solve fails, see `Model::try_resume`.
+    pub fn resume(self, limits: Limits) -> Model<Solved> {
+        self.try_resume(limits)
+            .expect("Failed to resume solve in state Solved")
+    }
+
+    /// Same as `resume`, but returns the error that aborted the solve instead of panicking.
+    pub fn try_resume(self, limits: Limits) -> Result<Model<Solved>, SolveError> {
+        limits.apply(&self.scip)?;
+        self.scip.solve()?;
+        Ok(self)
+    }
+
     /// Returns the objective value of the best solution found by the optimization model.
     pub fn obj_val(&self) -> f64 {
         self.scip.obj_val()
@@ -685,16 +1402,94 @@ impl Model<Solved> {
         self.scip.n_lp_iterations()
     }
 
+    /// Returns the progress table recorded during the solve, empty if `track_progress` or `on_progress` were not
+    /// called before solving.
+    pub fn progress(&self) -> Vec<ProgressRow> {
+        self.scip
+            .progress_tracker()
+            .map(|tracker| tracker.rows.clone())
+            .unwrap_or_default()
+    }
+
+    /// Computes an irreducible infeasible subsystem (IIS) of an infeasible problem, i.e. a set of original
+    /// constraints and variable bounds that is infeasible on its own but becomes feasible if any of them is
+    /// removed.
+    ///
+    /// The subsystem is computed by SCIP's IIS finders (`iis/...` parameters) on a copy of the original problem,
+    /// so the problem itself is left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if no IIS finder is included, the problem is not infeasible, the computation failed or
+    /// an item of the subsystem has no unique name in the original problem.
+    pub fn compute_iis(&self) -> Iis {
+        self.try_compute_iis()
+            .expect("Failed to compute IIS in state Solved")
+    }
+
+    /// Same as `compute_iis`, but returns an error instead of panicking.
+    pub fn try_compute_iis(&self) -> Result<Iis, ScipError> {
+        crate::iis::compute(&self.scip)
+    }
+
+    /// Returns the sensitivity of the optimal solution to the objective coefficients and the constraint sides.
+    ///
+    /// The report is computed by the LP solver on the LP of the original problem, so it is not affected by
+    /// presolving. For problems with integer variables, these are fixed at their values in the best solution
+    /// first, which gives the duals and ranges of the "fix integers and resolve" LP. All constraints must have a
+    /// linear representation.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if a constraint is not linear, a problem with integer variables has no solution, or
+    /// the LP could not be solved to optimality.
+    pub fn sensitivity(&self) -> SensitivityReport {
+        self.try_sensitivity()
+            .expect("Failed to compute sensitivity report in state Solved")
+    }
+
+    /// Same as `sensitivity`, but returns an error instead of panicking.
+    pub fn try_sensitivity(&self) -> Result<SensitivityReport, ScipError> {
+        crate::sensitivity::analyze(&self.scip)
+    }
+
+    /// Fixes the integer and binary variables at their values in the given solution, resolves the remaining LP
+    /// and returns the duals of the original constraints in it, e.g. to price a MIP solution.
+    ///
+    /// The LP is solved on a copy of the original problem with presolving, propagation, heuristics, separation and
+    /// dual reductions turned off, so that the duals of all constraints are available. The copy is returned
+    /// alongside the duals, while this model is left unchanged. Like `ConsSensitivity::dual`, the duals refer to the
+    /// original objective sense, i.e. they are the change of the optimal objective value per unit increase of the
+    /// active side of a constraint.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the problem could not be copied, e.g. because it contains constraints of a custom
+    /// constraint handler without a copy callback, or if the LP could not be solved.
+    pub fn fixed_lp_duals(&self, sol: &Solution) -> FixedLp {
+        self.try_fixed_lp_duals(sol)
+            .expect("Failed to solve fixed LP in state Solved")
+    }
+
+    /// Same as `fixed_lp_duals`, but returns an error instead of panicking.
+    pub fn try_fixed_lp_duals(&self, sol: &Solution) -> Result<FixedLp, SolveError> {
+        crate::fixedlp::solve(&self.scip, sol)
+    }
+
     /// Frees the transformed problem and returns the model the ProblemCreated state where you
     /// can add variables and constraints, useful for iterated solving
     pub fn free_transform(self) -> Model<ProblemCreated> {
-        self.scip
-            .free_transform()
-            .unwrap_or_else(|retcode| panic!("SCIP returned unexpected retcode {retcode:?}"));
-        Model {
+        self.try_free_transform()
+            .unwrap_or_else(|error| panic!("SCIP returned unexpected retcode {:?}", error.retcode))
+    }
+
+    /// Same as `free_transform`, but returns an error instead of panicking.
+    pub fn try_free_transform(self) -> Result<Model<ProblemCreated>, ScipError> {
+        self.scip.free_transform()?;
+        Ok(Model {
             scip: self.scip,
             state: ProblemCreated {},
-        }
+        })
     }
 }
 
@@ -731,11 +1526,23 @@ pub trait ModelWithProblem {
 
     /// Writes the optimization model to a file with the given path and extension.
     fn write(&self, path: &str, ext: &str) -> Result<(), Retcode>;
+
+    /// Returns the current problem (original before solving, transformed afterwards) as sparse matrices.
+    ///
+    /// Linear constraints and constraints with a linear representation (set partitioning/packing/covering,
+    /// logicor, knapsack and varbound) are exported, negated and aggregated variables are resolved.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MatrixError::NonLinear` listing all constraints that cannot be expressed linearly, or
+    /// `MatrixError::MultiAggregated` listing all constraints on variables that presolving multi-aggregated.
+    fn to_matrices(&self) -> Result<ModelMatrices, MatrixError>;
 }
 
 /// A trait for model stages that have a problem.
 pub trait ModelStageWithProblem {}
 impl ModelStageWithProblem for ProblemCreated {}
+impl ModelStageWithProblem for Presolved {}
 impl ModelStageWithProblem for Solved {}
 impl ModelStageWithProblem for Solving {}
 
@@ -829,6 +1636,46 @@ impl<S: ModelStageWithProblem> ModelWithProblem for Model<S> {
         self.scip.write(path, ext)?;
         Ok(())
     }
+
+    /// Returns the current problem as sparse matrices.
+    fn to_matrices(&self) -> Result<ModelMatrices, MatrixError> {
+        let to_cons = |raw| Constraint {
+            raw,
+            scip: self.scip.clone(),
+        };
+        let (block, vars, conss) =
+            self.scip
+                .to_matrices()
+                .map_err(|(non_linear, multi_aggregated)| {
+                    if non_linear.is_empty() {
+                        MatrixError::MultiAggregated(
+                            multi_aggregated.into_iter().map(to_cons).collect(),
+                        )
+                    } else {
+                        MatrixError::NonLinear(non_linear.into_iter().map(to_cons).collect())
+                    }
+                })?;
+
+        let stage = unsafe { ffi::SCIPgetStage(self.scip.raw) };
+        let obj_sense = if stage == ffi::SCIP_Stage_SCIP_STAGE_PROBLEM {
+            unsafe { ffi::SCIPgetObjsense(self.scip.raw) }.into()
+        } else {
+            ObjSense::Minimize
+        };
+
+        Ok(ModelMatrices {
+            obj_sense,
+            block,
+            vars: vars
+                .into_iter()
+                .map(|raw| Variable {
+                    raw,
+                    scip: self.scip.clone(),
+                })
+                .collect(),
+            conss: conss.into_iter().map(to_cons).collect(),
+        })
+    }
 }
 
 /// A trait for optimization models with a problem created or solved.
@@ -836,12 +1683,36 @@ pub trait ProblemOrSolving {
     /// Create a solution in the original space
     fn create_orig_sol(&self) -> Solution;
 
+    /// Same as `create_orig_sol`, but returns an error instead of panicking.
+    fn try_create_orig_sol(&self) -> Result<Solution, ScipError>;
+
     /// Adds a solution to the model
     ///
     /// # Returns
-    /// A `Result` indicating whether the solution was added successfully.
+    /// A `Result` indicating whether the solution was added successfully. Use `check_sol_detailed` to find out
+    /// why a solution is infeasible.
     fn add_sol(&self, sol: Solution) -> Result<(), SolError>;
 
+    /// Creates an original solution from a snapshot taken with `Solution::snapshot`, e.g. of a solution of
+    /// another model of the same problem. Variables are matched by name, variables missing from the snapshot are
+    /// set to zero.
+    ///
+    /// # Returns
+    /// The solution, which can be added with `add_sol`, or `SolError::UnknownVariable` if the snapshot contains a
+    /// variable that the model does not have.
+    fn solution_from_data(&self, data: &SolutionData) -> Result<Solution, SolError>;
+
+    /// Checks a solution without adding it, listing the violated constraints, bounds and integrality
+    /// requirements. The reasons for infeasibility are also printed by SCIP.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if SCIP fails to check the solution.
+    fn check_sol_detailed(&self, sol: &Solution) -> FeasibilityReport;
+
+    /// Same as `check_sol_detailed`, but returns an error instead of panicking.
+    fn try_check_sol_detailed(&self, sol: &Solution) -> Result<FeasibilityReport, ScipError>;
+
     /// Adds a binary variable to the given set partitioning constraint.
     ///
     /// # Arguments
@@ -854,6 +1725,17 @@ pub trait ProblemOrSolving {
     /// This method panics if the variable cannot be added in the current state, or if the variable is not binary.
     fn add_cons_coef_setppc(&mut self, cons: &Constraint, var: &Variable);
 
+    /// Same as `add_cons_coef_setppc`, but returns an error instead of panicking if SCIP fails.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the variable is not binary.
+    fn try_add_cons_coef_setppc(
+        &mut self,
+        cons: &Constraint,
+        var: &Variable,
+    ) -> Result<(), ScipError>;
+
     /// Adds a coefficient to the given constraint for the given variable and coefficient value.
     ///
     /// # Arguments
@@ -867,6 +1749,14 @@ pub trait ProblemOrSolving {
     /// This method panics if the coefficient cannot be added in the current state.
     fn add_cons_coef(&mut self, cons: &Constraint, var: &Variable, coef: f64);
 
+    /// Same as `add_cons_coef`, but returns an error instead of panicking.
+    fn try_add_cons_coef(
+        &mut self,
+        cons: &Constraint,
+        var: &Variable,
+        coef: f64,
+    ) -> Result<(), ScipError>;
+
     /// Adds a new quadratic constraint to the model with the given variables, coefficients, left-hand side, right-hand side, and name.
     ///
     /// # Arguments
@@ -899,6 +1789,23 @@ pub trait ProblemOrSolving {
         name: &str,
     ) -> Constraint;
 
+    /// Same as `add_cons_quadratic`, but returns an error instead of panicking if SCIP fails.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the lengths of the variable and coefficient slices differ.
+    fn try_add_cons_quadratic(
+        &mut self,
+        lin_vars: Vec<&Variable>,
+        lin_coefs: &mut [f64],
+        quad_vars_1: Vec<&Variable>,
+        quad_vars_2: Vec<&Variable>,
+        quad_coefs: &mut [f64],
+        lhs: f64,
+        rhs: f64,
+        name: &str,
+    ) -> Result<Constraint, ScipError>;
+
     /// Adds a new constraint to the model with the given variables, coefficients, left-hand side, right-hand side, and name.
     ///
     /// # Arguments
@@ -925,6 +1832,20 @@ pub trait ProblemOrSolving {
         name: &str,
     ) -> Constraint;
 
+    /// Same as `add_cons`, but returns an error instead of panicking if SCIP fails.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `vars` and `coefs` have different lengths.
+    fn try_add_cons(
+        &mut self,
+        vars: Vec<&Variable>,
+        coefs: &[f64],
+        lhs: f64,
+        rhs: f64,
+        name: &str,
+    ) -> Result<Constraint, ScipError>;
+
     /// Adds a new set partitioning constraint to the model with the given variables and name.
     ///
     /// # Arguments
@@ -941,6 +1862,17 @@ pub trait ProblemOrSolving {
     /// This method panics if the constraint cannot be created in the current state, or if any of the variables are not binary.
     fn add_cons_set_part(&mut self, vars: Vec<&Variable>, name: &str) -> Constraint;
 
+    /// Same as `add_cons_set_part`, but returns an error instead of panicking if SCIP fails.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if any of the variables are not binary.
+    fn try_add_cons_set_part(
+        &mut self,
+        vars: Vec<&Variable>,
+        name: &str,
+    ) -> Result<Constraint, ScipError>;
+
     /// Adds a new set cover constraint to the model with the given variables and name.
     ///
     /// # Arguments
@@ -957,6 +1889,17 @@ pub trait ProblemOrSolving {
     /// This method panics if the constraint cannot be created in the current state, or if any of the variables are not binary.
     fn add_cons_set_cover(&mut self, vars: Vec<&Variable>, name: &str) -> Constraint;
 
+    /// Same as `add_cons_set_cover`, but returns an error instead of panicking if SCIP fails.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if any of the variables are not binary.
+    fn try_add_cons_set_cover(
+        &mut self,
+        vars: Vec<&Variable>,
+        name: &str,
+    ) -> Result<Constraint, ScipError>;
+
     /// Adds a new set packing constraint to the model with the given variables and name.
     ///
     /// # Arguments
@@ -973,6 +1916,17 @@ pub trait ProblemOrSolving {
     /// This method panics if the constraint cannot be created in the current state, or if any of the variables are not binary.
     fn add_cons_set_pack(&mut self, vars: Vec<&Variable>, name: &str) -> Constraint;
 
+    /// Same as `add_cons_set_pack`, but returns an error instead of panicking if SCIP fails.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if any of the variables are not binary.
+    fn try_add_cons_set_pack(
+        &mut self,
+        vars: Vec<&Variable>,
+        name: &str,
+    ) -> Result<Constraint, ScipError>;
+
     /// Adds a new cardinality constraint to the model with the given variables, cardinality limit, and name.
     ///
     /// # Arguments
@@ -995,6 +1949,14 @@ pub trait ProblemOrSolving {
         name: &str,
     ) -> Constraint;
 
+    /// Same as `add_cons_cardinality`, but returns an error instead of panicking.
+    fn try_add_cons_cardinality(
+        &mut self,
+        vars: Vec<&Variable>,
+        cardinality: usize,
+        name: &str,
+    ) -> Result<Constraint, ScipError>;
+
     /// Adds a new indicator constraint to the model with the given variables, coefficients, right-hand side, and name.
     ///
     /// # Arguments
@@ -1021,15 +1983,50 @@ pub trait ProblemOrSolving {
         name: &str,
     ) -> Constraint;
 
+    /// Same as `add_cons_indicator`, but returns an error instead of panicking if SCIP fails.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `bin_var` is not binary or `vars` and `coefs` have different lengths.
+    fn try_add_cons_indicator(
+        &mut self,
+        bin_var: &Variable,
+        vars: Vec<&Variable>,
+        coefs: &mut [f64],
+        rhs: f64,
+        name: &str,
+    ) -> Result<Constraint, ScipError>;
+
     /// Sets the constraint as modifiable or not.
     fn set_cons_modifiable(&mut self, cons: &Constraint, modifiable: bool);
 
+    /// Same as `set_cons_modifiable`, but returns an error instead of panicking.
+    fn try_set_cons_modifiable(
+        &mut self,
+        cons: &Constraint,
+        modifiable: bool,
+    ) -> Result<(), ScipError>;
+
     /// Sets the constraint as removable or not.
     fn set_cons_removable(&mut self, cons: &Constraint, removable: bool);
 
+    /// Same as `set_cons_removable`, but returns an error instead of panicking.
+    fn try_set_cons_removable(
+        &mut self,
+        cons: &Constraint,
+        removable: bool,
+    ) -> Result<(), ScipError>;
+
     /// Sets whether the constraint should be separated during LP processing
     fn set_cons_separated(&mut self, cons: &Constraint, separate: bool);
 
+    /// Same as `set_cons_separated`, but returns an error instead of panicking.
+    fn try_set_cons_separated(
+        &mut self,
+        cons: &Constraint,
+        separate: bool,
+    ) -> Result<(), ScipError>;
+
     /// Adds a new SOS1 constraint to the model with the given variables, optional weights, and name.
     ///
     /// # Arguments
@@ -1047,6 +2044,14 @@ pub trait ProblemOrSolving {
         weights: Option<&[f64]>,
         name: &str,
     ) -> Constraint;
+
+    /// Same as `add_cons_sos1`, but returns an error instead of panicking.
+    fn try_add_cons_sos1(
+        &mut self,
+        vars: Vec<&Variable>,
+        weights: Option<&[f64]>,
+        name: &str,
+    ) -> Result<Constraint, ScipError>;
 }
 
 /// A trait for model stages that have a problem or are during solving.
@@ -1057,14 +2062,16 @@ impl ModelStageProblemOrSolving for Solving {}
 impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
     /// Create a new solution in the original space
     fn create_orig_sol(&self) -> Solution {
-        let sol_ptr = self
-            .scip
-            .create_sol(true)
-            .expect("Failed to create solution in state ProblemCreated");
-        Solution {
+        self.try_create_orig_sol()
+            .expect("Failed to create solution in state ProblemCreated")
+    }
+
+    fn try_create_orig_sol(&self) -> Result<Solution, ScipError> {
+        let sol_ptr = self.scip.create_sol(true)?;
+        Ok(Solution {
             scip_ptr: self.scip.clone(),
             raw: sol_ptr,
-        }
+        })
     }
 
     /// Adds a solution to the model
@@ -1072,7 +2079,7 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
     /// # Returns
     /// A `Result` indicating whether the solution was added successfully.
     fn add_sol(&self, sol: Solution) -> Result<(), SolError> {
-        let succesfully_stored = self.scip.add_sol(sol).expect("Failed to add solution");
+        let succesfully_stored = self.scip.add_sol(sol)?;
         if succesfully_stored {
             Ok(())
         } else {
@@ -1080,6 +2087,40 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
         }
     }
 
+    fn solution_from_data(&self, data: &SolutionData) -> Result<Solution, SolError> {
+        let vars: HashMap<String, *mut ffi::SCIP_VAR> = self
+            .scip
+            .vars(true, false)
+            .into_values()
+            .map(|var| {
+                let name = unsafe { CStr::from_ptr(ffi::SCIPvarGetName(var)) };
+                (name.to_string_lossy().into_owned(), var)
+            })
+            .collect();
+        let sol = self.try_create_orig_sol()?;
+        for value in &data.values {
+            let var = vars
+                .get(&value.name)
+                .ok_or_else(|| SolError::UnknownVariable(value.name.clone()))?;
+            scip_call!(ffi::SCIPsetSolVal(
+                self.scip.raw,
+                sol.raw,
+                *var,
+                value.value
+            ));
+        }
+        Ok(sol)
+    }
+
+    fn check_sol_detailed(&self, sol: &Solution) -> FeasibilityReport {
+        self.try_check_sol_detailed(sol)
+            .expect("Failed to check solution")
+    }
+
+    fn try_check_sol_detailed(&self, sol: &Solution) -> Result<FeasibilityReport, ScipError> {
+        FeasibilityReport::collect(&self.scip, sol.raw)
+    }
+
     /// Adds a binary variable to the given set partitioning constraint.
     ///
     /// # Arguments
@@ -1091,12 +2132,19 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
     ///
     /// This method panics if the variable cannot be added in the current state, or if the variable is not binary.
     fn add_cons_coef_setppc(&mut self, cons: &Constraint, var: &Variable) {
-        assert_eq!(var.var_type(), VarType::Binary);
-        self.scip
-            .add_cons_coef_setppc(cons, var)
+        self.try_add_cons_coef_setppc(cons, var)
             .expect("Failed to add constraint coefficient in state ProblemCreated");
     }
 
+    fn try_add_cons_coef_setppc(
+        &mut self,
+        cons: &Constraint,
+        var: &Variable,
+    ) -> Result<(), ScipError> {
+        assert_eq!(var.var_type(), VarType::Binary);
+        self.scip.add_cons_coef_setppc(cons, var)
+    }
+
     /// Adds a coefficient to the given constraint for the given variable and coefficient value.
     ///
     /// # Arguments
@@ -1109,11 +2157,19 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
     ///
     /// This method panics if the coefficient cannot be added in the current state.
     fn add_cons_coef(&mut self, cons: &Constraint, var: &Variable, coef: f64) {
-        self.scip
-            .add_cons_coef(cons, var, coef)
+        self.try_add_cons_coef(cons, var, coef)
             .expect("Failed to add constraint coefficient in state ProblemCreated");
     }
 
+    fn try_add_cons_coef(
+        &mut self,
+        cons: &Constraint,
+        var: &Variable,
+        coef: f64,
+    ) -> Result<(), ScipError> {
+        self.scip.add_cons_coef(cons, var, coef)
+    }
+
     /// Adds a new quadratic constraint to the model with the given variables, coefficients, left-hand side, right-hand side, and name.
     ///
     /// # Arguments
@@ -1145,27 +2201,48 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
         rhs: f64,
         name: &str,
     ) -> Constraint {
+        self.try_add_cons_quadratic(
+            lin_vars,
+            lin_coefs,
+            quad_vars_1,
+            quad_vars_2,
+            quad_coefs,
+            lhs,
+            rhs,
+            name,
+        )
+        .expect("Failed to create constraint in state ProblemCreated")
+    }
+
+    fn try_add_cons_quadratic(
+        &mut self,
+        lin_vars: Vec<&Variable>,
+        lin_coefs: &mut [f64],
+        quad_vars_1: Vec<&Variable>,
+        quad_vars_2: Vec<&Variable>,
+        quad_coefs: &mut [f64],
+        lhs: f64,
+        rhs: f64,
+        name: &str,
+    ) -> Result<Constraint, ScipError> {
         assert_eq!(lin_vars.len(), lin_coefs.len());
         assert_eq!(quad_vars_1.len(), quad_vars_2.len());
         assert_eq!(quad_vars_1.len(), quad_coefs.len());
-        let cons = self
-            .scip
-            .create_cons_quadratic(
-                lin_vars,
-                lin_coefs,
-                quad_vars_1,
-                quad_vars_2,
-                quad_coefs,
-                lhs,
-                rhs,
-                name,
-            )
-            .expect("Failed to create constraint in state ProblemCreated");
-
-        Constraint {
+        let cons = self.scip.create_cons_quadratic(
+            lin_vars,
+            lin_coefs,
+            quad_vars_1,
+            quad_vars_2,
+            quad_coefs,
+            lhs,
+            rhs,
+            name,
+        )?;
+
+        Ok(Constraint {
             raw: cons,
             scip: self.scip.clone(),
-        }
+        })
     }
 
     /// Adds a new constraint to the model with the given variables, coefficients, left-hand side, right-hand side, and name.
@@ -1193,16 +2270,27 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
         rhs: f64,
         name: &str,
     ) -> Constraint {
+        self.try_add_cons(vars, coefs, lhs, rhs, name)
+            .expect("Failed to create constraint in state ProblemCreated")
+    }
+
+    fn try_add_cons(
+        &mut self,
+        vars: Vec<&Variable>,
+        coefs: &[f64],
+        lhs: f64,
+        rhs: f64,
+        name: &str,
+    ) -> Result<Constraint, ScipError> {
         assert_eq!(vars.len(), coefs.len());
         let cons = self
             .scip
-            .create_cons(None, vars, coefs, lhs, rhs, name, false)
-            .expect("Failed to create constraint in state ProblemCreated");
+            .create_cons(None, vars, coefs, lhs, rhs, name, false)?;
 
-        Constraint {
+        Ok(Constraint {
             raw: cons,
             scip: self.scip.clone(),
-        }
+        })
     }
 
     /// Adds a new set partitioning constraint to the model with the given variables and name.
@@ -1220,16 +2308,22 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
     ///
     /// This method panics if the constraint cannot be created in the current state, or if any of the variables are not binary.
     fn add_cons_set_part(&mut self, vars: Vec<&Variable>, name: &str) -> Constraint {
+        self.try_add_cons_set_part(vars, name)
+            .expect("Failed to add constraint set partition in state ProblemCreated")
+    }
+
+    fn try_add_cons_set_part(
+        &mut self,
+        vars: Vec<&Variable>,
+        name: &str,
+    ) -> Result<Constraint, ScipError> {
         assert!(vars.iter().all(|v| v.var_type() == VarType::Binary));
-        let cons = self
-            .scip
-            .create_cons_set_part(vars, name)
-            .expect("Failed to add constraint set partition in state ProblemCreated");
+        let cons = self.scip.create_cons_set_part(vars, name)?;
 
-        Constraint {
+        Ok(Constraint {
             raw: cons,
             scip: self.scip.clone(),
-        }
+        })
     }
 
     /// Adds a new set cover constraint to the model with the given variables and name.
@@ -1247,16 +2341,22 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
     ///
     /// This method panics if the constraint cannot be created in the current state, or if any of the variables are not binary.
     fn add_cons_set_cover(&mut self, vars: Vec<&Variable>, name: &str) -> Constraint {
+        self.try_add_cons_set_cover(vars, name)
+            .expect("Failed to add constraint set cover in state ProblemCreated")
+    }
+
+    fn try_add_cons_set_cover(
+        &mut self,
+        vars: Vec<&Variable>,
+        name: &str,
+    ) -> Result<Constraint, ScipError> {
         assert!(vars.iter().all(|v| v.var_type() == VarType::Binary));
-        let cons = self
-            .scip
-            .create_cons_set_cover(vars, name)
-            .expect("Failed to add constraint set cover in state ProblemCreated");
+        let cons = self.scip.create_cons_set_cover(vars, name)?;
 
-        Constraint {
+        Ok(Constraint {
             raw: cons,
             scip: self.scip.clone(),
-        }
+        })
     }
 
     /// Adds a new set packing constraint to the model with the given variables and name.
@@ -1274,16 +2374,22 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
     ///
     /// This method panics if the constraint cannot be created in the current state, or if any of the variables are not binary.
     fn add_cons_set_pack(&mut self, vars: Vec<&Variable>, name: &str) -> Constraint {
+        self.try_add_cons_set_pack(vars, name)
+            .expect("Failed to add constraint set packing in state ProblemCreated")
+    }
+
+    fn try_add_cons_set_pack(
+        &mut self,
+        vars: Vec<&Variable>,
+        name: &str,
+    ) -> Result<Constraint, ScipError> {
         assert!(vars.iter().all(|v| v.var_type() == VarType::Binary));
-        let cons = self
-            .scip
-            .create_cons_set_pack(vars, name)
-            .expect("Failed to add constraint set packing in state ProblemCreated");
+        let cons = self.scip.create_cons_set_pack(vars, name)?;
 
-        Constraint {
+        Ok(Constraint {
             raw: cons,
             scip: self.scip.clone(),
-        }
+        })
     }
 
     /// Adds a new cardinality constraint to the model with the given variables, cardinality limit, and name.
@@ -1307,15 +2413,22 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
         cardinality: usize,
         name: &str,
     ) -> Constraint {
-        let cons = self
-            .scip
-            .create_cons_cardinality(vars, cardinality, name)
-            .expect("Failed to add cardinality constraint");
+        self.try_add_cons_cardinality(vars, cardinality, name)
+            .expect("Failed to add cardinality constraint")
+    }
+
+    fn try_add_cons_cardinality(
+        &mut self,
+        vars: Vec<&Variable>,
+        cardinality: usize,
+        name: &str,
+    ) -> Result<Constraint, ScipError> {
+        let cons = self.scip.create_cons_cardinality(vars, cardinality, name)?;
 
-        Constraint {
+        Ok(Constraint {
             raw: cons,
             scip: self.scip.clone(),
-        }
+        })
     }
 
     /// Adds a new indicator constraint to the model with the given variables, coefficients, right-hand side, and name.
@@ -1343,17 +2456,28 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
         rhs: f64,
         name: &str,
     ) -> Constraint {
+        self.try_add_cons_indicator(bin_var, vars, coefs, rhs, name)
+            .expect("Failed to create constraint in state ProblemCreated")
+    }
+
+    fn try_add_cons_indicator(
+        &mut self,
+        bin_var: &Variable,
+        vars: Vec<&Variable>,
+        coefs: &mut [f64],
+        rhs: f64,
+        name: &str,
+    ) -> Result<Constraint, ScipError> {
         assert_eq!(vars.len(), coefs.len());
         assert_eq!(bin_var.var_type(), VarType::Binary);
         let cons = self
             .scip
-            .create_cons_indicator(bin_var, vars, coefs, rhs, name)
-            .expect("Failed to create constraint in state ProblemCreated");
+            .create_cons_indicator(bin_var, vars, coefs, rhs, name)?;
 
-        Constraint {
+        Ok(Constraint {
             raw: cons,
             scip: self.scip.clone(),
-        }
+        })
     }
 
     /// Adds a new SOS1 constraint to the model with the given variables, optional weights, and name.
@@ -1373,37 +2497,65 @@ impl<S: ModelStageProblemOrSolving> ProblemOrSolving for Model<S> {
         weights: Option<&[f64]>,
         name: &str,
     ) -> Constraint {
-        let cons = self
-            .scip
-            .create_cons_sos1(vars, weights, name)
-            .expect("Failed to create SOS1 constraint");
+        self.try_add_cons_sos1(vars, weights, name)
+            .expect("Failed to create SOS1 constraint")
+    }
+
+    fn try_add_cons_sos1(
+        &mut self,
+        vars: Vec<&Variable>,
+        weights: Option<&[f64]>,
+        name: &str,
+    ) -> Result<Constraint, ScipError> {
+        let cons = self.scip.create_cons_sos1(vars, weights, name)?;
 
-        Constraint {
+        Ok(Constraint {
             raw: cons,
             scip: self.scip.clone(),
-        }
+        })
     }
 
     /// Sets the constraint as modifiable or not.
     fn set_cons_modifiable(&mut self, cons: &Constraint, modifiable: bool) {
-        self.scip
-            .set_cons_modifiable(cons, modifiable)
+        self.try_set_cons_modifiable(cons, modifiable)
             .expect("Failed to set constraint modifiable");
     }
 
+    fn try_set_cons_modifiable(
+        &mut self,
+        cons: &Constraint,
+        modifiable: bool,
+    ) -> Result<(), ScipError> {
+        self.scip.set_cons_modifiable(cons, modifiable)
+    }
+
     /// Sets the constraint as removable or not.
     fn set_cons_removable(&mut self, cons: &Constraint, removable: bool) {
-        self.scip
-            .set_cons_removable(cons, removable)
+        self.try_set_cons_removable(cons, removable)
             .expect("Failed to set constraint removable");
     }
 
+    fn try_set_cons_removable(
+        &mut self,
+        cons: &Constraint,
+        removable: bool,
+    ) -> Result<(), ScipError> {
+        self.scip.set_cons_removable(cons, removable)
+    }
+
     /// Sets whether the constraint should be separated during LP processing
     fn set_cons_separated(&mut self, cons: &Constraint, separate: bool) {
-        self.scip
-            .set_cons_separated(cons, separate)
+        self.try_set_cons_separated(cons, separate)
             .expect("Failed to set constraint separated");
     }
+
+    fn try_set_cons_separated(
+        &mut self,
+        cons: &Constraint,
+        separate: bool,
+    ) -> Result<(), ScipError> {
+        self.scip.set_cons_separated(cons, separate)
+    }
 }
 
 /// A trait for optimization models with any state that might have solutions.
@@ -1416,12 +2568,17 @@ pub trait WithSolutions {
 
     /// Returns the number of solutions found by the optimization model.
     fn n_sols(&self) -> usize;
+
+    /// Returns the activities of all original constraints in the given solution, in the order of the problem.
+    /// Constraints that cannot be represented as a single linear constraint are left out.
+    fn constraint_activities(&self, sol: &Solution) -> Vec<(Constraint, f64)>;
 }
 
 trait ModelStageWithSolutions {}
 impl ModelStageWithSolutions for Solved {}
 impl ModelStageWithSolutions for Solving {}
 impl ModelStageWithSolutions for ProblemCreated {}
+impl ModelStageWithSolutions for Presolved {}
 
 impl<S: ModelStageWithSolutions> WithSolutions for Model<S> {
     /// Returns the best solution for the optimization model, if one exists.
@@ -1460,6 +2617,21 @@ impl<S: ModelStageWithSolutions> WithSolutions for Model<S> {
             None
         }
     }
+
+    fn constraint_activities(&self, sol: &Solution) -> Vec<(Constraint, f64)> {
+        self.scip
+            .orig_conss()
+            .into_iter()
+            .filter_map(|raw| {
+                let cons = Constraint {
+                    raw,
+                    scip: self.scip.clone(),
+                };
+                let activity = sol.activity(&cons)?;
+                Some((cons, activity))
+            })
+            .collect()
+    }
 }
 
 /// A trait for optimization models with any state that might have solving statistics.
@@ -1478,12 +2650,17 @@ pub trait WithSolvingStats {
 
     /// Returns the number of LP iterations performed by the optimization model.
     fn n_lp_iterations(&self) -> usize;
+
+    /// Returns the stored solutions together with the time, node and heuristic of their discovery, and the
+    /// statistics of the heuristics that were called.
+    fn solution_history(&self) -> SolutionHistory;
 }
 
 trait ModelStageWithSolvingStats {}
 impl ModelStageWithSolvingStats for Solved {}
 impl ModelStageWithSolvingStats for Solving {}
 impl ModelStageWithSolvingStats for ProblemCreated {}
+impl ModelStageWithSolvingStats for Presolved {}
 
 impl<S: ModelStageWithSolvingStats> WithSolvingStats for Model<S> {
     /// Returns the objective value of the best solution found by the optimization model.
@@ -1510,6 +2687,70 @@ impl<S: ModelStageWithSolvingStats> WithSolvingStats for Model<S> {
     fn n_lp_iterations(&self) -> usize {
         self.scip.n_lp_iterations()
     }
+
+    fn solution_history(&self) -> SolutionHistory {
+        SolutionHistory::collect(&self.scip)
+    }
+}
+
+/// A trait for optimization models with a transformed (and possibly presolved) problem.
+pub trait WithTransformedProblem {
+    /// Writes the transformed problem to a file with the given path and extension, which determines the format.
+    fn write_transformed(&self, path: &str, ext: &str) -> Result<(), ScipError>;
+
+    /// Returns a summary of the reductions performed by presolving, in total and per presolving method.
+    fn presolve_report(&self) -> PresolveReport;
+
+    /// Returns the affine expression of active variables that the given variable was replaced by in the
+    /// transformed problem, e.g. the fixed value of a variable removed by presolving.
+    ///
+    /// Returns `None` if the variable is original and has no transformed counterpart.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if SCIP fails to resolve the variable.
+    fn aggregation(&self, var: &Variable) -> Option<Aggregation>;
+
+    /// Same as `aggregation`, but returns an error instead of panicking.
+    fn try_aggregation(&self, var: &Variable) -> Result<Option<Aggregation>, ScipError>;
+}
+
+trait ModelStageWithTransformedProblem {}
+impl ModelStageWithTransformedProblem for Presolved {}
+impl ModelStageWithTransformedProblem for Solving {}
+impl ModelStageWithTransformedProblem for Solved {}
+
+impl<S: ModelStageWithTransformedProblem> WithTransformedProblem for Model<S> {
+    fn write_transformed(&self, path: &str, ext: &str) -> Result<(), ScipError> {
+        self.scip.write_transformed(path, ext)
+    }
+
+    fn presolve_report(&self) -> PresolveReport {
+        PresolveReport::collect(self.scip.raw)
+    }
+
+    fn aggregation(&self, var: &Variable) -> Option<Aggregation> {
+        self.try_aggregation(var)
+            .expect("Failed to get aggregation of variable")
+    }
+
+    fn try_aggregation(&self, var: &Variable) -> Result<Option<Aggregation>, ScipError> {
+        let Some((vars, scalars, constant)) = self.scip.probvar_linear_sum(var.inner())? else {
+            return Ok(None);
+        };
+        let terms = vars
+            .into_iter()
+            .zip(scalars)
+            .map(|(raw, scalar)| {
+                let var = Variable {
+                    raw,
+                    scip: self.scip.clone(),
+                };
+                (var, scalar)
+            })
+            .collect();
+        Ok(Some(Aggregation { constant, terms }))
+    }
 }
 
 /// Creates a minimal `Model` instance and sets off a lot of SCIP plugins, useful for writing tests.
@@ -1541,6 +2782,12 @@ impl<T> Model<T> {
         self.scip.status()
     }
 
+    /// Returns the stage of the SCIP instance, which is finer than the state of the model, e.g. a `Model<Solving>`
+    /// passed to a plugin can be in presolving or in solving.
+    pub fn stage(&self) -> Stage {
+        self.scip.stage()
+    }
+
     /// Prints the version of SCIP used by the optimization model.
     pub fn print_version(&self) {
         self.scip.print_version()
@@ -1549,10 +2796,14 @@ impl<T> Model<T> {
     /// Sets the `display/verblevel` parameter to the provided value.
     #[allow(unused_mut)]
     pub fn set_display_verbosity(mut self, level: i32) -> Self {
-        self.scip
-            .set_int_param("display/verblevel", level)
-            .unwrap_or_else(|_| panic!("Failed to set display/verblevel to {level}"));
-        self
+        self.try_set_display_verbosity(level)
+            .unwrap_or_else(|_| panic!("Failed to set display/verblevel to {level}"))
+    }
+
+    /// Same as `set_display_verbosity`, but returns an error instead of panicking, e.g. if the level is out of range.
+    pub fn try_set_display_verbosity(self, level: i32) -> Result<Self, ScipError> {
+        self.scip.set_int_param("display/verblevel", level)?;
+        Ok(self)
     }
 
     /// Shows the output of the optimization model by setting the `display/verblevel` parameter to its default value 4.
@@ -1567,6 +2818,44 @@ impl<T> Model<T> {
         self.set_display_verbosity(0)
     }
 
+    /// Routes the output of SCIP to the given handler instead of stdout and returns the same `Model` instance.
+    ///
+    /// The handler is owned by the SCIP instance and dropped together with it, or when it is replaced by another
+    /// handler. Messages are still filtered by the `display/verblevel` parameter. Output written to files, e.g. by
+    /// `write` or to the log file, does not go through the handler.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the message handler cannot be created.
+    #[allow(unused_mut)]
+    pub fn set_message_handler(mut self, handler: impl MessageHandler + 'static) -> Self {
+        self.try_set_message_handler(handler)
+            .expect("Failed to set message handler")
+    }
+
+    /// Same as `set_message_handler`, but returns an error instead of panicking.
+    pub fn try_set_message_handler(
+        self,
+        handler: impl MessageHandler + 'static,
+    ) -> Result<Self, ScipError> {
+        self.scip.set_message_handler(Box::new(handler))?;
+        Ok(self)
+    }
+
+    /// Routes the output of SCIP to the `log` facade (see `LogHandler`) and returns the same `Model` instance.
+    #[cfg(feature = "log")]
+    pub fn log_messages(self) -> Self {
+        self.set_message_handler(LogHandler)
+    }
+
+    /// Additionally writes the output of SCIP to the file at the given path and returns the same `Model` instance.
+    ///
+    /// The log file belongs to the current message handler, so it has to be set after `set_message_handler`.
+    pub fn set_log_file(self, path: &str) -> Self {
+        self.scip.set_log_file(path);
+        self
+    }
+
     /// Sets the time limit for the optimization model.
     ///
     /// # Arguments
@@ -1574,10 +2863,14 @@ impl<T> Model<T> {
     /// * `time_limit` - The time limit in seconds.
     #[allow(unused_mut)]
     pub fn set_time_limit(mut self, time_limit: usize) -> Self {
-        self.scip
-            .set_real_param("limits/time", time_limit as f64)
-            .expect("Failed to set time limit");
-        self
+        self.try_set_time_limit(time_limit)
+            .expect("Failed to set time limit")
+    }
+
+    /// Same as `set_time_limit`, but returns an error instead of panicking.
+    pub fn try_set_time_limit(self, time_limit: usize) -> Result<Self, ScipError> {
+        self.scip.set_real_param("limits/time", time_limit as f64)?;
+        Ok(self)
     }
 
     /// Sets the memory limit for the optimization model.
@@ -1587,22 +2880,31 @@ impl<T> Model<T> {
     /// * `memory_limit` - The memory limit in MB.
     #[allow(unused_mut)]
     pub fn set_memory_limit(mut self, memory_limit: usize) -> Self {
+        self.try_set_memory_limit(memory_limit)
+            .expect("Failed to set memory limit")
+    }
+
+    /// Same as `set_memory_limit`, but returns an error instead of panicking.
+    pub fn try_set_memory_limit(self, memory_limit: usize) -> Result<Self, ScipError> {
         self.scip
-            .set_real_param("limits/memory", memory_limit as f64)
-            .expect("Failed to set memory limit");
-        self
+            .set_real_param("limits/memory", memory_limit as f64)?;
+        Ok(self)
     }
 
     /// Includes all default plugins in the SCIP instance and returns a new `Model` instance with a `PluginsIncluded` state.
     #[allow(unused_mut)]
     pub fn include_default_plugins(mut self) -> Model<PluginsIncluded> {
-        self.scip
-            .include_default_plugins()
-            .expect("Failed to include default plugins");
-        Model {
+        self.try_include_default_plugins()
+            .expect("Failed to include default plugins")
+    }
+
+    /// Same as `include_default_plugins`, but returns an error instead of panicking.
+    pub fn try_include_default_plugins(self) -> Result<Model<PluginsIncluded>, ScipError> {
+        self.scip.include_default_plugins()?;
+        Ok(Model {
             scip: self.scip,
             state: PluginsIncluded {},
-        }
+        })
     }
 
     /// Sets a SCIP string parameter and returns a new `Model` instance with the parameter set.
@@ -1642,10 +2944,13 @@ impl<T> Model<T> {
 
     /// Returns the value of a SCIP string parameter.
     pub fn str_param(&self, param: &str) -> String {
-        self.scip
-            .str_param(param)
+        self.try_str_param(param)
             .expect("Failed to get string parameter")
-            .to_string()
+    }
+
+    /// Returns the value of a SCIP string parameter, or an error if there is no such parameter.
+    pub fn try_str_param(&self, param: &str) -> Result<String, ScipError> {
+        Ok(self.scip.str_param(param)?.to_string())
     }
 
     /// Returns the value of a SCIP paramter.
@@ -1653,6 +2958,11 @@ impl<T> Model<T> {
         P::get(self, param)
     }
 
+    /// Returns the value of a SCIP parameter, or an error if there is no such parameter of type `P`.
+    pub fn try_param<P: ScipParameter>(&self, param: &str) -> Result<P, ScipError> {
+        P::try_get(self, param)
+    }
+
     /// Tries to set the value of a SCIP parameter and returns the same `Model` instance if successful.
     pub fn try_set_param<P: ScipParameter>(
         self,
@@ -1669,57 +2979,85 @@ impl<T> Model<T> {
 
     /// Returns the value of a SCIP boolean parameter.
     pub fn bool_param(&self, param: &str) -> bool {
-        self.scip
-            .bool_param(param)
+        self.try_bool_param(param)
             .expect("Failed to get boolean parameter")
     }
 
+    /// Returns the value of a SCIP boolean parameter, or an error if there is no such parameter.
+    pub fn try_bool_param(&self, param: &str) -> Result<bool, ScipError> {
+        self.scip.bool_param(param)
+    }
+
     /// Returns the value of a SCIP integer parameter.
     pub fn int_param(&self, param: &str) -> i32 {
-        self.scip
-            .int_param(param)
+        self.try_int_param(param)
             .expect("Failed to get integer parameter")
     }
 
+    /// Returns the value of a SCIP integer parameter, or an error if there is no such parameter.
+    pub fn try_int_param(&self, param: &str) -> Result<i32, ScipError> {
+        self.scip.int_param(param)
+    }
+
     /// Returns the value of a SCIP long integer parameter.
     pub fn longint_param(&self, param: &str) -> i64 {
-        self.scip
-            .longint_param(param)
+        self.try_longint_param(param)
             .expect("Failed to get long integer parameter")
     }
 
+    /// Returns the value of a SCIP long integer parameter, or an error if there is no such parameter.
+    pub fn try_longint_param(&self, param: &str) -> Result<i64, ScipError> {
+        self.scip.longint_param(param)
+    }
+
     /// Returns the value of a SCIP real parameter.
     pub fn real_param(&self, param: &str) -> f64 {
-        self.scip
-            .real_param(param)
+        self.try_real_param(param)
             .expect("Failed to get real parameter")
     }
 
+    /// Returns the value of a SCIP real parameter, or an error if there is no such parameter.
+    pub fn try_real_param(&self, param: &str) -> Result<f64, ScipError> {
+        self.scip.real_param(param)
+    }
+
     /// Sets the presolving parameter of the SCIP instance and returns the same `Model` instance.
     #[allow(unused_mut)]
     pub fn set_presolving(mut self, presolving: ParamSetting) -> Self {
-        self.scip
-            .set_presolving(presolving)
-            .expect("Failed to set presolving with valid value");
-        self
+        self.try_set_presolving(presolving)
+            .expect("Failed to set presolving with valid value")
+    }
+
+    /// Same as `set_presolving`, but returns an error instead of panicking.
+    pub fn try_set_presolving(self, presolving: ParamSetting) -> Result<Self, ScipError> {
+        self.scip.set_presolving(presolving)?;
+        Ok(self)
     }
 
     /// Sets the separating parameter of the SCIP instance and returns the same `Model` instance.
     #[allow(unused_mut)]
     pub fn set_separating(mut self, separating: ParamSetting) -> Self {
-        self.scip
-            .set_separating(separating)
-            .expect("Failed to set separating with valid value");
-        self
+        self.try_set_separating(separating)
+            .expect("Failed to set separating with valid value")
+    }
+
+    /// Same as `set_separating`, but returns an error instead of panicking.
+    pub fn try_set_separating(self, separating: ParamSetting) -> Result<Self, ScipError> {
+        self.scip.set_separating(separating)?;
+        Ok(self)
     }
 
     /// Sets the heuristics parameter of the SCIP instance and returns the same `Model` instance.
     #[allow(unused_mut)]
     pub fn set_heuristics(mut self, heuristics: ParamSetting) -> Self {
-        self.scip
-            .set_heuristics(heuristics)
-            .expect("Failed to set heuristics with valid value");
-        self
+        self.try_set_heuristics(heuristics)
+            .expect("Failed to set heuristics with valid value")
+    }
+
+    /// Same as `set_heuristics`, but returns an error instead of panicking.
+    pub fn try_set_heuristics(self, heuristics: ParamSetting) -> Result<Self, ScipError> {
+        self.scip.set_heuristics(heuristics)?;
+        Ok(self)
     }
 
     /// Checks equality using tolerance.
@@ -1755,19 +3093,37 @@ impl<T> Model<T> {
     #[cfg(feature = "datastore")]
     /// Set generic data attached to the model
     pub fn set_data<D: 'static>(&mut self, data: D) {
-        self.scip.set_store(data).expect("Failed to set data");
+        self.try_set_data(data).expect("Failed to set data");
+    }
+
+    #[cfg(feature = "datastore")]
+    /// Same as `set_data`, but returns an error instead of panicking.
+    pub fn try_set_data<D: 'static>(&mut self, data: D) -> Result<(), ScipError> {
+        self.scip.set_store(data)
     }
 
     #[cfg(feature = "datastore")]
     /// Retrieves a reference to a generic data type attached to the model
     pub fn get_data<D: 'static>(&self) -> Option<&D> {
-        self.scip.get_store::<D>().expect("Failed to get data")
+        self.try_get_data::<D>().expect("Failed to get data")
+    }
+
+    #[cfg(feature = "datastore")]
+    /// Same as `get_data`, but returns an error instead of panicking.
+    pub fn try_get_data<D: 'static>(&self) -> Result<Option<&D>, ScipError> {
+        self.scip.get_store::<D>()
     }
 
     #[cfg(feature = "datastore")]
     /// Returns a mutable reference to generic data attached to the model
     pub fn get_data_mut<D: 'static>(&mut self) -> Option<&mut D> {
-        self.scip.get_mut_store::<D>().expect("Failed to get data")
+        self.try_get_data_mut::<D>().expect("Failed to get data")
+    }
+
+    #[cfg(feature = "datastore")]
+    /// Same as `get_data_mut`, but returns an error instead of panicking.
+    pub fn try_get_data_mut<D: 'static>(&mut self) -> Result<Option<&mut D>, ScipError> {
+        self.scip.get_mut_store::<D>()
     }
 }
 
@@ -1794,6 +3150,68 @@ pub enum ParamSetting {
     Off,
 }
 
+/// Limits of a solve, used to continue an interrupted solve with `Model::resume`.
+///
+/// Every limit that is `Some` overwrites the corresponding `limits/*` parameter; like the parameters, the limits
+/// apply to the whole solve, including the part before resuming.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Limits {
+    /// The total solving time in seconds (`limits/time`).
+    pub time: Option<f64>,
+    /// The maximum number of processed nodes, -1 for no limit (`limits/nodes`).
+    pub nodes: Option<i64>,
+    /// The maximum number of processed nodes including restarts, -1 for no limit (`limits/totalnodes`).
+    pub total_nodes: Option<i64>,
+    /// The number of nodes processed without improving the primal bound, -1 for no limit (`limits/stallnodes`).
+    pub stall_nodes: Option<i64>,
+    /// The relative gap at which the solve stops (`limits/gap`).
+    pub gap: Option<f64>,
+    /// The number of found solutions at which the solve stops, -1 for no limit (`limits/solutions`).
+    pub solutions: Option<i32>,
+    /// The memory limit in MB (`limits/memory`).
+    pub memory: Option<f64>,
+}
+
+impl Limits {
+    /// Returns limits that disable the time, node, stalling, gap and solution limits.
+    pub fn unlimited() -> Self {
+        Limits {
+            time: Some(1e20),
+            nodes: Some(-1),
+            total_nodes: Some(-1),
+            stall_nodes: Some(-1),
+            gap: Some(0.0),
+            solutions: Some(-1),
+            memory: None,
+        }
+    }
+
+    fn apply(&self, scip: &ScipPtr) -> Result<(), ScipError> {
+        if let Some(time) = self.time {
+            scip.set_real_param("limits/time", time)?;
+        }
+        if let Some(nodes) = self.nodes {
+            scip.set_longint_param("limits/nodes", nodes)?;
+        }
+        if let Some(total_nodes) = self.total_nodes {
+            scip.set_longint_param("limits/totalnodes", total_nodes)?;
+        }
+        if let Some(stall_nodes) = self.stall_nodes {
+            scip.set_longint_param("limits/stallnodes", stall_nodes)?;
+        }
+        if let Some(gap) = self.gap {
+            scip.set_real_param("limits/gap", gap)?;
+        }
+        if let Some(solutions) = self.solutions {
+            scip.set_int_param("limits/solutions", solutions)?;
+        }
+        if let Some(memory) = self.memory {
+            scip.set_real_param("limits/memory", memory)?;
+        }
+        Ok(())
+    }
+}
+
 impl From<ParamSetting> for ffi::SCIP_PARAMSETTING {
     /// Converts a `ParamSetting` enum variant into its corresponding `ffi::SCIP_PARAMSETTING` value.
     fn from(val: ParamSetting) -> Self {
@@ -1807,7 +3225,7 @@ impl From<ParamSetting> for ffi::SCIP_PARAMSETTING {
 }
 
 /// An enum representing the objective sense of a SCIP optimization model.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ObjSense {
     /// The problem is a minimization problem.
     Minimize,
@@ -1825,6 +3243,16 @@ impl From<ObjSense> for ffi::SCIP_OBJSENSE {
     }
 }
 
+impl From<ffi::SCIP_OBJSENSE> for ObjSense {
+    /// Converts a `ffi::SCIP_OBJSENSE` value into its corresponding `ObjSense` enum variant.
+    fn from(val: ffi::SCIP_OBJSENSE) -> Self {
+        match val {
+            ffi::SCIP_Objsense_SCIP_OBJSENSE_MAXIMIZE => ObjSense::Maximize,
+            _ => ObjSense::Minimize,
+        }
+    }
+}
+
 /// Status of the LP solver
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LPStatus {
@@ -2308,6 +3736,61 @@ mod tests {
         assert_eq!(res, Retcode::ParameterWrongVal);
     }
 
+    #[test]
+    fn resume_after_node_limit() {
+        let solved = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/gen-ip054.mps")
+            .unwrap()
+            .set_longint_param("limits/nodes", 5)
+            .unwrap()
+            .solve();
+        assert_eq!(solved.status(), Status::NodeLimit);
+        assert_eq!(solved.n_nodes(), 5);
+
+        let solved = solved.resume(Limits {
+            nodes: Some(10),
+            ..Limits::default()
+        });
+        assert_eq!(solved.status(), Status::NodeLimit);
+        assert_eq!(solved.n_nodes(), 10);
+
+        let solved = solved.resume(Limits::unlimited());
+        assert_eq!(solved.status(), Status::Optimal);
+        assert!(solved.n_nodes() > 10);
+
+        // resuming a finished solve does nothing
+        let n_nodes = solved.n_nodes();
+        let solved = solved.resume(Limits::unlimited());
+        assert_eq!(solved.status(), Status::Optimal);
+        assert_eq!(solved.n_nodes(), n_nodes);
+    }
+
+    #[test]
+    fn try_methods_return_errors() {
+        let model = Model::new().hide_output();
+        let err = model.try_int_param("no/such/param").unwrap_err();
+        assert_eq!(err.retcode, Retcode::ParameterUnknown);
+        assert_eq!(err.function, "SCIPgetIntParam");
+        assert_eq!(
+            err.to_string(),
+            "SCIPgetIntParam failed: the parameter with the given name was not found"
+        );
+
+        // names with interior NUL bytes are rejected instead of panicking
+        let err = model.try_int_param("display/\0verblevel").unwrap_err();
+        assert_eq!(err.retcode, Retcode::InvalidData);
+
+        let err = model.try_set_display_verbosity(-1).unwrap_err();
+        assert_eq!(err.retcode, Retcode::ParameterWrongVal);
+
+        let mut model = Model::default().hide_output();
+        let x = model.try_add_var(0., 1., 1., "x", VarType::Binary).unwrap();
+        assert!(model.try_add_cons(vec![&x], &[1.], 0., 1., "c").is_ok());
+        assert!(model.try_solve().is_ok());
+    }
+
     #[test]
     fn set_real_param() {
         let model = Model::new()
@@ -0,0 +1,264 @@
+use crate::{BasisStatus, Model, Solving, VarType, WithSolutions, ffi};
+
+/// Names of the constraint features, in the order they are stored in `BipartiteObservation::cons_features`.
+pub const CONS_FEATURES: [&str; 5] = [
+    "obj_cosine_similarity",
+    "bias",
+    "is_tight",
+    "dual_sol_val",
+    "scaled_age",
+];
+
+/// Names of the variable features, in the order they are stored in `BipartiteObservation::var_features`.
+pub const VAR_FEATURES: [&str; 21] = [
+    "obj",
+    "is_type_binary",
+    "is_type_integer",
+    "is_type_implicit_integer",
+    "is_type_continuous",
+    "has_lb",
+    "has_ub",
+    "lb",
+    "ub",
+    "reduced_cost",
+    "sol_val",
+    "sol_frac",
+    "sol_is_at_lb",
+    "sol_is_at_ub",
+    "scaled_age",
+    "has_incumbent",
+    "incumbent_val",
+    "is_basis_lower",
+    "is_basis_basic",
+    "is_basis_upper",
+    "is_basis_zero",
+];
+
+/// Names of the edge features, in the order they are stored in `BipartiteObservation::edge_features`.
+pub const EDGE_FEATURES: [&str; 1] = ["coef_normalized"];
+
+/// The variable–constraint bipartite graph of the LP relaxation at the focus node, as used by
+/// Gasse et al. (2019), "Exact Combinatorial Optimization with Graph Convolutional Neural Networks".
+///
+/// Following the paper, every LP row `lhs <= a x <= rhs` is turned into one constraint `-a x <= -lhs` for a finite
+/// left-hand side and one constraint `a x <= rhs` for a finite right-hand side, so that all constraints are `<=`.
+/// Features are stored row-major in flat vectors, e.g. feature `j` of variable `i` is
+/// `var_features[i * VAR_FEATURES.len() + j]`, which allows them to be exported without conversion.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BipartiteObservation {
+    /// The number of constraint nodes.
+    pub n_conss: usize,
+    /// The number of variable nodes (one per LP column).
+    pub n_vars: usize,
+    /// The constraint features, `n_conss` rows of `CONS_FEATURES.len()` values.
+    pub cons_features: Vec<f64>,
+    /// The variable features, `n_vars` rows of `VAR_FEATURES.len()` values.
+    pub var_features: Vec<f64>,
+    /// The constraint node of each edge.
+    pub edge_conss: Vec<usize>,
+    /// The variable node of each edge.
+    pub edge_vars: Vec<usize>,
+    /// The edge features, one row of `EDGE_FEATURES.len()` values per edge.
+    pub edge_features: Vec<f64>,
+    /// The position in the LP of the row each constraint node was created from.
+    pub cons_lp_rows: Vec<usize>,
+    /// The problem index (see `Model::var_in_prob`) of the variable of each variable node.
+    pub var_prob_ids: Vec<usize>,
+}
+
+impl BipartiteObservation {
+    /// Extracts the observation from the current LP relaxation.
+    ///
+    /// This is meant to be called from a plugin (e.g. a `BranchRule`) after the LP of the focus node was solved.
+    pub fn extract(model: &Model<Solving>) -> Self {
+        let scip = model.scip.raw;
+        let obj_norm = match unsafe { ffi::SCIPgetObjNorm(scip) } {
+            norm if norm > 0.0 => norm,
+            _ => 1.0,
+        };
+        let age_scale = unsafe { ffi::SCIPgetNLPs(scip) } as f64 + 5.0;
+        let incumbent = model.best_sol();
+        let mut obs = BipartiteObservation::default();
+
+        for col in model.lp_cols() {
+            let var = col.var();
+            let var_type = var.var_type();
+            let (lb, ub) = (col.lb(), col.ub());
+            let has_lb = unsafe { ffi::SCIPisInfinity(scip, -lb) } == 0;
+            let has_ub = unsafe { ffi::SCIPisInfinity(scip, ub) } == 0;
+            let sol_val = col.primal_sol();
+            let sol_frac = if var_type == VarType::Continuous {
+                0.0
+            } else {
+                unsafe { ffi::SCIPfeasFrac(scip, sol_val) }
+            };
+            let basis_status = col.basis_status();
+            let incumbent_val = incumbent.as_ref().map(|sol| sol.val(&var));
+
+            obs.var_features.extend_from_slice(&[
+                col.obj() / obj_norm,
+                flag(var_type == VarType::Binary),
+                flag(var_type == VarType::Integer),
+                flag(var_type == VarType::ImplInt),
+                flag(var_type == VarType::Continuous),
+                flag(has_lb),
+                flag(has_ub),
+                if has_lb { lb } else { 0.0 },
+                if has_ub { ub } else { 0.0 },
+                col.reduced_cost() / obj_norm,
+                sol_val,
+                sol_frac,
+                flag(has_lb && model.eq(sol_val, lb)),
+                flag(has_ub && model.eq(sol_val, ub)),
+                col.age() as f64 / age_scale,
+                flag(incumbent_val.is_some()),
+                incumbent_val.unwrap_or(0.0),
+                flag(basis_status == BasisStatus::Lower),
+                flag(basis_status == BasisStatus::Basic),
+                flag(basis_status == BasisStatus::Upper),
+                flag(basis_status == BasisStatus::Zero),
+            ]);
+            obs.var_prob_ids
+                .push(col.var_probindex().unwrap_or(usize::MAX));
+            obs.n_vars += 1;
+        }
+
+        for row in model.lp_rows() {
+            let row_norm = match row.norm() {
+                norm if norm > 0.0 => norm,
+                _ => 1.0,
+            };
+            let lhs = row.lhs() - row.constant();
+            let rhs = row.rhs() - row.constant();
+            let activity = row.lp_activity() - row.constant();
+            let lp_pos = row.lp_position().unwrap();
+            let entries: Vec<(usize, f64)> = row
+                .cols()
+                .iter()
+                .zip(row.vals())
+                .filter_map(|(col, val)| col.lp_pos().map(|pos| (pos, val)))
+                .collect();
+
+            // (sign, side) of the `<=` constraints created from this row
+            let mut sides = Vec::with_capacity(2);
+            if unsafe { ffi::SCIPisInfinity(scip, -lhs) } == 0 {
+                sides.push((-1.0, lhs));
+            }
+            if unsafe { ffi::SCIPisInfinity(scip, rhs) } == 0 {
+                sides.push((1.0, rhs));
+            }
+
+            for (sign, side) in sides {
+                let cons_node = obs.n_conss;
+                obs.cons_features.extend_from_slice(&[
+                    sign * row.obj_parallelism(),
+                    sign * side / row_norm,
+                    flag(model.eq(activity, side)),
+                    sign * row.dual() / (row_norm * obj_norm),
+                    row.age() as f64 / age_scale,
+                ]);
+                for &(var_node, val) in &entries {
+                    obs.edge_conss.push(cons_node);
+                    obs.edge_vars.push(var_node);
+                    obs.edge_features.push(sign * val / row_norm);
+                }
+                obs.cons_lp_rows.push(lp_pos);
+                obs.n_conss += 1;
+            }
+        }
+
+        obs
+    }
+
+    /// Returns the number of edges in the graph.
+    pub fn n_edges(&self) -> usize {
+        self.edge_conss.len()
+    }
+
+    /// Returns the features of the given constraint node.
+    pub fn cons_feature_row(&self, cons: usize) -> &[f64] {
+        let n = CONS_FEATURES.len();
+        &self.cons_features[cons * n..(cons + 1) * n]
+    }
+
+    /// Returns the features of the given variable node.
+    pub fn var_feature_row(&self, var: usize) -> &[f64] {
+        let n = VAR_FEATURES.len();
+        &self.var_features[var * n..(var + 1) * n]
+    }
+}
+
+fn flag(value: bool) -> f64 {
+    if value { 1.0 } else { 0.0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::branchrule;
+    use crate::{BranchRule, BranchingCandidate, BranchingResult, ModelWithProblem, Status};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    struct ObservingBranchRule {
+        observations: Rc<RefCell<Vec<BipartiteObservation>>>,
+    }
+
+    impl BranchRule for ObservingBranchRule {
+        fn execute(
+            &mut self,
+            model: Model<Solving>,
+            _branchrule: crate::SCIPBranchRule,
+            candidates: Vec<BranchingCandidate>,
+        ) -> BranchingResult {
+            let obs = BipartiteObservation::extract(&model);
+            assert_eq!(obs.n_vars, model.lp_cols().len());
+            assert_eq!(obs.var_features.len(), obs.n_vars * VAR_FEATURES.len());
+            assert_eq!(obs.cons_features.len(), obs.n_conss * CONS_FEATURES.len());
+            assert_eq!(obs.edge_vars.len(), obs.n_edges());
+            assert!(obs.edge_conss.iter().all(|&c| c < obs.n_conss));
+            assert!(obs.edge_vars.iter().all(|&v| v < obs.n_vars));
+            assert!(obs.var_features.iter().all(|f| f.is_finite()));
+
+            // every branching candidate is fractional in the LP solution
+            let sol_frac = VAR_FEATURES.iter().position(|&f| f == "sol_frac").unwrap();
+            for cand in &candidates {
+                let node = obs
+                    .var_prob_ids
+                    .iter()
+                    .position(|&id| id == cand.var_prob_id)
+                    .unwrap();
+                assert!(obs.var_feature_row(node)[sol_frac] > 0.0);
+            }
+
+            self.observations.borrow_mut().push(obs);
+            BranchingResult::BranchOn(candidates[0].clone())
+        }
+    }
+
+    #[test]
+    fn bipartite_observation() {
+        let observations = Rc::new(RefCell::new(Vec::new()));
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/gen-ip054.mps")
+            .unwrap()
+            .set_longint_param("limits/nodes", 10)
+            .unwrap();
+        model.add(branchrule(ObservingBranchRule {
+            observations: observations.clone(),
+        }));
+
+        let n_vars = model.n_vars();
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::NodeLimit);
+
+        let observations = observations.borrow();
+        assert!(!observations.is_empty());
+        let root = &observations[0];
+        assert!(root.n_vars <= n_vars);
+        assert!(root.n_conss > 0);
+        assert!(root.n_edges() > 0);
+    }
+}
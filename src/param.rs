@@ -1,8 +1,14 @@
-use crate::{Model, Retcode};
+use crate::{Model, Retcode, ScipError, ffi, scip_call};
+use scip_sys::{SCIP, SCIP_PARAM};
+use std::ffi::{CStr, CString, c_char};
 
 pub trait ScipParameter: Sized {
     fn set<T>(model: Model<T>, name: &str, value: Self) -> Result<Model<T>, Retcode>;
     fn get<T>(model: &Model<T>, name: &str) -> Self;
+    /// Same as `get`, but returns an error instead of panicking. The default implementation calls `get`.
+    fn try_get<T>(model: &Model<T>, name: &str) -> Result<Self, ScipError> {
+        Ok(Self::get(model, name))
+    }
 }
 
 impl ScipParameter for f64 {
@@ -14,6 +20,10 @@ impl ScipParameter for f64 {
     fn get<T>(model: &Model<T>, name: &str) -> f64 {
         model.real_param(name)
     }
+
+    fn try_get<T>(model: &Model<T>, name: &str) -> Result<f64, ScipError> {
+        model.try_real_param(name)
+    }
 }
 
 impl ScipParameter for i32 {
@@ -25,6 +35,10 @@ impl ScipParameter for i32 {
     fn get<T>(model: &Model<T>, name: &str) -> i32 {
         model.int_param(name)
     }
+
+    fn try_get<T>(model: &Model<T>, name: &str) -> Result<i32, ScipError> {
+        model.try_int_param(name)
+    }
 }
 
 impl ScipParameter for bool {
@@ -36,6 +50,10 @@ impl ScipParameter for bool {
     fn get<T>(model: &Model<T>, name: &str) -> bool {
         model.bool_param(name)
     }
+
+    fn try_get<T>(model: &Model<T>, name: &str) -> Result<bool, ScipError> {
+        model.try_bool_param(name)
+    }
 }
 
 impl ScipParameter for i64 {
@@ -47,6 +65,10 @@ impl ScipParameter for i64 {
     fn get<T>(model: &Model<T>, name: &str) -> i64 {
         model.longint_param(name)
     }
+
+    fn try_get<T>(model: &Model<T>, name: &str) -> Result<i64, ScipError> {
+        model.try_longint_param(name)
+    }
 }
 
 impl ScipParameter for String {
@@ -58,6 +80,94 @@ impl ScipParameter for String {
     fn get<T>(model: &Model<T>, name: &str) -> String {
         model.str_param(name)
     }
+
+    fn try_get<T>(model: &Model<T>, name: &str) -> Result<String, ScipError> {
+        model.try_str_param(name)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum ParamValue {
+    Bool(u32),
+    Int(i32),
+    Longint(i64),
+    Real(f64),
+    Char(c_char),
+    String(CString),
+}
+
+impl ParamValue {
+    fn of(param: *mut SCIP_PARAM) -> Self {
+        unsafe {
+            match ffi::SCIPparamGetType(param) {
+                ffi::SCIP_ParamType_SCIP_PARAMTYPE_BOOL => {
+                    ParamValue::Bool(ffi::SCIPparamGetBool(param))
+                }
+                ffi::SCIP_ParamType_SCIP_PARAMTYPE_INT => {
+                    ParamValue::Int(ffi::SCIPparamGetInt(param))
+                }
+                ffi::SCIP_ParamType_SCIP_PARAMTYPE_LONGINT => {
+                    ParamValue::Longint(ffi::SCIPparamGetLongint(param))
+                }
+                ffi::SCIP_ParamType_SCIP_PARAMTYPE_REAL => {
+                    ParamValue::Real(ffi::SCIPparamGetReal(param))
+                }
+                ffi::SCIP_ParamType_SCIP_PARAMTYPE_CHAR => {
+                    ParamValue::Char(ffi::SCIPparamGetChar(param))
+                }
+                _ => ParamValue::String(CStr::from_ptr(ffi::SCIPparamGetString(param)).to_owned()),
+            }
+        }
+    }
+}
+
+/// The values of all parameters of a SCIP instance, used to undo temporary parameter changes such as emphasis
+/// settings.
+pub(crate) struct ParamSnapshot(Vec<(*mut SCIP_PARAM, ParamValue)>);
+
+impl ParamSnapshot {
+    pub(crate) fn save(scip: *mut SCIP) -> Self {
+        let n_params = unsafe { ffi::SCIPgetNParams(scip) } as usize;
+        let params = unsafe { ffi::SCIPgetParams(scip) };
+        let values = (0..n_params)
+            .map(|i| {
+                let param = unsafe { *params.add(i) };
+                (param, ParamValue::of(param))
+            })
+            .collect();
+        ParamSnapshot(values)
+    }
+
+    /// Resets the parameters that changed since the snapshot was taken.
+    pub(crate) fn restore(&self, scip: *mut SCIP) -> Result<(), ScipError> {
+        for (param, value) in &self.0 {
+            if ParamValue::of(*param) == *value {
+                continue;
+            }
+            let name = unsafe { ffi::SCIPparamGetName(*param) };
+            match value {
+                ParamValue::Bool(val) => {
+                    scip_call!(ffi::SCIPsetBoolParam(scip, name, *val));
+                }
+                ParamValue::Int(val) => {
+                    scip_call!(ffi::SCIPsetIntParam(scip, name, *val));
+                }
+                ParamValue::Longint(val) => {
+                    scip_call!(ffi::SCIPsetLongintParam(scip, name, *val));
+                }
+                ParamValue::Real(val) => {
+                    scip_call!(ffi::SCIPsetRealParam(scip, name, *val));
+                }
+                ParamValue::Char(val) => {
+                    scip_call!(ffi::SCIPsetCharParam(scip, name, *val));
+                }
+                ParamValue::String(val) => {
+                    scip_call!(ffi::SCIPsetStringParam(scip, name, val.as_ptr()));
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
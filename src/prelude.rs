@@ -8,6 +8,7 @@ pub use crate::builder::row::row;
 pub use crate::builder::sepa::sepa;
 pub use crate::builder::var::var;
 pub use crate::conshdlr::*;
+pub use crate::error::*;
 pub use crate::eventhdlr::*;
 pub use crate::heuristic::*;
 pub use crate::model::Model;
@@ -18,8 +19,10 @@ pub use crate::model::ProblemOrSolving;
 pub use crate::model::Solving;
 pub use crate::model::WithSolutions;
 pub use crate::model::WithSolvingStats;
+pub use crate::model::WithTransformedProblem;
 pub use crate::pricer::*;
-pub use crate::retcode::Retcode;
+pub use crate::retcode::{Retcode, ScipError};
 pub use crate::separator::*;
+pub use crate::stage::Stage;
 pub use crate::status::Status;
 pub use crate::variable::VarType;
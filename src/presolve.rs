@@ -0,0 +1,270 @@
+use crate::ffi;
+use crate::scip::cstr_to_string;
+use crate::variable::Variable;
+use scip_sys::SCIP;
+use std::ops::AddAssign;
+
+/// The number of reductions of each kind performed during presolving.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Reductions {
+    /// The number of fixed variables.
+    pub fixed_vars: usize,
+    /// The number of aggregated variables.
+    pub aggregated_vars: usize,
+    /// The number of variable type changes.
+    pub changed_var_types: usize,
+    /// The number of bound changes.
+    pub changed_bounds: usize,
+    /// The number of holes added to variable domains.
+    pub added_holes: usize,
+    /// The number of deleted constraints.
+    pub deleted_conss: usize,
+    /// The number of added constraints.
+    pub added_conss: usize,
+    /// The number of constraints upgraded to a more specific type.
+    pub upgraded_conss: usize,
+    /// The number of changed coefficients.
+    pub changed_coefs: usize,
+    /// The number of changed constraint sides.
+    pub changed_sides: usize,
+}
+
+impl AddAssign for Reductions {
+    fn add_assign(&mut self, other: Self) {
+        self.fixed_vars += other.fixed_vars;
+        self.aggregated_vars += other.aggregated_vars;
+        self.changed_var_types += other.changed_var_types;
+        self.changed_bounds += other.changed_bounds;
+        self.added_holes += other.added_holes;
+        self.deleted_conss += other.deleted_conss;
+        self.added_conss += other.added_conss;
+        self.upgraded_conss += other.upgraded_conss;
+        self.changed_coefs += other.changed_coefs;
+        self.changed_sides += other.changed_sides;
+    }
+}
+
+/// The kind of plugin that performed presolving reductions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresolverKind {
+    /// A presolver, e.g. `dualfix` or `milp`.
+    Presolver,
+    /// The presolving method of a propagator.
+    Propagator,
+    /// The presolving method of a constraint handler.
+    ConstraintHandler,
+}
+
+/// The statistics of a single presolving method.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PresolverStats {
+    /// The name of the plugin.
+    pub name: String,
+    /// The kind of the plugin.
+    pub kind: PresolverKind,
+    /// The number of times the presolving method was called.
+    pub calls: usize,
+    /// The time spent in the presolving method in seconds.
+    pub time: f64,
+    /// The reductions performed by the presolving method.
+    pub reductions: Reductions,
+}
+
+/// A summary of what presolving did to the problem, returned by `WithTransformedProblem::presolve_report`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PresolveReport {
+    /// The number of presolving rounds.
+    pub rounds: usize,
+    /// The total presolving time in seconds.
+    pub time: f64,
+    /// The number of variables in the original problem.
+    pub n_orig_vars: usize,
+    /// The number of constraints in the original problem.
+    pub n_orig_conss: usize,
+    /// The number of active variables in the presolved problem.
+    pub n_vars: usize,
+    /// The number of constraints in the presolved problem.
+    pub n_conss: usize,
+    /// The reductions of all presolving methods.
+    pub total: Reductions,
+    /// The statistics of the presolving methods that were called, in the order presolvers, propagators and
+    /// constraint handlers.
+    pub presolvers: Vec<PresolverStats>,
+}
+
+/// An affine expression of active variables in the transformed problem that an original variable was replaced by.
+#[derive(Debug, Clone)]
+pub struct Aggregation {
+    /// The constant term, which is the value of the variable if it was fixed.
+    pub constant: f64,
+    /// The active variables together with their coefficients.
+    pub terms: Vec<(Variable, f64)>,
+}
+
+impl Aggregation {
+    /// Returns whether the variable was fixed to the constant.
+    pub fn is_fixed(&self) -> bool {
+        self.terms.is_empty()
+    }
+}
+
+/// Reads the reductions of a plugin through its `SCIP<plugin>GetN*` functions.
+macro_rules! reductions {
+    ($plugin:expr, $fixed:ident, $aggr:ident, $vartypes:ident, $bds:ident, $holes:ident, $delconss:ident,
+     $addconss:ident, $upgdconss:ident, $coefs:ident, $sides:ident) => {
+        unsafe {
+            Reductions {
+                fixed_vars: ffi::$fixed($plugin) as usize,
+                aggregated_vars: ffi::$aggr($plugin) as usize,
+                changed_var_types: ffi::$vartypes($plugin) as usize,
+                changed_bounds: ffi::$bds($plugin) as usize,
+                added_holes: ffi::$holes($plugin) as usize,
+                deleted_conss: ffi::$delconss($plugin) as usize,
+                added_conss: ffi::$addconss($plugin) as usize,
+                upgraded_conss: ffi::$upgdconss($plugin) as usize,
+                changed_coefs: ffi::$coefs($plugin) as usize,
+                changed_sides: ffi::$sides($plugin) as usize,
+            }
+        }
+    };
+}
+
+impl PresolveReport {
+    /// Collects the presolving statistics of the given SCIP instance.
+    pub(crate) fn collect(scip: *mut SCIP) -> Self {
+        let mut presolvers = Vec::new();
+
+        let n_presols = unsafe { ffi::SCIPgetNPresols(scip) } as usize;
+        let presols = unsafe { ffi::SCIPgetPresols(scip) };
+        for i in 0..n_presols {
+            let presol = unsafe { *presols.add(i) };
+            presolvers.push(PresolverStats {
+                name: cstr_to_string(unsafe { ffi::SCIPpresolGetName(presol) }),
+                kind: PresolverKind::Presolver,
+                calls: unsafe { ffi::SCIPpresolGetNCalls(presol) } as usize,
+                time: unsafe { ffi::SCIPpresolGetTime(presol) },
+                reductions: reductions!(
+                    presol,
+                    SCIPpresolGetNFixedVars,
+                    SCIPpresolGetNAggrVars,
+                    SCIPpresolGetNChgVarTypes,
+                    SCIPpresolGetNChgBds,
+                    SCIPpresolGetNAddHoles,
+                    SCIPpresolGetNDelConss,
+                    SCIPpresolGetNAddConss,
+                    SCIPpresolGetNUpgdConss,
+                    SCIPpresolGetNChgCoefs,
+                    SCIPpresolGetNChgSides
+                ),
+            });
+        }
+
+        let n_props = unsafe { ffi::SCIPgetNProps(scip) } as usize;
+        let props = unsafe { ffi::SCIPgetProps(scip) };
+        for i in 0..n_props {
+            let prop = unsafe { *props.add(i) };
+            presolvers.push(PresolverStats {
+                name: cstr_to_string(unsafe { ffi::SCIPpropGetName(prop) }),
+                kind: PresolverKind::Propagator,
+                calls: unsafe { ffi::SCIPpropGetNPresolCalls(prop) } as usize,
+                time: unsafe { ffi::SCIPpropGetPresolTime(prop) },
+                reductions: reductions!(
+                    prop,
+                    SCIPpropGetNFixedVars,
+                    SCIPpropGetNAggrVars,
+                    SCIPpropGetNChgVarTypes,
+                    SCIPpropGetNChgBds,
+                    SCIPpropGetNAddHoles,
+                    SCIPpropGetNDelConss,
+                    SCIPpropGetNAddConss,
+                    SCIPpropGetNUpgdConss,
+                    SCIPpropGetNChgCoefs,
+                    SCIPpropGetNChgSides
+                ),
+            });
+        }
+
+        let n_conshdlrs = unsafe { ffi::SCIPgetNConshdlrs(scip) } as usize;
+        let conshdlrs = unsafe { ffi::SCIPgetConshdlrs(scip) };
+        for i in 0..n_conshdlrs {
+            let conshdlr = unsafe { *conshdlrs.add(i) };
+            presolvers.push(PresolverStats {
+                name: cstr_to_string(unsafe { ffi::SCIPconshdlrGetName(conshdlr) }),
+                kind: PresolverKind::ConstraintHandler,
+                calls: unsafe { ffi::SCIPconshdlrGetNPresolCalls(conshdlr) } as usize,
+                time: unsafe { ffi::SCIPconshdlrGetPresolTime(conshdlr) },
+                reductions: reductions!(
+                    conshdlr,
+                    SCIPconshdlrGetNFixedVars,
+                    SCIPconshdlrGetNAggrVars,
+                    SCIPconshdlrGetNChgVarTypes,
+                    SCIPconshdlrGetNChgBds,
+                    SCIPconshdlrGetNAddHoles,
+                    SCIPconshdlrGetNDelConss,
+                    SCIPconshdlrGetNAddConss,
+                    SCIPconshdlrGetNUpgdConss,
+                    SCIPconshdlrGetNChgCoefs,
+                    SCIPconshdlrGetNChgSides
+                ),
+            });
+        }
+
+        presolvers.retain(|stats| stats.calls > 0);
+        let mut total = Reductions::default();
+        for stats in &presolvers {
+            total += stats.reductions;
+        }
+
+        unsafe {
+            PresolveReport {
+                rounds: ffi::SCIPgetNPresolRounds(scip) as usize,
+                time: ffi::SCIPgetPresolvingTime(scip),
+                n_orig_vars: ffi::SCIPgetNOrigVars(scip) as usize,
+                n_orig_conss: ffi::SCIPgetNOrigConss(scip) as usize,
+                n_vars: ffi::SCIPgetNVars(scip) as usize,
+                n_conss: ffi::SCIPgetNConss(scip) as usize,
+                total,
+                presolvers,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn presolve_report() {
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/gen-ip054.mps")
+            .unwrap()
+            .presolve();
+
+        let report = model.presolve_report();
+        assert!(report.rounds > 0);
+        assert_eq!(report.n_orig_vars, model.orig_vars().len());
+        assert_eq!(report.n_vars, model.n_vars());
+        assert!(report.presolvers.iter().all(|stats| stats.calls > 0));
+        let fixed: usize = report
+            .presolvers
+            .iter()
+            .map(|stats| stats.reductions.fixed_vars)
+            .sum();
+        assert_eq!(report.total.fixed_vars, fixed);
+
+        for var in model.orig_vars() {
+            let aggregation = model.aggregation(&var).unwrap();
+            assert!(aggregation.terms.len() <= report.n_vars);
+            assert!(aggregation.terms.iter().all(|(_, coef)| *coef != 0.));
+        }
+
+        let path = std::env::temp_dir().join("russcip_presolved_test.lp");
+        let path = path.to_str().unwrap();
+        model.write_transformed(path, "lp").unwrap();
+        assert!(std::fs::metadata(path).unwrap().len() > 0);
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -1,4 +1,5 @@
-use crate::{Model, Solving, ffi};
+use crate::error::Infallible;
+use crate::{Model, PluginError, Solving, ffi};
 use scip_sys::SCIP_Result;
 
 /// A trait for SCIP pricers.
@@ -17,6 +18,42 @@ pub trait Pricer {
     ) -> PricerResult;
 }
 
+/// A fallible variant of `Pricer`, for pricers that can fail (e.g. because they call external code).
+///
+/// Every `Pricer` is a `TryPricer` that never fails. An error aborts the solve and is returned by
+/// `Model::try_solve`.
+pub trait TryPricer {
+    /// Generates negative reduced cost columns, see `Pricer::generate_columns`.
+    fn try_generate_columns(
+        &mut self,
+        model: Model<Solving>,
+        pricer: SCIPPricer,
+        farkas: bool,
+    ) -> Result<PricerResult, PluginError>;
+}
+
+impl<P: Pricer + ?Sized> TryPricer for P {
+    fn try_generate_columns(
+        &mut self,
+        model: Model<Solving>,
+        pricer: SCIPPricer,
+        farkas: bool,
+    ) -> Result<PricerResult, PluginError> {
+        Ok(self.generate_columns(model, pricer, farkas))
+    }
+}
+
+impl TryPricer for Infallible<dyn Pricer> {
+    fn try_generate_columns(
+        &mut self,
+        model: Model<Solving>,
+        pricer: SCIPPricer,
+        farkas: bool,
+    ) -> Result<PricerResult, PluginError> {
+        Ok(self.0.generate_columns(model, pricer, farkas))
+    }
+}
+
 /// An enum representing the possible states of a `PricerResult`.
 #[derive(Debug, PartialEq)]
 pub enum PricerResultState {
@@ -1,5 +1,6 @@
+use crate::retcode::ScipError;
 use crate::scip::ScipPtr;
-use crate::{Retcode, Row, Variable, ffi, scip_call, scip_call_panic};
+use crate::{BasisStatus, Retcode, Row, Variable, ffi, scip_call, scip_call_panic};
 use std::rc::Rc;
 
 /// Struct giving access to methods allowed in probing mode
@@ -182,6 +183,28 @@ impl Prober {
     pub fn add_row(&mut self, row: &Row) {
         scip_call_panic! { ffi::SCIPaddRowProbing(self.scip.raw, row.inner(),) }
     }
+
+    /// Sets the basis the next `solve_lp` of the current probing node starts from, with one status per column
+    /// and row of the current LP, ordered like `model.lp_cols()` and `model.lp_rows()`.
+    ///
+    /// Warm starting is only supported in probing mode, since SCIP offers no way to set the LP state of a dive.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the number of statuses does not match the current LP.
+    pub fn set_lp_basis(&mut self, cols: &[BasisStatus], rows: &[BasisStatus]) {
+        self.try_set_lp_basis(cols, rows)
+            .expect("Failed to set LP basis in probing mode")
+    }
+
+    /// Same as `set_lp_basis`, but returns an error instead of panicking.
+    pub fn try_set_lp_basis(
+        &mut self,
+        cols: &[BasisStatus],
+        rows: &[BasisStatus],
+    ) -> Result<(), ScipError> {
+        self.scip.set_probing_lp_basis(cols, rows)
+    }
 }
 
 impl Drop for Prober {
@@ -200,7 +223,7 @@ mod tests {
     use crate::model::Model;
     use crate::prelude::{eventhdlr, row};
     use crate::{Event, EventMask, SCIPEventhdlr, Solving};
-    use crate::{Eventhdlr, ModelWithProblem, ParamSetting, ffi};
+    use crate::{Eventhdlr, LPStatus, ModelWithProblem, ParamSetting, ffi};
 
     #[test]
     fn test_prober() {
@@ -291,4 +314,54 @@ mod tests {
 
         model.solve();
     }
+
+    #[test]
+    fn test_prober_warm_start() {
+        struct WarmStartTester;
+
+        impl Eventhdlr for WarmStartTester {
+            fn get_type(&self) -> EventMask {
+                EventMask::NODE_SOLVED
+            }
+
+            fn execute(
+                &mut self,
+                mut model: Model<Solving>,
+                _eventhdlr: SCIPEventhdlr,
+                _event: Event,
+            ) {
+                let Some((cols, rows)) = model.lp_basis() else {
+                    return;
+                };
+                assert_eq!(cols.len(), model.lp_cols().len());
+                assert_eq!(rows.len(), model.lp_rows().len());
+                for (col, status) in model.lp_cols().iter().zip(&cols) {
+                    assert_eq!(col.basis_status(), *status);
+                }
+
+                // the optimal basis stays optimal for a zero objective
+                let mut prober = model.start_probing();
+                for var in model.vars() {
+                    prober.chg_var_obj(&var, 0.0);
+                }
+                assert!(prober.try_set_lp_basis(&cols, &rows[1..]).is_err());
+                prober.set_lp_basis(&cols, &rows);
+                assert!(!prober.solve_lp(None).unwrap());
+                assert_eq!(model.lp_status(), LPStatus::Optimal);
+                assert_eq!(model.lp_basis(), Some((cols, rows)));
+            }
+        }
+
+        let mut model = Model::new()
+            .include_default_plugins()
+            .read_prob("data/test/simple.mps")
+            .unwrap()
+            .hide_output()
+            .set_presolving(ParamSetting::Off)
+            .set_separating(ParamSetting::Off)
+            .set_heuristics(ParamSetting::Off);
+
+        model.add(eventhdlr(WarmStartTester));
+        model.solve();
+    }
 }
@@ -0,0 +1,113 @@
+use crate::ffi;
+use scip_sys::SCIP;
+
+/// A line of the progress table that SCIP displays while solving.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProgressRow {
+    /// The solving time in seconds.
+    pub time: f64,
+    /// The number of processed nodes.
+    pub nodes: usize,
+    /// The number of open nodes.
+    pub nodes_left: usize,
+    /// The number of LP iterations.
+    pub lp_iterations: usize,
+    /// The number of cuts applied to the LP.
+    pub cuts: usize,
+    /// The objective value of the best solution, infinite if none was found yet.
+    pub primal_bound: f64,
+    /// The best proven bound on the objective value.
+    pub dual_bound: f64,
+    /// The relative gap between the primal and the dual bound, infinite if either is infinite.
+    pub gap: f64,
+    /// Whether the row was recorded because a new best solution was found.
+    pub new_incumbent: bool,
+}
+
+impl ProgressRow {
+    /// Reads the current progress of the solve.
+    pub(crate) fn current(scip: *mut SCIP, new_incumbent: bool) -> Self {
+        unsafe {
+            ProgressRow {
+                time: ffi::SCIPgetSolvingTime(scip),
+                nodes: ffi::SCIPgetNNodes(scip) as usize,
+                nodes_left: ffi::SCIPgetNNodesLeft(scip) as usize,
+                lp_iterations: ffi::SCIPgetNLPIterations(scip) as usize,
+                cuts: ffi::SCIPgetNCutsApplied(scip) as usize,
+                primal_bound: ffi::SCIPgetPrimalbound(scip),
+                dual_bound: ffi::SCIPgetDualbound(scip),
+                gap: ffi::SCIPgetGap(scip),
+                new_incumbent,
+            }
+        }
+    }
+}
+
+/// A callback called with every recorded row.
+pub(crate) type ProgressCallback = Box<dyn FnMut(&ProgressRow)>;
+
+/// The data of the event handler recording the progress of a solve.
+pub(crate) struct ProgressTracker {
+    /// The rows recorded during the current solve.
+    pub(crate) rows: Vec<ProgressRow>,
+    /// Called with every recorded row.
+    pub(crate) callback: Option<ProgressCallback>,
+    /// The value of `display/freq` at the start of the solve.
+    pub(crate) freq: i64,
+}
+
+impl ProgressTracker {
+    pub(crate) fn record(&mut self, row: ProgressRow) {
+        if let Some(callback) = self.callback.as_mut() {
+            callback(&row);
+        }
+        self.rows.push(row);
+    }
+
+    /// Returns whether a row is displayed after the given number of processed nodes, i.e. at the root and then
+    /// every `display/freq` nodes.
+    pub(crate) fn is_due(&self, nodes: usize) -> bool {
+        nodes == 1 || (self.freq > 0 && nodes as i64 % self.freq == 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::{ProgressRow, minimal_model};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn progress_rows() {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let captured = seen.clone();
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/gen-ip054.mps")
+            .unwrap()
+            .set_longint_param("display/freq", 5)
+            .unwrap()
+            .set_longint_param("limits/nodes", 30)
+            .unwrap()
+            .on_progress(move |row: &ProgressRow| captured.borrow_mut().push(*row));
+        let solved = model.solve();
+
+        let rows = solved.progress();
+        assert_eq!(rows, *seen.borrow());
+        assert!(rows.len() > 2);
+        assert!(rows.windows(2).all(|w| w[0].nodes <= w[1].nodes));
+        assert!(rows.windows(2).all(|w| w[0].time <= w[1].time));
+        let last = rows.last().unwrap();
+        assert_eq!(last.nodes, solved.n_nodes());
+        assert_eq!(last.primal_bound, solved.obj_val());
+        assert!(rows.iter().any(|row| row.new_incumbent));
+    }
+
+    #[test]
+    fn progress_untracked() {
+        let solved = minimal_model().hide_output().solve();
+        assert!(solved.progress().is_empty());
+    }
+}
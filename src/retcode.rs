@@ -1,4 +1,6 @@
 use crate::ffi;
+use std::error::Error;
+use std::fmt;
 
 /// An enum representing the possible return codes from SCIP functions.
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
@@ -104,3 +106,108 @@ impl From<Retcode> for ffi::SCIP_Retcode {
         }
     }
 }
+
+impl fmt::Display for Retcode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Retcode::Okay => "normal termination",
+            Retcode::Error => "unspecified error",
+            Retcode::NoMemory => "insufficient memory error",
+            Retcode::ReadError => "read error",
+            Retcode::WriteError => "write error",
+            Retcode::NoFile => "file not found error",
+            Retcode::FileCreateError => "cannot create file",
+            Retcode::LpError => "error in LP solver",
+            Retcode::NoProblem => "no problem exists",
+            Retcode::InvalidCall => "method cannot be called at this time in solution process",
+            Retcode::InvalidData => "error in input data",
+            Retcode::InvalidResult => "method returned an invalid result code",
+            Retcode::PluginNotFound => "a required plugin was not found",
+            Retcode::ParameterUnknown => "the parameter with the given name was not found",
+            Retcode::ParameterWrongType => "the parameter is not of the expected type",
+            Retcode::ParameterWrongVal => "the value is invalid for the given parameter",
+            Retcode::KeyAlreadyExisting => "the given key is already existing in table",
+            Retcode::MaxDepthLevel => "maximal branching depth level exceeded",
+            Retcode::BranchError => "no branching could be created",
+            Retcode::NotImplemented => "function not implemented",
+            Retcode::Unknown(val) => return write!(f, "unknown return code {val}"),
+        };
+        f.write_str(msg)
+    }
+}
+
+impl Error for Retcode {}
+
+/// A failed SCIP call: the return code together with the name of the SCIP function that returned it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScipError {
+    /// The return code of the failed call.
+    pub retcode: Retcode,
+    /// The name of the SCIP function that failed, e.g. `SCIPcreateProbBasic`, or an empty string if the error did
+    /// not originate from a SCIP call.
+    pub function: &'static str,
+}
+
+impl ScipError {
+    /// Creates a new error from the return code of the given call, which can be the stringified call expression
+    /// (as done by the `scip_call!` macro) from which the function name is extracted.
+    pub fn new(retcode: Retcode, call: &'static str) -> Self {
+        let path = call.split('(').next().unwrap_or_default();
+        let function = path.rsplit("::").next().unwrap_or_default().trim();
+        ScipError { retcode, function }
+    }
+}
+
+impl fmt::Display for ScipError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.function.is_empty() {
+            write!(f, "SCIP error: {}", self.retcode)
+        } else {
+            write!(f, "{} failed: {}", self.function, self.retcode)
+        }
+    }
+}
+
+impl Error for ScipError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.retcode)
+    }
+}
+
+impl From<Retcode> for ScipError {
+    fn from(retcode: Retcode) -> Self {
+        ScipError {
+            retcode,
+            function: "",
+        }
+    }
+}
+
+impl From<ScipError> for Retcode {
+    fn from(error: ScipError) -> Self {
+        error.retcode
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scip_error_function_name() {
+        let error = ScipError::new(
+            Retcode::ParameterUnknown,
+            "ffi::SCIPsetIntParam(self.raw, name.as_ptr(), value)",
+        );
+        assert_eq!(error.function, "SCIPsetIntParam");
+        assert_eq!(
+            error.to_string(),
+            "SCIPsetIntParam failed: the parameter with the given name was not found"
+        );
+        assert_eq!(Retcode::from(error), Retcode::ParameterUnknown);
+        assert_eq!(
+            ScipError::from(Retcode::NoProblem).to_string(),
+            "SCIP error: no problem exists"
+        );
+    }
+}
@@ -38,6 +38,18 @@ impl Row {
         columns
     }
 
+    /// Returns the coefficients of the non-zero entries in the row.
+    pub fn vals(&self) -> Vec<f64> {
+        let n_non_zeroes = self.n_non_zeroes();
+        if n_non_zeroes == 0 {
+            // SCIP does not allocate the array for empty rows
+            return Vec::new();
+        }
+        let vals_ptr = unsafe { ffi::SCIProwGetVals(self.raw) };
+        let vals = unsafe { std::slice::from_raw_parts(vals_ptr, n_non_zeroes) };
+        vals.to_vec()
+    }
+
     /// Returns the index of the row.
     pub fn index(&self) -> usize {
         let id = unsafe { ffi::SCIProwGetIndex(self.raw) };
@@ -55,6 +67,26 @@ impl Row {
         unsafe { ffi::SCIProwGetRhs(self.raw) }
     }
 
+    /// Returns the constant of the row, which is subtracted from both sides.
+    pub fn constant(&self) -> f64 {
+        unsafe { ffi::SCIProwGetConstant(self.raw) }
+    }
+
+    /// Returns the Euclidean norm of the row's coefficient vector.
+    pub fn norm(&self) -> f64 {
+        unsafe { ffi::SCIProwGetNorm(self.raw) }
+    }
+
+    /// Returns the activity of the row in the current LP solution.
+    pub fn lp_activity(&self) -> f64 {
+        unsafe { ffi::SCIPgetRowLPActivity(self.scip.raw, self.raw) }
+    }
+
+    /// Returns the parallelism (cosine of the angle) between the row and the objective function.
+    pub fn obj_parallelism(&self) -> f64 {
+        unsafe { ffi::SCIPgetRowObjParallelism(self.scip.raw, self.raw) }
+    }
+
     /// Returns the dual value of the row.
     pub fn dual(&self) -> f64 {
         unsafe { ffi::SCIProwGetDualsol(self.raw) }
@@ -190,7 +222,7 @@ impl PartialEq for Row {
     }
 }
 
-/// The basis status of a row.
+/// The basis status of a row or column.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum BasisStatus {
     /// The row is at its lower bound.
@@ -215,6 +247,25 @@ impl From<ffi::SCIP_BASESTAT> for BasisStatus {
     }
 }
 
+impl From<BasisStatus> for ffi::SCIP_BASESTAT {
+    fn from(status: BasisStatus) -> Self {
+        match status {
+            BasisStatus::Lower => ffi::SCIP_BaseStat_SCIP_BASESTAT_LOWER,
+            BasisStatus::Basic => ffi::SCIP_BaseStat_SCIP_BASESTAT_BASIC,
+            BasisStatus::Upper => ffi::SCIP_BaseStat_SCIP_BASESTAT_UPPER,
+            BasisStatus::Zero => ffi::SCIP_BaseStat_SCIP_BASESTAT_ZERO,
+        }
+    }
+}
+
+/// Converts basis statuses to the integer array expected by `SCIPlpiSetBase`.
+pub(crate) fn basis_stats(statuses: &[BasisStatus]) -> Vec<c_int> {
+    statuses
+        .iter()
+        .map(|&status| ffi::SCIP_BASESTAT::from(status) as c_int)
+        .collect()
+}
+
 /// The origin type of row.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum RowOrigin {
@@ -245,7 +296,7 @@ impl From<ffi::SCIP_ROWORIGINTYPE> for RowOrigin {
 
 #[cfg(test)]
 mod tests {
-    use crate::prelude::{cons, eventhdlr, var};
+    use crate::prelude::{cons, eventhdlr, row, var};
     use crate::{Event, ProblemOrSolving};
     use crate::{EventMask, Eventhdlr, Model, ModelWithProblem, Solving, minimal_model};
 
@@ -308,4 +359,35 @@ mod tests {
         model.add(eventhdlr(e).name("RowTesterEventHandler"));
         model.solve();
     }
+
+    #[test]
+    fn empty_row() {
+        struct EmptyRowEventHandler;
+
+        impl Eventhdlr for EmptyRowEventHandler {
+            fn get_type(&self) -> EventMask {
+                EventMask::FIRST_LP_SOLVED
+            }
+
+            fn execute(
+                &mut self,
+                mut model: Model<Solving>,
+                _eventhdlr: crate::SCIPEventhdlr,
+                _event: Event,
+            ) {
+                // SCIP does not allocate the arrays of a row without entries
+                let row = model.add(row().eq(-1.0));
+                assert_eq!(row.n_non_zeroes(), 0);
+                assert!(row.cols().is_empty());
+                assert!(row.vals().is_empty());
+            }
+        }
+
+        let mut model = minimal_model();
+        let x = model.add(var().bin().name("x").obj(1.0));
+        model.add(cons().name("cons1").eq(1.0).coef(&x, 1.0));
+
+        model.add(eventhdlr(EmptyRowEventHandler).name("EmptyRowEventHandler"));
+        model.solve();
+    }
 }
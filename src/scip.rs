@@ -1,27 +1,40 @@
 #[cfg(feature = "datastore")]
 use anymap::AnyMap;
 
-use crate::branchrule::{BranchRule, BranchingCandidate};
+use crate::branchrule::{BranchingCandidate, TryBranchRule};
+use crate::lpi::LpiState;
+use crate::matrix::{LinearRow, SparseMatrix};
 use crate::node::Node;
-use crate::pricer::{Pricer, PricerResultState};
+use crate::pricer::{PricerResultState, TryPricer};
+use crate::progress::{ProgressCallback, ProgressRow, ProgressTracker};
+use crate::row::basis_stats;
 use crate::{
-    BranchingResult, Conshdlr, Constraint, Event, Eventhdlr, HeurResult, LPStatus, Model, ObjSense,
-    ParamSetting, Retcode, Row, SCIPBranchRule, SCIPConshdlr, SCIPEventhdlr, SCIPPricer,
-    SCIPSeparator, Separator, Solution, Solving, Status, VarType, Variable, ffi, scip_call_panic,
+    BasisStatus, BranchingResult, Constraint, Event, EventMask, HeurResult, LPStatus, LinearBlock,
+    MessageHandler, MessageKind, Model, ObjSense, ParamSetting, PluginError, Retcode, Row,
+    SCIPBranchRule, SCIPConshdlr, SCIPEventhdlr, SCIPPricer, SCIPSeparator, ScipError, Solution,
+    SolveError, Solving, Stage, Status, TryConshdlr, TryEventhdlr, TrySeparator, VarType, Variable,
+    ffi, scip_call_panic,
 };
-use crate::{HeurTiming, Heuristic, scip_call};
+use crate::{HeurTiming, TryHeuristic, scip_call};
 use core::panic;
 use scip_sys::{
     SCIP, SCIP_CONS, SCIP_CONSHDLR, SCIP_Cons, SCIP_LOCKTYPE, SCIP_NODE, SCIP_RESULT, SCIP_RETCODE,
     SCIP_SOL, SCIP_Var, Scip,
 };
-use std::collections::BTreeMap;
-use std::ffi::{CStr, CString, c_int};
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::ffi::{CStr, CString, c_char, c_int};
+use std::io::Write;
 use std::mem::MaybeUninit;
+use std::panic::AssertUnwindSafe;
 use std::rc::Rc;
 
 use crate::builder::row::{RowBuilder, RowSource};
 
+/// The active variables, coefficients and constant of an affine expression in the transformed problem.
+pub(crate) type LinearSum = (Vec<*mut ffi::SCIP_VAR>, Vec<f64>, f64);
+
 #[non_exhaustive]
 #[derive(Debug)]
 pub struct ScipPtr {
@@ -31,18 +44,94 @@ pub struct ScipPtr {
     pub(crate) weak: bool,
     /// Variables added during solving (to be released after solving)
     vars_added_in_solving: Vec<*mut ffi::SCIP_VAR>,
+    /// The heuristic being executed, credited with the solutions created through this pointer
+    pub(crate) heur: *mut ffi::SCIP_HEUR,
+}
+
+/// A failure of a plugin callback that aborted the solve.
+enum CallbackFailure {
+    /// The callback panicked with the given payload.
+    Panic(Box<dyn Any + Send>),
+    /// The callback of the given plugin returned an error.
+    Error { plugin: String, error: PluginError },
+}
+
+thread_local! {
+    /// The first failure of a plugin callback since the last call to `ScipPtr::solve`.
+    static CALLBACK_FAILURE: RefCell<Option<CallbackFailure>> = const { RefCell::new(None) };
+}
+
+fn set_callback_failure(failure: CallbackFailure) -> SCIP_RETCODE {
+    CALLBACK_FAILURE.with(|f| {
+        f.borrow_mut().get_or_insert(failure);
+    });
+    Retcode::Error.into()
+}
+
+/// Runs the body of a plugin callback, turning a panic into an error return instead of unwinding into SCIP.
+///
+/// The payload of the panic is kept until `ScipPtr::solve` (or `resume_callback_panics`) returns, which re-raises
+/// it.
+fn catch_callback_panic(callback: impl FnOnce() -> SCIP_RETCODE) -> SCIP_RETCODE {
+    match std::panic::catch_unwind(AssertUnwindSafe(callback)) {
+        Ok(retcode) => retcode,
+        Err(payload) => set_callback_failure(CallbackFailure::Panic(payload)),
+    }
+}
+
+/// Records the error returned by the plugin with the given name, which `ScipPtr::solve` returns once SCIP has
+/// aborted the solve.
+fn plugin_error(name: *const std::os::raw::c_char, error: PluginError) -> SCIP_RETCODE {
+    let plugin = unsafe { CStr::from_ptr(name) }
+        .to_string_lossy()
+        .into_owned();
+    set_callback_failure(CallbackFailure::Error { plugin, error })
+}
+
+fn take_callback_failure() -> Option<CallbackFailure> {
+    CALLBACK_FAILURE.with(|f| f.borrow_mut().take())
+}
+
+/// Converts a string to a C string, failing with `Retcode::InvalidData` if it contains a NUL byte.
+pub(crate) fn c_string(s: &str) -> Result<CString, ScipError> {
+    CString::new(s).map_err(|_| ScipError::new(Retcode::InvalidData, ""))
+}
+
+/// Copies a string owned by SCIP, e.g. the name of a variable or plugin, replacing invalid UTF-8.
+pub(crate) fn cstr_to_string(raw: *const c_char) -> String {
+    unsafe { CStr::from_ptr(raw) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Runs a SCIP call outside of a solve that may execute plugin callbacks (e.g. the checks of constraint
+/// handlers), resuming their panics. Errors returned by the callbacks are reported through the retcode of the call.
+///
+/// A failure recorded before the call (e.g. by the callback of a running solve that adds a solution) is kept.
+pub(crate) fn resume_callback_panics<T>(call: impl FnOnce() -> T) -> T {
+    let previous = take_callback_failure();
+    let result = call();
+    let failure = take_callback_failure();
+    if let Some(previous) = previous {
+        set_callback_failure(previous);
+    }
+    if let Some(CallbackFailure::Panic(payload)) = failure {
+        std::panic::resume_unwind(payload);
+    }
+    result
 }
 
 impl ScipPtr {
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new() -> Result<Self, ScipError> {
         let mut scip_ptr = MaybeUninit::uninit();
-        scip_call_panic!(ffi::SCIPcreate(scip_ptr.as_mut_ptr()));
+        scip_call!(ffi::SCIPcreate(scip_ptr.as_mut_ptr()));
         let scip_ptr = unsafe { scip_ptr.assume_init() };
-        ScipPtr {
+        Ok(ScipPtr {
             raw: scip_ptr,
             weak: false,
             vars_added_in_solving: Vec::new(),
-        }
+            heur: std::ptr::null_mut(),
+        })
     }
 
     pub(crate) fn from_raw(raw: *mut ffi::SCIP, weak: bool) -> Self {
@@ -50,121 +139,128 @@ impl ScipPtr {
             raw,
             weak,
             vars_added_in_solving: Vec::new(),
+            heur: std::ptr::null_mut(),
         }
     }
 
-    pub(crate) fn set_str_param(&self, param: &str, value: &str) -> Result<(), Retcode> {
-        let param = CString::new(param).unwrap();
-        let value = CString::new(value).unwrap();
+    pub(crate) fn set_str_param(&self, param: &str, value: &str) -> Result<(), ScipError> {
+        let param = c_string(param)?;
+        let value = c_string(value)?;
         scip_call! { ffi::SCIPsetStringParam(self.raw, param.as_ptr(), value.as_ptr()) };
         Ok(())
     }
 
-    pub(crate) fn str_param(&self, param: &str) -> Result<&str, Retcode> {
-        let param = CString::new(param).unwrap();
+    pub(crate) fn str_param(&self, param: &str) -> Result<&str, ScipError> {
+        let param = c_string(param)?;
         let mut value_ptr = MaybeUninit::uninit();
         scip_call! { ffi::SCIPgetStringParam(self.raw, param.as_ptr(), value_ptr.as_mut_ptr()) };
         let value_ptr = unsafe { value_ptr.assume_init() };
         let value = unsafe { CStr::from_ptr(value_ptr) };
-        Ok(value.to_str().unwrap())
+        value
+            .to_str()
+            .map_err(|_| ScipError::new(Retcode::InvalidData, "SCIPgetStringParam"))
     }
 
-    pub(crate) fn set_bool_param(&self, param: &str, value: bool) -> Result<(), Retcode> {
-        let param = CString::new(param).unwrap();
+    pub(crate) fn set_bool_param(&self, param: &str, value: bool) -> Result<(), ScipError> {
+        let param = c_string(param)?;
         scip_call! { ffi::SCIPsetBoolParam(self.raw, param.as_ptr(), if value { 1u32 } else { 0u32 }) };
         Ok(())
     }
 
-    pub(crate) fn bool_param(&self, param: &str) -> Result<bool, Retcode> {
-        let param = CString::new(param).unwrap();
+    pub(crate) fn bool_param(&self, param: &str) -> Result<bool, ScipError> {
+        let param = c_string(param)?;
         let mut value = MaybeUninit::uninit();
         scip_call! { ffi::SCIPgetBoolParam(self.raw, param.as_ptr(), value.as_mut_ptr()) };
         let value = unsafe { value.assume_init() };
         Ok(value != 0)
     }
 
-    pub(crate) fn set_int_param(&self, param: &str, value: i32) -> Result<(), Retcode> {
-        let param = CString::new(param).unwrap();
+    pub(crate) fn set_int_param(&self, param: &str, value: i32) -> Result<(), ScipError> {
+        let param = c_string(param)?;
         scip_call! { ffi::SCIPsetIntParam(self.raw, param.as_ptr(), value) };
         Ok(())
     }
 
-    pub(crate) fn int_param(&self, param: &str) -> Result<i32, Retcode> {
-        let param = CString::new(param).unwrap();
+    pub(crate) fn int_param(&self, param: &str) -> Result<i32, ScipError> {
+        let param = c_string(param)?;
         let mut value = MaybeUninit::uninit();
         scip_call! { ffi::SCIPgetIntParam(self.raw, param.as_ptr(), value.as_mut_ptr()) };
         let value = unsafe { value.assume_init() };
         Ok(value)
     }
 
-    pub(crate) fn set_longint_param(&self, param: &str, value: i64) -> Result<(), Retcode> {
-        let param = CString::new(param).unwrap();
+    pub(crate) fn set_longint_param(&self, param: &str, value: i64) -> Result<(), ScipError> {
+        let param = c_string(param)?;
         scip_call! { ffi::SCIPsetLongintParam(self.raw, param.as_ptr(), value) };
         Ok(())
     }
 
-    pub(crate) fn longint_param(&self, param: &str) -> Result<i64, Retcode> {
-        let param = CString::new(param).unwrap();
+    pub(crate) fn longint_param(&self, param: &str) -> Result<i64, ScipError> {
+        let param = c_string(param)?;
         let mut value = MaybeUninit::uninit();
         scip_call! { ffi::SCIPgetLongintParam(self.raw, param.as_ptr(), value.as_mut_ptr()) };
         let value = unsafe { value.assume_init() };
         Ok(value)
     }
 
-    pub(crate) fn set_real_param(&self, param: &str, value: f64) -> Result<(), Retcode> {
-        let param = CString::new(param).unwrap();
+    pub(crate) fn set_real_param(&self, param: &str, value: f64) -> Result<(), ScipError> {
+        let param = c_string(param)?;
         scip_call! { ffi::SCIPsetRealParam(self.raw, param.as_ptr(), value) };
         Ok(())
     }
 
-    pub(crate) fn real_param(&self, param: &str) -> Result<f64, Retcode> {
-        let param = CString::new(param).unwrap();
+    pub(crate) fn real_param(&self, param: &str) -> Result<f64, ScipError> {
+        let param = c_string(param)?;
         let mut value = MaybeUninit::uninit();
         scip_call! { ffi::SCIPgetRealParam(self.raw, param.as_ptr(), value.as_mut_ptr()) };
         let value = unsafe { value.assume_init() };
         Ok(value)
     }
 
-    pub(crate) fn set_presolving(&self, presolving: ParamSetting) -> Result<(), Retcode> {
+    pub(crate) fn set_presolving(&self, presolving: ParamSetting) -> Result<(), ScipError> {
         scip_call! { ffi::SCIPsetPresolving(self.raw, presolving.into(), true.into()) };
         Ok(())
     }
 
-    pub(crate) fn set_separating(&self, separating: ParamSetting) -> Result<(), Retcode> {
+    pub(crate) fn set_separating(&self, separating: ParamSetting) -> Result<(), ScipError> {
         scip_call! { ffi::SCIPsetSeparating(self.raw, separating.into(), true.into()) };
         Ok(())
     }
 
-    pub(crate) fn set_heuristics(&self, heuristics: ParamSetting) -> Result<(), Retcode> {
+    pub(crate) fn set_heuristics(&self, heuristics: ParamSetting) -> Result<(), ScipError> {
         scip_call! { ffi::SCIPsetHeuristics(self.raw, heuristics.into(), true.into()) };
         Ok(())
     }
 
-    pub(crate) fn create_prob(&self, name: &str) -> Result<(), Retcode> {
-        let name = CString::new(name).unwrap();
+    pub(crate) fn create_prob(&self, name: &str) -> Result<(), ScipError> {
+        let name = c_string(name)?;
         scip_call!(ffi::SCIPcreateProbBasic(self.raw, name.as_ptr()));
         Ok(())
     }
 
-    pub(crate) fn read_prob(&self, filename: &str) -> Result<(), Retcode> {
-        let filename = CString::new(filename).unwrap();
-        scip_call!(ffi::SCIPreadProb(
-            self.raw,
-            filename.as_ptr(),
-            std::ptr::null_mut()
-        ));
+    pub(crate) fn read_prob(&self, filename: &str) -> Result<(), ScipError> {
+        let filename = c_string(filename)?;
+        // the message handler may panic while the reader reports progress
+        resume_callback_panics(|| -> Result<(), ScipError> {
+            scip_call!(ffi::SCIPreadProb(
+                self.raw,
+                filename.as_ptr(),
+                std::ptr::null_mut()
+            ));
+            Ok(())
+        })?;
         // capture vars and cons since they were not created by the user (and SCIP will free them later)
         self.vars(false, true);
         self.conss(true);
         Ok(())
     }
 
-    pub(crate) fn set_obj_sense(&self, sense: ObjSense) -> Result<(), Retcode> {
+    pub(crate) fn set_obj_sense(&self, sense: ObjSense) -> Result<(), ScipError> {
         scip_call!(ffi::SCIPsetObjsense(self.raw, sense.into()));
         Ok(())
     }
 
-    pub(crate) fn set_obj_integral(&self) -> Result<(), Retcode> {
+    pub(crate) fn set_obj_integral(&self) -> Result<(), ScipError> {
         scip_call!(ffi::SCIPsetObjIntegral(self.raw));
         Ok(())
     }
@@ -177,8 +273,14 @@ impl ScipPtr {
         unsafe { ffi::SCIPgetNConss(self.raw) as usize }
     }
 
+    pub(crate) fn orig_conss(&self) -> Vec<*mut SCIP_Cons> {
+        let n_conss = unsafe { ffi::SCIPgetNOrigConss(self.raw) } as usize;
+        let conss = unsafe { ffi::SCIPgetOrigConss(self.raw) };
+        (0..n_conss).map(|i| unsafe { *conss.add(i) }).collect()
+    }
+
     pub(crate) fn find_cons(&self, name: &str) -> Option<*mut SCIP_Cons> {
-        let c_name = CString::new(name).unwrap();
+        let c_name = CString::new(name).ok()?;
         let scip_cons = unsafe { ffi::SCIPfindCons(self.raw, c_name.as_ptr()) };
         if scip_cons.is_null() {
             None
@@ -190,7 +292,7 @@ impl ScipPtr {
     pub(crate) fn get_transformed_cons(
         &self,
         cons: &Constraint,
-    ) -> Result<Option<*mut SCIP_Cons>, Retcode> {
+    ) -> Result<Option<*mut SCIP_Cons>, ScipError> {
         let mut transformed_cons = std::mem::MaybeUninit::<*mut ffi::SCIP_Cons>::uninit();
         scip_call! {
             ffi::SCIPgetTransformedCons(self.raw, cons.raw, transformed_cons.as_mut_ptr())
@@ -212,19 +314,171 @@ impl ScipPtr {
         unsafe { ffi::SCIPprintVersion(self.raw, std::ptr::null_mut()) };
     }
 
-    pub(crate) fn write(&self, path: &str, ext: &str) -> Result<(), Retcode> {
-        let c_path = CString::new(path).unwrap();
-        let c_ext = CString::new(ext).unwrap();
-        scip_call! { ffi::SCIPwriteOrigProblem(
-            self.raw,
-            c_path.as_ptr(),
-            c_ext.as_ptr(),
+    pub(crate) fn set_message_handler(
+        &self,
+        handler: Box<dyn MessageHandler>,
+    ) -> Result<(), ScipError> {
+        fn dispatch(
+            messagehdlr: *mut ffi::SCIP_MESSAGEHDLR,
+            file: *mut ffi::FILE,
+            msg: *const std::os::raw::c_char,
+            kind: MessageKind,
+        ) {
+            if msg.is_null() {
+                return;
+            }
+            // only the output to the terminal goes to the handler, the output for files (e.g. when writing a
+            // problem or a solution, or every message a second time for the log file) is written as is
+            let to_terminal = file.is_null() || matches!(unsafe { ffi::fileno(file) }, 1 | 2);
+            if !to_terminal {
+                unsafe { ffi::fputs(msg, file) };
+                return;
+            }
+            catch_callback_panic(|| {
+                let data_ptr = unsafe { ffi::SCIPmessagehdlrGetData(messagehdlr) };
+                assert!(!data_ptr.is_null());
+                let handler = unsafe { &mut *(data_ptr as *mut Box<dyn MessageHandler>) };
+                let msg = unsafe { CStr::from_ptr(msg) }.to_string_lossy();
+                for line in msg.lines() {
+                    handler.message(kind, line);
+                }
+                Retcode::Okay.into()
+            });
+        }
+
+        unsafe extern "C" fn messagewarning(
+            messagehdlr: *mut ffi::SCIP_MESSAGEHDLR,
+            file: *mut ffi::FILE,
+            msg: *const std::os::raw::c_char,
+        ) {
+            dispatch(messagehdlr, file, msg, MessageKind::Warning);
+        }
+
+        unsafe extern "C" fn messagedialog(
+            messagehdlr: *mut ffi::SCIP_MESSAGEHDLR,
+            file: *mut ffi::FILE,
+            msg: *const std::os::raw::c_char,
+        ) {
+            dispatch(messagehdlr, file, msg, MessageKind::Dialog);
+        }
+
+        unsafe extern "C" fn messageinfo(
+            messagehdlr: *mut ffi::SCIP_MESSAGEHDLR,
+            file: *mut ffi::FILE,
+            msg: *const std::os::raw::c_char,
+        ) {
+            dispatch(messagehdlr, file, msg, MessageKind::Info);
+        }
+
+        unsafe extern "C" fn messagehdlrfree(
+            messagehdlr: *mut ffi::SCIP_MESSAGEHDLR,
+        ) -> ffi::SCIP_Retcode {
+            catch_callback_panic(|| {
+                let data_ptr = unsafe { ffi::SCIPmessagehdlrGetData(messagehdlr) };
+                assert!(!data_ptr.is_null());
+                drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn MessageHandler>) });
+                Retcode::Okay.into()
+            })
+        }
+
+        let handler_ptr = Box::into_raw(Box::new(handler));
+        let mut messagehdlr = MaybeUninit::uninit();
+        scip_call!(ffi::SCIPmessagehdlrCreate(
+            messagehdlr.as_mut_ptr(),
             true.into(),
-        ) };
+            std::ptr::null(),
+            false.into(),
+            Some(messagewarning),
+            Some(messagedialog),
+            Some(messageinfo),
+            Some(messagehdlrfree),
+            handler_ptr as *mut ffi::SCIP_MESSAGEHDLRDATA,
+        ));
+        let mut messagehdlr = unsafe { messagehdlr.assume_init() };
+
+        // the SCIP instance captures the handler and frees it together with the closure when it is freed or replaced
+        scip_call!(ffi::SCIPsetMessagehdlr(self.raw, messagehdlr));
+        scip_call!(ffi::SCIPmessagehdlrRelease(&mut messagehdlr));
         Ok(())
     }
 
-    pub(crate) fn include_default_plugins(&self) -> Result<(), Retcode> {
+    pub(crate) fn set_log_file(&self, path: &str) {
+        let c_path = CString::new(path).unwrap();
+        unsafe { ffi::SCIPsetMessagehdlrLogfile(self.raw, c_path.as_ptr()) };
+    }
+
+    pub(crate) fn write_transformed(&self, path: &str, ext: &str) -> Result<(), ScipError> {
+        let c_path = c_string(path)?;
+        let c_ext = c_string(ext)?;
+        resume_callback_panics(|| {
+            scip_call! { ffi::SCIPwriteTransProblem(
+                self.raw,
+                c_path.as_ptr(),
+                c_ext.as_ptr(),
+                false.into(),
+            ) };
+            Ok(())
+        })
+    }
+
+    /// Returns the active variables, coefficients and constant that the given variable is replaced by in the
+    /// transformed problem, or `None` if the variable is original and was not transformed.
+    pub(crate) fn probvar_linear_sum(
+        &self,
+        var: *mut ffi::SCIP_VAR,
+    ) -> Result<Option<LinearSum>, ScipError> {
+        let var = if unsafe { ffi::SCIPvarIsOriginal(var) } != 0 {
+            unsafe { ffi::SCIPvarGetTransVar(var) }
+        } else {
+            var
+        };
+        if var.is_null() {
+            return Ok(None);
+        }
+
+        let mut size = 1;
+        loop {
+            let mut vars = vec![std::ptr::null_mut(); size];
+            let mut scalars = vec![0.0; size];
+            vars[0] = var;
+            scalars[0] = 1.0;
+            let mut n_vars = 1;
+            let mut constant = 0.0;
+            let mut required_size = 0;
+            scip_call!(ffi::SCIPgetProbvarLinearSum(
+                self.raw,
+                vars.as_mut_ptr(),
+                scalars.as_mut_ptr(),
+                &mut n_vars,
+                size as c_int,
+                &mut constant,
+                &mut required_size,
+            ));
+            if required_size as usize > size {
+                size = required_size as usize;
+                continue;
+            }
+            vars.truncate(n_vars as usize);
+            scalars.truncate(n_vars as usize);
+            return Ok(Some((vars, scalars, constant)));
+        }
+    }
+
+    pub(crate) fn write(&self, path: &str, ext: &str) -> Result<(), ScipError> {
+        let c_path = c_string(path)?;
+        let c_ext = c_string(ext)?;
+        resume_callback_panics(|| {
+            scip_call! { ffi::SCIPwriteOrigProblem(
+                self.raw,
+                c_path.as_ptr(),
+                c_ext.as_ptr(),
+                true.into(),
+            ) };
+            Ok(())
+        })
+    }
+
+    pub(crate) fn include_default_plugins(&self) -> Result<(), ScipError> {
         scip_call!(ffi::SCIPincludeDefaultPlugins(self.raw));
         Ok(())
     }
@@ -275,11 +529,56 @@ impl ScipPtr {
         conss
     }
 
-    pub(crate) fn solve(&self) -> Result<(), Retcode> {
-        scip_call!(ffi::SCIPsolve(self.raw));
+    /// Solves the problem, returning the error of a plugin or resuming the panic of a plugin callback that
+    /// aborted the solve.
+    pub(crate) fn solve(&self) -> Result<(), SolveError> {
+        self.run_with_plugins(|| {
+            let retcode = unsafe { ffi::SCIPsolve(self.raw) };
+            if let Some(tracker) = self.progress_tracker() {
+                // like the display, finish the table with the final state of the solve
+                tracker.record(ProgressRow::current(self.raw, false));
+            }
+            (retcode, "SCIPsolve")
+        })
+    }
+
+    pub(crate) fn presolve(&self) -> Result<(), SolveError> {
+        self.run_with_plugins(|| (unsafe { ffi::SCIPpresolve(self.raw) }, "SCIPpresolve"))
+    }
+
+    pub(crate) fn count(&self) -> Result<(), SolveError> {
+        self.run_with_plugins(|| (unsafe { ffi::SCIPcount(self.raw) }, "SCIPcount"))
+    }
+
+    /// Runs a SCIP call that executes plugin callbacks, resuming their panics and returning their errors.
+    fn run_with_plugins(
+        &self,
+        call: impl FnOnce() -> (SCIP_RETCODE, &'static str),
+    ) -> Result<(), SolveError> {
+        // errors of callbacks outside of a solve were already returned by the call that ran them, but a panic (e.g.
+        // of the message handler) is not lost
+        if let Some(CallbackFailure::Panic(payload)) = take_callback_failure() {
+            std::panic::resume_unwind(payload);
+        }
+        let (retcode, function) = call();
+        match take_callback_failure() {
+            Some(CallbackFailure::Panic(payload)) => std::panic::resume_unwind(payload),
+            Some(CallbackFailure::Error { plugin, error }) => {
+                return Err(SolveError::Plugin { plugin, error });
+            }
+            None => {}
+        }
+        let retcode = Retcode::from(retcode);
+        if retcode != Retcode::Okay {
+            return Err(SolveError::Scip(ScipError::new(retcode, function)));
+        }
         Ok(())
     }
 
+    pub(crate) fn stage(&self) -> Stage {
+        unsafe { ffi::SCIPgetStage(self.raw) }.into()
+    }
+
     pub(crate) fn n_sols(&self) -> usize {
         unsafe { ffi::SCIPgetNSols(self.raw) as usize }
     }
@@ -323,8 +622,8 @@ impl ScipPtr {
         obj: f64,
         name: &str,
         var_type: VarType,
-    ) -> Result<*mut SCIP_Var, Retcode> {
-        let name = CString::new(name).unwrap();
+    ) -> Result<*mut SCIP_Var, ScipError> {
+        let name = c_string(name)?;
         let mut var_ptr = MaybeUninit::uninit();
         scip_call! { ffi::SCIPcreateVarBasic(
             self.raw,
@@ -347,8 +646,8 @@ impl ScipPtr {
         obj: f64,
         name: &str,
         var_type: VarType,
-    ) -> Result<*mut SCIP_Var, Retcode> {
-        let name = CString::new(name).unwrap();
+    ) -> Result<*mut SCIP_Var, ScipError> {
+        let name = c_string(name)?;
         let mut var_ptr = MaybeUninit::uninit();
         scip_call! { ffi::SCIPcreateVarBasic(
             self.raw,
@@ -372,7 +671,7 @@ impl ScipPtr {
         unsafe { ffi::SCIPisLPConstructed(self.raw) != 0 }
     }
 
-    pub(crate) fn construct_lp(&self) -> Result<Option<bool>, Retcode> {
+    pub(crate) fn construct_lp(&self) -> Result<Option<bool>, ScipError> {
         let mut cutoff = 0;
         scip_call! { ffi::SCIPconstructLP(self.raw, &mut cutoff) }
         Ok(Some(cutoff != 0))
@@ -385,8 +684,8 @@ impl ScipPtr {
         obj: f64,
         name: &str,
         var_type: VarType,
-    ) -> Result<*mut SCIP_Var, Retcode> {
-        let name = CString::new(name).unwrap();
+    ) -> Result<*mut SCIP_Var, ScipError> {
+        let name = c_string(name)?;
         let mut var_ptr = MaybeUninit::uninit();
         scip_call! { ffi::SCIPcreateVarBasic(
             self.raw,
@@ -415,9 +714,9 @@ impl ScipPtr {
         rhs: f64,
         name: &str,
         local: bool,
-    ) -> Result<*mut SCIP_Cons, Retcode> {
+    ) -> Result<*mut SCIP_Cons, ScipError> {
         assert_eq!(vars.len(), coefs.len());
-        let c_name = CString::new(name).unwrap();
+        let c_name = c_string(name)?;
         let mut scip_cons = MaybeUninit::uninit();
         scip_call! { ffi::SCIPcreateConsBasicLinear(
             self.raw,
@@ -452,13 +751,71 @@ impl ScipPtr {
         Ok(scip_cons)
     }
 
+    /// Creates all variables and linear constraints of the given block, without creating
+    /// intermediate Rust handles.
+    pub(crate) fn add_linear_block(
+        &self,
+        block: &LinearBlock,
+    ) -> Result<(Vec<*mut SCIP_Var>, Vec<*mut SCIP_Cons>), ScipError> {
+        let first_var = unsafe { ffi::SCIPgetNOrigVars(self.raw) } as usize;
+        let first_cons = unsafe { ffi::SCIPgetNOrigConss(self.raw) } as usize;
+        // names are written into a single reused buffer
+        let mut name = Vec::new();
+
+        let mut vars = Vec::with_capacity(block.n_vars());
+        for i in 0..block.n_vars() {
+            name.clear();
+            write!(name, "x{}\0", first_var + i).unwrap();
+            let mut var_ptr = MaybeUninit::uninit();
+            scip_call! { ffi::SCIPcreateVarBasic(
+                self.raw,
+                var_ptr.as_mut_ptr(),
+                name.as_ptr() as *const _,
+                block.lb[i],
+                block.ub[i],
+                block.obj[i],
+                block.var_types[i].into(),
+            ) };
+            let var_ptr = unsafe { var_ptr.assume_init() };
+            scip_call! { ffi::SCIPaddVar(self.raw, var_ptr) };
+            vars.push(var_ptr);
+        }
+
+        let matrix = block.matrix.to_csr();
+        let mut conss = Vec::with_capacity(block.n_conss());
+        let mut row_vars = Vec::new();
+        for j in 0..block.n_conss() {
+            let (cols, vals) = matrix.row(j);
+            row_vars.clear();
+            row_vars.extend(cols.iter().map(|&col| vars[col]));
+            name.clear();
+            write!(name, "c{}\0", first_cons + j).unwrap();
+            let mut scip_cons = MaybeUninit::uninit();
+            scip_call! { ffi::SCIPcreateConsBasicLinear(
+                self.raw,
+                scip_cons.as_mut_ptr(),
+                name.as_ptr() as *const _,
+                row_vars.len() as c_int,
+                row_vars.as_mut_ptr(),
+                vals.as_ptr() as *mut f64,
+                block.lhs[j],
+                block.rhs[j],
+            ) };
+            let scip_cons = unsafe { scip_cons.assume_init() };
+            scip_call! { ffi::SCIPaddCons(self.raw, scip_cons) };
+            conss.push(scip_cons);
+        }
+
+        Ok((vars, conss))
+    }
+
     /// Create set partitioning constraint
     pub(crate) fn create_cons_set_part(
         &self,
         vars: Vec<&Variable>,
         name: &str,
-    ) -> Result<*mut SCIP_Cons, Retcode> {
-        let c_name = CString::new(name).unwrap();
+    ) -> Result<*mut SCIP_Cons, ScipError> {
+        let c_name = c_string(name)?;
         let mut scip_cons = MaybeUninit::uninit();
         scip_call! { ffi::SCIPcreateConsBasicSetpart(
             self.raw,
@@ -480,8 +837,8 @@ impl ScipPtr {
         &self,
         vars: Vec<&Variable>,
         name: &str,
-    ) -> Result<*mut SCIP_Cons, Retcode> {
-        let c_name = CString::new(name).unwrap();
+    ) -> Result<*mut SCIP_Cons, ScipError> {
+        let c_name = c_string(name)?;
         let mut scip_cons = MaybeUninit::uninit();
         scip_call! { ffi::SCIPcreateConsBasicSetcover(
             self.raw,
@@ -508,7 +865,7 @@ impl ScipPtr {
         lhs: f64,
         rhs: f64,
         name: &str,
-    ) -> Result<*mut SCIP_Cons, Retcode> {
+    ) -> Result<*mut SCIP_Cons, ScipError> {
         assert_eq!(lin_vars.len(), lin_coefs.len());
         assert!(
             lin_vars.len() <= c_int::MAX as usize,
@@ -521,7 +878,7 @@ impl ScipPtr {
             "Number of quadratic terms exceeds SCIP capabilities"
         );
 
-        let c_name = CString::new(name).unwrap();
+        let c_name = c_string(name)?;
         let mut scip_cons = MaybeUninit::uninit();
 
         let get_ptrs = |vars: Vec<&Variable>| {
@@ -557,8 +914,8 @@ impl ScipPtr {
         &self,
         vars: Vec<&Variable>,
         name: &str,
-    ) -> Result<*mut SCIP_Cons, Retcode> {
-        let c_name = CString::new(name).unwrap();
+    ) -> Result<*mut SCIP_Cons, ScipError> {
+        let c_name = c_string(name)?;
         let mut scip_cons = MaybeUninit::uninit();
         scip_call! { ffi::SCIPcreateConsBasicSetpack(
             self.raw,
@@ -581,8 +938,8 @@ impl ScipPtr {
         vars: Vec<&Variable>,
         cardinality: usize,
         name: &str,
-    ) -> Result<*mut SCIP_Cons, Retcode> {
-        let c_name = CString::new(name).unwrap();
+    ) -> Result<*mut SCIP_Cons, ScipError> {
+        let c_name = c_string(name)?;
         let mut scip_cons = MaybeUninit::uninit();
         scip_call! { ffi::SCIPcreateConsBasicCardinality(
             self.raw,
@@ -626,9 +983,9 @@ impl ScipPtr {
         coefs: &mut [f64],
         rhs: f64,
         name: &str,
-    ) -> Result<*mut SCIP_Cons, Retcode> {
+    ) -> Result<*mut SCIP_Cons, ScipError> {
         assert_eq!(vars.len(), coefs.len());
-        let c_name = CString::new(name).unwrap();
+        let c_name = c_string(name)?;
         let mut scip_cons = MaybeUninit::uninit();
 
         scip_call! { ffi::SCIPcreateConsBasicIndicator(
@@ -650,12 +1007,12 @@ impl ScipPtr {
     }
 
     /// Create solution
-    pub(crate) fn create_sol(&self, original: bool) -> Result<*mut SCIP_SOL, Retcode> {
+    pub(crate) fn create_sol(&self, original: bool) -> Result<*mut SCIP_SOL, ScipError> {
         let mut sol = MaybeUninit::uninit();
         if original {
-            scip_call! { ffi::SCIPcreateOrigSol(self.raw, sol.as_mut_ptr(), std::ptr::null_mut()) }
+            scip_call! { ffi::SCIPcreateOrigSol(self.raw, sol.as_mut_ptr(), self.heur) }
         } else {
-            scip_call! { ffi::SCIPcreateSol(self.raw, sol.as_mut_ptr(), std::ptr::null_mut()) }
+            scip_call! { ffi::SCIPcreateSol(self.raw, sol.as_mut_ptr(), self.heur) }
         }
         let sol = unsafe { sol.assume_init() };
         assert!(!sol.is_null());
@@ -667,7 +1024,7 @@ impl ScipPtr {
         &self,
         cons: &Constraint,
         var: &Variable,
-    ) -> Result<(), Retcode> {
+    ) -> Result<(), ScipError> {
         scip_call! { ffi::SCIPaddCoefSetppc(self.raw, cons.raw, var.raw) };
         Ok(())
     }
@@ -712,10 +1069,10 @@ impl ScipPtr {
         scip: *mut ffi::SCIP,
         var_prob_id: usize,
         val: f64,
-    ) -> Result<(), Retcode> {
+    ) -> Result<(), ScipError> {
         let var = ScipPtr::var_from_id(scip, var_prob_id);
         if var.is_none() {
-            return Err(Retcode::Error);
+            return Err(Retcode::Error.into());
         }
         let var = var.unwrap();
         scip_call! { ffi::SCIPbranchVarVal(scip, var, val, std::ptr::null_mut(), std::ptr::null_mut(),std::ptr::null_mut()) }
@@ -726,63 +1083,72 @@ impl ScipPtr {
         &self,
         name: &str,
         desc: &str,
-        eventhdlr: Box<dyn Eventhdlr>,
-    ) -> Result<(), Retcode> {
+        eventhdlr: Box<dyn TryEventhdlr>,
+    ) -> Result<(), ScipError> {
         extern "C" fn eventhdlrexec(
             scip: *mut ffi::SCIP,
             eventhdlr: *mut ffi::SCIP_EVENTHDLR,
             event: *mut ffi::SCIP_EVENT,
             _event_data: *mut ffi::SCIP_EVENTDATA,
         ) -> ffi::SCIP_Retcode {
-            let data_ptr = unsafe { ffi::SCIPeventhdlrGetData(eventhdlr) };
-            assert!(!data_ptr.is_null());
-            let eventhdlr_ptr = data_ptr as *mut Box<dyn Eventhdlr>;
-            let scip_ptr = Rc::new(ScipPtr::from_raw(scip, true));
-            let model = Model {
-                scip: scip_ptr.clone(),
-                state: Solving,
-            };
-            let eventhdlr = SCIPEventhdlr { raw: eventhdlr };
-            let event = Event {
-                raw: event,
-                scip: scip_ptr.clone(),
-            };
-            unsafe { (*eventhdlr_ptr).execute(model, eventhdlr, event) };
-            Retcode::Okay.into()
+            catch_callback_panic(|| {
+                let data_ptr = unsafe { ffi::SCIPeventhdlrGetData(eventhdlr) };
+                assert!(!data_ptr.is_null());
+                let eventhdlr_ptr = data_ptr as *mut Box<dyn TryEventhdlr>;
+                let scip_ptr = Rc::new(ScipPtr::from_raw(scip, true));
+                let model = Model {
+                    scip: scip_ptr.clone(),
+                    state: Solving,
+                };
+                let name = unsafe { ffi::SCIPeventhdlrGetName(eventhdlr) };
+                let eventhdlr = SCIPEventhdlr { raw: eventhdlr };
+                let event = Event {
+                    raw: event,
+                    scip: scip_ptr.clone(),
+                };
+                match unsafe { (*eventhdlr_ptr).try_execute(model, eventhdlr, event) } {
+                    Ok(()) => Retcode::Okay.into(),
+                    Err(error) => plugin_error(name, error),
+                }
+            })
         }
 
         extern "C" fn eventhdlrinit(
             scip: *mut ffi::SCIP,
             eventhdlr: *mut ffi::SCIP_EVENTHDLR,
         ) -> ffi::SCIP_Retcode {
-            let data_ptr = unsafe { ffi::SCIPeventhdlrGetData(eventhdlr) };
-            assert!(!data_ptr.is_null());
-            let eventhdlr_ptr = data_ptr as *mut Box<dyn Eventhdlr>;
-            let event_type = unsafe { (*eventhdlr_ptr).get_type() };
-            unsafe {
-                ffi::SCIPcatchEvent(
-                    scip,
-                    event_type.into(),
-                    eventhdlr,
-                    std::ptr::null_mut(),
-                    std::ptr::null_mut(),
-                )
-            }
+            catch_callback_panic(|| {
+                let data_ptr = unsafe { ffi::SCIPeventhdlrGetData(eventhdlr) };
+                assert!(!data_ptr.is_null());
+                let eventhdlr_ptr = data_ptr as *mut Box<dyn TryEventhdlr>;
+                let event_type = unsafe { (*eventhdlr_ptr).event_type() };
+                unsafe {
+                    ffi::SCIPcatchEvent(
+                        scip,
+                        event_type.into(),
+                        eventhdlr,
+                        std::ptr::null_mut(),
+                        std::ptr::null_mut(),
+                    )
+                }
+            })
         }
 
         unsafe extern "C" fn eventhdlrfree(
             _scip: *mut ffi::SCIP,
             eventhdlr: *mut ffi::SCIP_EVENTHDLR,
         ) -> ffi::SCIP_Retcode {
-            let data_ptr = unsafe { ffi::SCIPeventhdlrGetData(eventhdlr) };
-            assert!(!data_ptr.is_null());
-            let eventhdlr_ptr = data_ptr as *mut Box<dyn Eventhdlr>;
-            drop(unsafe { Box::from_raw(eventhdlr_ptr) });
-            Retcode::Okay.into()
+            catch_callback_panic(|| {
+                let data_ptr = unsafe { ffi::SCIPeventhdlrGetData(eventhdlr) };
+                assert!(!data_ptr.is_null());
+                let eventhdlr_ptr = data_ptr as *mut Box<dyn TryEventhdlr>;
+                drop(unsafe { Box::from_raw(eventhdlr_ptr) });
+                Retcode::Okay.into()
+            })
         }
 
-        let c_name = CString::new(name).unwrap();
-        let c_desc = CString::new(desc).unwrap();
+        let c_name = c_string(name)?;
+        let c_desc = c_string(desc)?;
         let eventhdlr_ptr = Box::into_raw(Box::new(eventhdlr));
 
         scip_call! {
@@ -812,10 +1178,10 @@ impl ScipPtr {
         priority: i32,
         maxdepth: i32,
         maxbounddist: f64,
-        rule: Box<dyn BranchRule>,
-    ) -> Result<(), Retcode> {
-        let c_name = CString::new(name).unwrap();
-        let c_desc = CString::new(desc).unwrap();
+        rule: Box<dyn TryBranchRule>,
+    ) -> Result<(), ScipError> {
+        let c_name = c_string(name)?;
+        let c_desc = c_string(desc)?;
 
         // TODO: Add rest of branching rule plugin callbacks
 
@@ -825,50 +1191,59 @@ impl ScipPtr {
             _: u32,
             res: *mut ffi::SCIP_RESULT,
         ) -> ffi::SCIP_Retcode {
-            let data_ptr = unsafe { ffi::SCIPbranchruleGetData(branchrule) };
-            assert!(!data_ptr.is_null());
-            let rule_ptr = data_ptr as *mut Box<dyn BranchRule>;
-            let cands = unsafe { ScipPtr::lp_branching_cands(scip) }
-                .into_iter()
-                .map(|(scip_var, lp_sol_val, frac)| BranchingCandidate {
-                    var_prob_id: unsafe { ffi::SCIPvarGetProbindex(scip_var) } as usize,
-                    lp_sol_val,
-                    frac,
-                })
-                .collect::<Vec<_>>();
-            let scip_ptr = ScipPtr::from_raw(scip, true);
-            let model = Model {
-                scip: Rc::new(scip_ptr),
-                state: Solving,
-            };
-            let branchrule = SCIPBranchRule { raw: branchrule };
-            let branching_res = unsafe { (*rule_ptr).execute(model, branchrule, cands) };
+            catch_callback_panic(|| {
+                let data_ptr = unsafe { ffi::SCIPbranchruleGetData(branchrule) };
+                assert!(!data_ptr.is_null());
+                let rule_ptr = data_ptr as *mut Box<dyn TryBranchRule>;
+                let cands = unsafe { ScipPtr::lp_branching_cands(scip) }
+                    .into_iter()
+                    .map(|(scip_var, lp_sol_val, frac)| BranchingCandidate {
+                        var_prob_id: unsafe { ffi::SCIPvarGetProbindex(scip_var) } as usize,
+                        lp_sol_val,
+                        frac,
+                    })
+                    .collect::<Vec<_>>();
+                let scip_ptr = ScipPtr::from_raw(scip, true);
+                let model = Model {
+                    scip: Rc::new(scip_ptr),
+                    state: Solving,
+                };
+                let name = unsafe { ffi::SCIPbranchruleGetName(branchrule) };
+                let branchrule = SCIPBranchRule { raw: branchrule };
+                let branching_res =
+                    match unsafe { (*rule_ptr).try_execute(model, branchrule, cands) } {
+                        Ok(res) => res,
+                        Err(error) => return plugin_error(name, error),
+                    };
+
+                if let BranchingResult::BranchOn(cand) = branching_res.clone() {
+                    unsafe {
+                        ScipPtr::branch_var_val(scip, cand.var_prob_id, cand.lp_sol_val).unwrap();
+                    }
+                };
 
-            if let BranchingResult::BranchOn(cand) = branching_res.clone() {
-                unsafe {
-                    ScipPtr::branch_var_val(scip, cand.var_prob_id, cand.lp_sol_val).unwrap();
+                if branching_res == BranchingResult::CustomBranching {
+                    assert!(
+                        unsafe { ffi::SCIPgetNChildren(scip) > 0 },
+                        "Custom branching rule must create at least one child node"
+                    )
                 }
-            };
-
-            if branching_res == BranchingResult::CustomBranching {
-                assert!(
-                    unsafe { ffi::SCIPgetNChildren(scip) > 0 },
-                    "Custom branching rule must create at least one child node"
-                )
-            }
 
-            unsafe { *res = branching_res.into() };
-            Retcode::Okay.into()
+                unsafe { *res = branching_res.into() };
+                Retcode::Okay.into()
+            })
         }
 
         extern "C" fn branchfree(
             _scip: *mut ffi::SCIP,
             branchrule: *mut ffi::SCIP_BRANCHRULE,
         ) -> ffi::SCIP_Retcode {
-            let data_ptr = unsafe { ffi::SCIPbranchruleGetData(branchrule) };
-            assert!(!data_ptr.is_null());
-            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn BranchRule>) });
-            Retcode::Okay.into()
+            catch_callback_panic(|| {
+                let data_ptr = unsafe { ffi::SCIPbranchruleGetData(branchrule) };
+                assert!(!data_ptr.is_null());
+                drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn TryBranchRule>) });
+                Retcode::Okay.into()
+            })
         }
 
         let rule_ptr = Box::into_raw(Box::new(rule));
@@ -902,10 +1277,10 @@ impl ScipPtr {
         desc: &str,
         priority: i32,
         delay: bool,
-        pricer: Box<dyn Pricer>,
-    ) -> Result<(), Retcode> {
-        let c_name = CString::new(name).unwrap();
-        let c_desc = CString::new(desc).unwrap();
+        pricer: Box<dyn TryPricer>,
+    ) -> Result<(), ScipError> {
+        let c_name = c_string(name)?;
+        let c_desc = c_string(desc)?;
 
         pub(crate) fn call_pricer(
             scip: *mut ffi::SCIP,
@@ -915,43 +1290,50 @@ impl ScipPtr {
             result: *mut ffi::SCIP_RESULT,
             farkas: bool,
         ) -> ffi::SCIP_Retcode {
-            let data_ptr = unsafe { ffi::SCIPpricerGetData(pricer) };
-            assert!(!data_ptr.is_null());
-            let pricer_ptr = data_ptr as *mut Box<dyn Pricer>;
+            catch_callback_panic(|| {
+                let data_ptr = unsafe { ffi::SCIPpricerGetData(pricer) };
+                assert!(!data_ptr.is_null());
+                let pricer_ptr = data_ptr as *mut Box<dyn TryPricer>;
 
-            let n_vars_before = unsafe { ffi::SCIPgetNVars(scip) };
-
-            let scip_ptr = ScipPtr::from_raw(scip, true);
-            let model = Model {
-                scip: Rc::new(scip_ptr),
-                state: Solving,
-            };
+                let n_vars_before = unsafe { ffi::SCIPgetNVars(scip) };
 
-            let pricer = SCIPPricer { raw: pricer };
-            let pricing_res = unsafe { (*pricer_ptr).generate_columns(model, pricer, farkas) };
+                let scip_ptr = ScipPtr::from_raw(scip, true);
+                let model = Model {
+                    scip: Rc::new(scip_ptr),
+                    state: Solving,
+                };
 
-            if !farkas {
-                if let Some(lb) = pricing_res.lower_bound {
-                    unsafe { *lowerbound = lb };
-                }
-                if pricing_res.state == PricerResultState::StopEarly {
-                    unsafe { *stopearly = 1 };
+                let name = unsafe { ffi::SCIPpricerGetName(pricer) };
+                let pricer = SCIPPricer { raw: pricer };
+                let pricing_res =
+                    match unsafe { (*pricer_ptr).try_generate_columns(model, pricer, farkas) } {
+                        Ok(res) => res,
+                        Err(error) => return plugin_error(name, error),
+                    };
+
+                if !farkas {
+                    if let Some(lb) = pricing_res.lower_bound {
+                        unsafe { *lowerbound = lb };
+                    }
+                    if pricing_res.state == PricerResultState::StopEarly {
+                        unsafe { *stopearly = 1 };
+                    }
                 }
-            }
 
-            if farkas && pricing_res.state == PricerResultState::StopEarly {
-                panic!("Farkas pricing should never stop early as LP would remain infeasible");
-            }
+                if farkas && pricing_res.state == PricerResultState::StopEarly {
+                    panic!("Farkas pricing should never stop early as LP would remain infeasible");
+                }
 
-            if pricing_res.state == PricerResultState::FoundColumns {
-                let n_vars_after = unsafe { ffi::SCIPgetNVars(scip) };
-                if n_vars_before >= n_vars_after {
-                    return Retcode::Error.into();
+                if pricing_res.state == PricerResultState::FoundColumns {
+                    let n_vars_after = unsafe { ffi::SCIPgetNVars(scip) };
+                    if n_vars_before >= n_vars_after {
+                        return Retcode::Error.into();
+                    }
                 }
-            }
 
-            unsafe { *result = pricing_res.state.into() };
-            Retcode::Okay.into()
+                unsafe { *result = pricing_res.state.into() };
+                Retcode::Okay.into()
+            })
         }
 
         unsafe extern "C" fn pricerredcost(
@@ -983,10 +1365,12 @@ impl ScipPtr {
             _scip: *mut ffi::SCIP,
             pricer: *mut ffi::SCIP_PRICER,
         ) -> ffi::SCIP_Retcode {
-            let data_ptr = unsafe { ffi::SCIPpricerGetData(pricer) };
-            assert!(!data_ptr.is_null());
-            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn Pricer>) });
-            Retcode::Okay.into()
+            catch_callback_panic(|| {
+                let data_ptr = unsafe { ffi::SCIPpricerGetData(pricer) };
+                assert!(!data_ptr.is_null());
+                drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn TryPricer>) });
+                Retcode::Okay.into()
+            })
         }
 
         let pricer_ptr = Box::into_raw(Box::new(pricer));
@@ -1027,10 +1411,10 @@ impl ScipPtr {
         maxdepth: i32,
         timing: HeurTiming,
         usessubscip: bool,
-        heur: Box<dyn Heuristic>,
-    ) -> Result<(), Retcode> {
-        let c_name = CString::new(name).unwrap();
-        let c_desc = CString::new(desc).unwrap();
+        heur: Box<dyn TryHeuristic>,
+    ) -> Result<(), ScipError> {
+        let c_name = c_string(name)?;
+        let c_desc = c_string(desc)?;
 
         extern "C" fn heurexec(
             scip: *mut ffi::SCIP,
@@ -1039,43 +1423,54 @@ impl ScipPtr {
             nodeinfeasible: ::std::os::raw::c_uint,
             result: *mut ffi::SCIP_RESULT,
         ) -> ffi::SCIP_RETCODE {
-            let data_ptr = unsafe { ffi::SCIPheurGetData(heur) };
-            assert!(!data_ptr.is_null());
-            let rule_ptr = data_ptr as *mut Box<dyn Heuristic>;
-
-            let current_n_sols = unsafe { ffi::SCIPgetNSols(scip) };
-            let scip_ptr = ScipPtr::from_raw(scip, true);
-            let model = Model {
-                scip: Rc::new(scip_ptr),
-                state: Solving,
-            };
-            let heur_res =
-                unsafe { (*rule_ptr).execute(model, heurtiming.into(), nodeinfeasible != 0) };
-            if heur_res == HeurResult::FoundSol {
-                let new_n_sols = unsafe { ffi::SCIPgetNSols(scip) };
-
-                if new_n_sols <= current_n_sols {
-                    let heur_name =
-                        unsafe { CStr::from_ptr(ffi::SCIPheurGetName(heur)).to_str().unwrap() };
-                    eprintln!(
-                        "Heuristic {heur_name} returned result {heur_res:?}, but no solutions were added"
-                    );
-                    return Retcode::Error.into();
+            catch_callback_panic(|| {
+                let data_ptr = unsafe { ffi::SCIPheurGetData(heur) };
+                assert!(!data_ptr.is_null());
+                let rule_ptr = data_ptr as *mut Box<dyn TryHeuristic>;
+
+                let current_n_sols = unsafe { ffi::SCIPgetNSols(scip) };
+                let mut scip_ptr = ScipPtr::from_raw(scip, true);
+                scip_ptr.heur = heur;
+                let model = Model {
+                    scip: Rc::new(scip_ptr),
+                    state: Solving,
+                };
+                let heur_res = match unsafe {
+                    (*rule_ptr).try_execute(model, heurtiming.into(), nodeinfeasible != 0)
+                } {
+                    Ok(res) => res,
+                    Err(error) => {
+                        return plugin_error(unsafe { ffi::SCIPheurGetName(heur) }, error);
+                    }
+                };
+                if heur_res == HeurResult::FoundSol {
+                    let new_n_sols = unsafe { ffi::SCIPgetNSols(scip) };
+
+                    if new_n_sols <= current_n_sols {
+                        let heur_name =
+                            unsafe { CStr::from_ptr(ffi::SCIPheurGetName(heur)).to_str().unwrap() };
+                        eprintln!(
+                            "Heuristic {heur_name} returned result {heur_res:?}, but no solutions were added"
+                        );
+                        return Retcode::Error.into();
+                    }
                 }
-            }
 
-            unsafe { *result = heur_res.into() };
-            Retcode::Okay.into()
+                unsafe { *result = heur_res.into() };
+                Retcode::Okay.into()
+            })
         }
 
         extern "C" fn heurfree(
             _scip: *mut ffi::SCIP,
             heur: *mut ffi::SCIP_HEUR,
         ) -> ffi::SCIP_Retcode {
-            let data_ptr = unsafe { ffi::SCIPheurGetData(heur) };
-            assert!(!data_ptr.is_null());
-            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn Heuristic>) });
-            Retcode::Okay.into()
+            catch_callback_panic(|| {
+                let data_ptr = unsafe { ffi::SCIPheurGetData(heur) };
+                assert!(!data_ptr.is_null());
+                drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn TryHeuristic>) });
+                Retcode::Okay.into()
+            })
         }
 
         let ptr = Box::into_raw(Box::new(heur));
@@ -1114,10 +1509,10 @@ impl ScipPtr {
         maxbounddist: f64,
         usesubscip: bool,
         delay: bool,
-        separator: Box<dyn Separator>,
-    ) -> Result<(), Retcode> {
-        let c_name = CString::new(name).unwrap();
-        let c_desc = CString::new(desc).unwrap();
+        separator: Box<dyn TrySeparator>,
+    ) -> Result<(), ScipError> {
+        let c_name = c_string(name)?;
+        let c_desc = c_string(desc)?;
 
         extern "C" fn sepexeclp(
             scip: *mut ffi::SCIP,
@@ -1126,21 +1521,27 @@ impl ScipPtr {
             _allowlocal: ::std::os::raw::c_uint,
             _depth: ::std::os::raw::c_int,
         ) -> ffi::SCIP_Retcode {
-            let data_ptr = unsafe { ffi::SCIPsepaGetData(separator) };
-            assert!(!data_ptr.is_null());
-            let rule_ptr = data_ptr as *mut Box<dyn Separator>;
-
-            let scip_ptr = ScipPtr::from_raw(scip, true);
-            let model = Model {
-                scip: Rc::new(scip_ptr),
-                state: Solving,
-            };
-            let separator = SCIPSeparator { raw: separator };
-            let sep_res = unsafe { (*rule_ptr).execute_lp(model, separator) };
+            catch_callback_panic(|| {
+                let data_ptr = unsafe { ffi::SCIPsepaGetData(separator) };
+                assert!(!data_ptr.is_null());
+                let rule_ptr = data_ptr as *mut Box<dyn TrySeparator>;
+
+                let scip_ptr = ScipPtr::from_raw(scip, true);
+                let model = Model {
+                    scip: Rc::new(scip_ptr),
+                    state: Solving,
+                };
+                let name = unsafe { ffi::SCIPsepaGetName(separator) };
+                let separator = SCIPSeparator { raw: separator };
+                let sep_res = match unsafe { (*rule_ptr).try_execute_lp(model, separator) } {
+                    Ok(res) => res,
+                    Err(error) => return plugin_error(name, error),
+                };
 
-            unsafe { *result = sep_res.into() };
+                unsafe { *result = sep_res.into() };
 
-            Retcode::Okay.into()
+                Retcode::Okay.into()
+            })
         }
 
         extern "C" fn sepexecsol(
@@ -1158,10 +1559,12 @@ impl ScipPtr {
             _scip: *mut ffi::SCIP,
             separator: *mut ffi::SCIP_SEPA,
         ) -> ffi::SCIP_Retcode {
-            let data_ptr = unsafe { ffi::SCIPsepaGetData(separator) };
-            assert!(!data_ptr.is_null());
-            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn Separator>) });
-            Retcode::Okay.into()
+            catch_callback_panic(|| {
+                let data_ptr = unsafe { ffi::SCIPsepaGetData(separator) };
+                assert!(!data_ptr.is_null());
+                drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn TrySeparator>) });
+                Retcode::Okay.into()
+            })
         }
 
         let ptr = Box::into_raw(Box::new(separator));
@@ -1196,10 +1599,10 @@ impl ScipPtr {
         desc: &str,
         enfopriority: i32,
         checkpriority: i32,
-        conshdlr: Box<dyn Conshdlr>,
-    ) -> Result<(), Retcode> {
-        let c_name = CString::new(name).unwrap();
-        let c_desc = CString::new(desc).unwrap();
+        conshdlr: Box<dyn TryConshdlr>,
+    ) -> Result<(), ScipError> {
+        let c_name = c_string(name)?;
+        let c_desc = c_string(desc)?;
 
         extern "C" fn consenfolp(
             scip: *mut SCIP,
@@ -1210,23 +1613,28 @@ impl ScipPtr {
             _solinfeasible: std::os::raw::c_uint,
             result: *mut SCIP_RESULT,
         ) -> SCIP_RETCODE {
-            let data_ptr = unsafe { ffi::SCIPconshdlrGetData(conshdlr) };
-            assert!(!data_ptr.is_null());
-            let conshdlr_ptr = data_ptr as *mut Box<dyn Conshdlr>;
-
-            let scip_ptr = Rc::new(ScipPtr::from_raw(scip, true));
-            let model = Model {
-                scip: scip_ptr.clone(),
-                state: Solving,
-            };
+            catch_callback_panic(|| {
+                let data_ptr = unsafe { ffi::SCIPconshdlrGetData(conshdlr) };
+                assert!(!data_ptr.is_null());
+                let conshdlr_ptr = data_ptr as *mut Box<dyn TryConshdlr>;
+
+                let scip_ptr = Rc::new(ScipPtr::from_raw(scip, true));
+                let model = Model {
+                    scip: scip_ptr.clone(),
+                    state: Solving,
+                };
 
-            let scip_conshdlr = SCIPConshdlr { raw: conshdlr };
+                let scip_conshdlr = SCIPConshdlr { raw: conshdlr };
 
-            unsafe {
-                *result = (*conshdlr_ptr).enforce(model, scip_conshdlr).into();
-            }
+                match unsafe { (*conshdlr_ptr).try_enforce(model, scip_conshdlr) } {
+                    Ok(res) => unsafe { *result = res.into() },
+                    Err(error) => {
+                        return plugin_error(unsafe { ffi::SCIPconshdlrGetName(conshdlr) }, error);
+                    }
+                }
 
-            Retcode::Okay.into()
+                Retcode::Okay.into()
+            })
         }
 
         extern "C" fn conscheck(
@@ -1241,36 +1649,45 @@ impl ScipPtr {
             _completely: ::std::os::raw::c_uint,
             result: *mut SCIP_RESULT,
         ) -> SCIP_RETCODE {
-            let data_ptr = unsafe { ffi::SCIPconshdlrGetData(conshdlr) };
-            assert!(!data_ptr.is_null());
-            let conshdlr_ptr = data_ptr as *mut Box<dyn Conshdlr>;
-
-            let scip_ptr = Rc::new(ScipPtr::from_raw(scip, true));
-            let model = Model {
-                scip: scip_ptr.clone(),
-                state: Solving,
-            };
-
-            let scip_conshdlr = SCIPConshdlr { raw: conshdlr };
+            catch_callback_panic(|| {
+                let data_ptr = unsafe { ffi::SCIPconshdlrGetData(conshdlr) };
+                assert!(!data_ptr.is_null());
+                let conshdlr_ptr = data_ptr as *mut Box<dyn TryConshdlr>;
+
+                let scip_ptr = Rc::new(ScipPtr::from_raw(scip, true));
+                let model = Model {
+                    scip: scip_ptr.clone(),
+                    state: Solving,
+                };
 
-            assert!(!sol.is_null());
+                let scip_conshdlr = SCIPConshdlr { raw: conshdlr };
 
-            let solution = Solution {
-                raw: sol,
-                scip_ptr: scip_ptr.clone(),
-            };
+                assert!(!sol.is_null());
 
-            let feasible = unsafe { (*conshdlr_ptr).check(model, scip_conshdlr, &solution) };
+                let solution = Solution {
+                    raw: sol,
+                    scip_ptr: scip_ptr.clone(),
+                };
 
-            unsafe {
-                *result = if feasible {
-                    ffi::SCIP_Result_SCIP_FEASIBLE
-                } else {
-                    ffi::SCIP_Result_SCIP_INFEASIBLE
+                let feasible = match unsafe {
+                    (*conshdlr_ptr).try_check(model, scip_conshdlr, &solution)
+                } {
+                    Ok(feasible) => feasible,
+                    Err(error) => {
+                        return plugin_error(unsafe { ffi::SCIPconshdlrGetName(conshdlr) }, error);
+                    }
                 };
-            }
 
-            Retcode::Okay.into()
+                unsafe {
+                    *result = if feasible {
+                        ffi::SCIP_Result_SCIP_FEASIBLE
+                    } else {
+                        ffi::SCIP_Result_SCIP_INFEASIBLE
+                    };
+                }
+
+                Retcode::Okay.into()
+            })
         }
 
         extern "C" fn conslock(
@@ -1329,10 +1746,12 @@ impl ScipPtr {
             _scip: *mut ffi::SCIP,
             conshdlr: *mut ffi::SCIP_CONSHDLR,
         ) -> ffi::SCIP_Retcode {
-            let data_ptr = unsafe { ffi::SCIPconshdlrGetData(conshdlr) };
-            assert!(!data_ptr.is_null());
-            drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn Conshdlr>) });
-            Retcode::Okay.into()
+            catch_callback_panic(|| {
+                let data_ptr = unsafe { ffi::SCIPconshdlrGetData(conshdlr) };
+                assert!(!data_ptr.is_null());
+                drop(unsafe { Box::from_raw(data_ptr as *mut Box<dyn TryConshdlr>) });
+                Retcode::Okay.into()
+            })
         }
 
         let ptr = Box::into_raw(Box::new(conshdlr));
@@ -1365,11 +1784,16 @@ impl ScipPtr {
         cons: &Constraint,
         var: &Variable,
         coef: f64,
-    ) -> Result<(), Retcode> {
+    ) -> Result<(), ScipError> {
         let cons_is_transformed = unsafe { ffi::SCIPconsIsTransformed(cons.raw) } == 1;
         let var_is_transformed = unsafe { ffi::SCIPvarIsTransformed(var.raw) } == 1;
         let cons_ptr = if !cons_is_transformed && var_is_transformed {
-            self.get_transformed_cons(cons)?.expect("No transformed constraint was found for the passed original constraint, to prevent this you could disable presolving or mark the constraint to be not removable")
+            // the constraint was removed in presolving, to prevent this disable presolving or mark the constraint
+            // to be not removable
+            self.get_transformed_cons(cons)?.ok_or(ScipError::new(
+                Retcode::InvalidData,
+                "SCIPgetTransformedCons",
+            ))?
         } else {
             cons.raw
         };
@@ -1395,7 +1819,7 @@ impl ScipPtr {
         &self,
         cons: &Constraint,
         modifiable: bool,
-    ) -> Result<(), Retcode> {
+    ) -> Result<(), ScipError> {
         scip_call!(ffi::SCIPsetConsModifiable(
             self.raw,
             cons.raw,
@@ -1412,7 +1836,7 @@ impl ScipPtr {
         &self,
         cons: &Constraint,
         removable: bool,
-    ) -> Result<(), Retcode> {
+    ) -> Result<(), ScipError> {
         scip_call!(ffi::SCIPsetConsRemovable(
             self.raw,
             cons.raw,
@@ -1429,7 +1853,7 @@ impl ScipPtr {
         &self,
         cons: &Constraint,
         separate: bool,
-    ) -> Result<(), Retcode> {
+    ) -> Result<(), ScipError> {
         scip_call!(ffi::SCIPsetConsSeparated(
             self.raw,
             cons.raw,
@@ -1459,7 +1883,7 @@ impl ScipPtr {
         if ptr.is_null() { None } else { Some(ptr) }
     }
 
-    pub(crate) fn create_child(&self) -> Result<*mut SCIP_NODE, Retcode> {
+    pub(crate) fn create_child(&self) -> Result<*mut SCIP_NODE, ScipError> {
         let mut node_ptr = MaybeUninit::uninit();
         scip_call!(ffi::SCIPcreateChild(
             self.raw,
@@ -1472,42 +1896,46 @@ impl ScipPtr {
         Ok(node_ptr)
     }
 
-    pub(crate) fn add_sol(&self, mut sol: Solution) -> Result<bool, Retcode> {
-        let mut feasible = 0;
+    pub(crate) fn add_sol(&self, mut sol: Solution) -> Result<bool, ScipError> {
         assert!(!sol.raw.is_null());
-        let is_orig = unsafe { ffi::SCIPsolIsOriginal(sol.raw) } == 1;
-        if is_orig {
-            scip_call!(ffi::SCIPcheckSolOrig(
-                self.raw,
-                sol.raw,
-                &mut feasible,
-                false.into(),
-                true.into(),
-            ));
-            if feasible == 1 {
-                scip_call!(ffi::SCIPaddSolFree(self.raw, &mut sol.raw, &mut feasible));
+        // checking the solution runs the checks of the constraint handlers, which may panic
+        resume_callback_panics(|| {
+            let mut feasible = 0;
+            let is_orig = unsafe { ffi::SCIPsolIsOriginal(sol.raw) } == 1;
+            if is_orig {
+                scip_call!(ffi::SCIPcheckSolOrig(
+                    self.raw,
+                    sol.raw,
+                    &mut feasible,
+                    false.into(),
+                    true.into(),
+                ));
+                if feasible == 1 {
+                    scip_call!(ffi::SCIPaddSolFree(self.raw, &mut sol.raw, &mut feasible));
+                }
+            } else {
+                scip_call!(ffi::SCIPtrySol(
+                    self.raw,
+                    sol.raw,
+                    false.into(),
+                    true.into(),
+                    true.into(),
+                    true.into(),
+                    true.into(),
+                    &mut feasible,
+                ));
             }
-            return Ok(feasible != 0);
-        } else {
-            scip_call!(ffi::SCIPtrySol(
-                self.raw,
-                sol.raw,
-                false.into(),
-                true.into(),
-                true.into(),
-                true.into(),
-                true.into(),
-                &mut feasible,
-            ));
-        }
-
-        Ok(feasible != 0)
+            Ok(feasible != 0)
+        })
     }
 
-    pub(crate) fn create_empty_row(&self, row: &RowBuilder) -> Result<*mut ffi::SCIP_ROW, Retcode> {
+    pub(crate) fn create_empty_row(
+        &self,
+        row: &RowBuilder,
+    ) -> Result<*mut ffi::SCIP_ROW, ScipError> {
         let mut row_ptr = MaybeUninit::uninit();
 
-        let row_name = CString::new(row.name.unwrap_or("r")).unwrap();
+        let row_name = c_string(row.name.unwrap_or("r"))?;
         let modifiable = row.modifiable.unwrap_or(false);
         let removable = row.removable.unwrap_or(true);
         let local = row.local.unwrap_or(true);
@@ -1570,8 +1998,279 @@ impl ScipPtr {
         Ok(unsafe { row_ptr.assume_init() })
     }
 
-    pub(crate) fn free_transform(&self) -> Result<(), Retcode> {
-        scip_call!(ffi::SCIPfreeTransform(self.raw));
+    pub(crate) fn free_transform(&self) -> Result<(), ScipError> {
+        // freeing the transformed problem runs the exit callbacks of the plugins
+        resume_callback_panics(|| {
+            scip_call!(ffi::SCIPfreeTransform(self.raw));
+            Ok(())
+        })
+    }
+
+    /// Maps SCIP's infinity values to the floating point infinities.
+    pub(crate) fn to_f64_inf(&self, val: f64) -> f64 {
+        if unsafe { ffi::SCIPisInfinity(self.raw, val) } != 0 {
+            f64::INFINITY
+        } else if unsafe { ffi::SCIPisInfinity(self.raw, -val) } != 0 {
+            f64::NEG_INFINITY
+        } else {
+            val
+        }
+    }
+
+    /// Returns the linear representation of the given constraint, or `None` if its
+    /// constraint handler is not linear or linear-representable.
+    pub(crate) fn cons_linear_row(&self, cons: *mut SCIP_Cons) -> Option<LinearRow> {
+        let conshdlr = unsafe { ffi::SCIPconsGetHdlr(cons) };
+        let conshdlr_name = unsafe { CStr::from_ptr(ffi::SCIPconshdlrGetName(conshdlr)) };
+        let scip = self.raw;
+        let collect = |vars: *mut *mut SCIP_Var, n_vars: c_int| -> Vec<*mut SCIP_Var> {
+            (0..n_vars as usize)
+                .map(|i| unsafe { *vars.add(i) })
+                .collect()
+        };
+
+        let row = match conshdlr_name.to_str().unwrap() {
+            "linear" => unsafe {
+                let n_vars = ffi::SCIPgetNVarsLinear(scip, cons);
+                let vals = ffi::SCIPgetValsLinear(scip, cons);
+                LinearRow {
+                    vars: collect(ffi::SCIPgetVarsLinear(scip, cons), n_vars),
+                    vals: (0..n_vars as usize).map(|i| *vals.add(i)).collect(),
+                    lhs: ffi::SCIPgetLhsLinear(scip, cons),
+                    rhs: ffi::SCIPgetRhsLinear(scip, cons),
+                }
+            },
+            "setppc" => unsafe {
+                let n_vars = ffi::SCIPgetNVarsSetppc(scip, cons);
+                let (lhs, rhs) = match ffi::SCIPgetTypeSetppc(scip, cons) {
+                    ffi::SCIP_SetppcType_SCIP_SETPPCTYPE_PARTITIONING => (1.0, 1.0),
+                    ffi::SCIP_SetppcType_SCIP_SETPPCTYPE_PACKING => (f64::NEG_INFINITY, 1.0),
+                    _ => (1.0, f64::INFINITY),
+                };
+                LinearRow {
+                    vars: collect(ffi::SCIPgetVarsSetppc(scip, cons), n_vars),
+                    vals: vec![1.0; n_vars as usize],
+                    lhs,
+                    rhs,
+                }
+            },
+            "logicor" => unsafe {
+                let n_vars = ffi::SCIPgetNVarsLogicor(scip, cons);
+                LinearRow {
+                    vars: collect(ffi::SCIPgetVarsLogicor(scip, cons), n_vars),
+                    vals: vec![1.0; n_vars as usize],
+                    lhs: 1.0,
+                    rhs: f64::INFINITY,
+                }
+            },
+            "knapsack" => unsafe {
+                let n_vars = ffi::SCIPgetNVarsKnapsack(scip, cons);
+                let weights = ffi::SCIPgetWeightsKnapsack(scip, cons);
+                LinearRow {
+                    vars: collect(ffi::SCIPgetVarsKnapsack(scip, cons), n_vars),
+                    vals: (0..n_vars as usize)
+                        .map(|i| *weights.add(i) as f64)
+                        .collect(),
+                    lhs: f64::NEG_INFINITY,
+                    rhs: ffi::SCIPgetCapacityKnapsack(scip, cons) as f64,
+                }
+            },
+            "varbound" => unsafe {
+                LinearRow {
+                    vars: vec![
+                        ffi::SCIPgetVarVarbound(scip, cons),
+                        ffi::SCIPgetVbdvarVarbound(scip, cons),
+                    ],
+                    vals: vec![1.0, ffi::SCIPgetVbdcoefVarbound(scip, cons)],
+                    lhs: ffi::SCIPgetLhsVarbound(scip, cons),
+                    rhs: ffi::SCIPgetRhsVarbound(scip, cons),
+                }
+            },
+            _ => return None,
+        };
+
+        Some(LinearRow {
+            lhs: self.to_f64_inf(row.lhs),
+            rhs: self.to_f64_inf(row.rhs),
+            ..row
+        })
+    }
+
+    /// Builds the matrix representation of the current problem, returning the constraints
+    /// that cannot be represented linearly and those on multi-aggregated variables as error.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn to_matrices(
+        &self,
+    ) -> Result<
+        (LinearBlock, Vec<*mut SCIP_Var>, Vec<*mut SCIP_Cons>),
+        (Vec<*mut SCIP_Cons>, Vec<*mut SCIP_Cons>),
+    > {
+        let n_vars = self.n_vars();
+        let scip_vars = unsafe { ffi::SCIPgetVars(self.raw) };
+        let vars: Vec<*mut SCIP_Var> = (0..n_vars).map(|i| unsafe { *scip_vars.add(i) }).collect();
+        let col_of: HashMap<*mut SCIP_Var, usize> =
+            vars.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+
+        let conss = self.conss(false);
+        let mut non_linear = Vec::new();
+        let mut multi_aggregated = Vec::new();
+        let mut indptr = vec![0];
+        let mut indices = Vec::new();
+        let mut values = Vec::new();
+        let mut lhs = Vec::with_capacity(conss.len());
+        let mut rhs = Vec::with_capacity(conss.len());
+        'conss: for &cons in &conss {
+            let Some(row) = self.cons_linear_row(cons) else {
+                non_linear.push(cons);
+                continue;
+            };
+
+            // resolve negated and aggregated variables to the columns of the problem
+            let mut constant = 0.0;
+            let mut entries = BTreeMap::new();
+            for (&var, &val) in row.vars.iter().zip(row.vals.iter()) {
+                let mut var = var;
+                let mut scalar = val;
+                let mut var_constant = 0.0;
+                let retcode = Retcode::from(unsafe {
+                    ffi::SCIPgetProbvarSum(self.raw, &mut var, &mut scalar, &mut var_constant)
+                });
+                constant += var_constant;
+                if retcode != Retcode::Okay {
+                    non_linear.push(cons);
+                    continue 'conss;
+                }
+                if scalar == 0.0 {
+                    continue;
+                }
+                match col_of.get(&var) {
+                    Some(&col) => *entries.entry(col).or_insert(0.0) += scalar,
+                    None if unsafe { ffi::SCIPvarGetStatus(var) }
+                        == ffi::SCIP_Varstatus_SCIP_VARSTATUS_MULTAGGR =>
+                    {
+                        multi_aggregated.push(cons);
+                        continue 'conss;
+                    }
+                    None => {
+                        non_linear.push(cons);
+                        continue 'conss;
+                    }
+                }
+            }
+
+            // duplicate variables may cancel out
+            for (col, val) in entries.into_iter().filter(|&(_, val)| val != 0.0) {
+                indices.push(col);
+                values.push(val);
+            }
+            indptr.push(indices.len());
+            lhs.push(row.lhs - constant);
+            rhs.push(row.rhs - constant);
+        }
+
+        if !non_linear.is_empty() || !multi_aggregated.is_empty() {
+            return Err((non_linear, multi_aggregated));
+        }
+
+        let mut block = LinearBlock::new(
+            vars.iter()
+                .map(|&v| unsafe { ffi::SCIPvarGetObj(v) })
+                .collect(),
+            SparseMatrix::csr(conss.len(), n_vars, indptr, indices, values),
+        );
+        block.lhs = lhs;
+        block.rhs = rhs;
+        block.lb = vars
+            .iter()
+            .map(|&v| self.to_f64_inf(unsafe { ffi::SCIPvarGetLbGlobal(v) }))
+            .collect();
+        block.ub = vars
+            .iter()
+            .map(|&v| self.to_f64_inf(unsafe { ffi::SCIPvarGetUbGlobal(v) }))
+            .collect();
+        block.var_types = vars
+            .iter()
+            .map(|&v| unsafe { ffi::SCIPvarGetType(v) }.into())
+            .collect();
+
+        Ok((block, vars, conss))
+    }
+
+    pub(crate) fn lp_cols(&self) -> Vec<*mut ffi::SCIP_COL> {
+        let cols = unsafe { ffi::SCIPgetLPCols(self.raw) };
+        let n_cols = unsafe { ffi::SCIPgetNLPCols(self.raw) } as usize;
+        (0..n_cols).map(|i| unsafe { *cols.add(i) }).collect()
+    }
+
+    pub(crate) fn lp_rows(&self) -> Vec<*mut ffi::SCIP_ROW> {
+        let rows = unsafe { ffi::SCIPgetLPRows(self.raw) };
+        let n_rows = unsafe { ffi::SCIPgetNLPRows(self.raw) } as usize;
+        (0..n_rows).map(|i| unsafe { *rows.add(i) }).collect()
+    }
+
+    pub(crate) fn lp_basis(&self) -> Option<(Vec<BasisStatus>, Vec<BasisStatus>)> {
+        if self.lp_status() != LPStatus::Optimal || unsafe { ffi::SCIPisLPSolBasic(self.raw) } == 0
+        {
+            return None;
+        }
+        let cols = self
+            .lp_cols()
+            .into_iter()
+            .map(|col| unsafe { ffi::SCIPcolGetBasisStatus(col) }.into())
+            .collect();
+        let rows = self
+            .lp_rows()
+            .into_iter()
+            .map(|row| unsafe { ffi::SCIProwGetBasisStatus(row) }.into())
+            .collect();
+        Some((cols, rows))
+    }
+
+    /// Sets the LP state of the current probing node to the given basis, which SCIP loads into the LP solver
+    /// and marks the LP as unsolved before the next probing LP solve.
+    pub(crate) fn set_probing_lp_basis(
+        &self,
+        cols: &[BasisStatus],
+        rows: &[BasisStatus],
+    ) -> Result<(), ScipError> {
+        if unsafe { ffi::SCIPinProbing(self.raw) } == 0 {
+            return Err(ScipError::new(
+                Retcode::InvalidCall,
+                "SCIPsetProbingLPState",
+            ));
+        }
+        // the LP solver needs to contain the current LP for the basis to match its columns and rows
+        scip_call!(ffi::SCIPflushLP(self.raw));
+        let mut lpi = std::ptr::null_mut();
+        scip_call!(ffi::SCIPgetLPI(self.raw, &mut lpi));
+        let (mut n_cols, mut n_rows) = (0, 0);
+        scip_call!(ffi::SCIPlpiGetNCols(lpi, &mut n_cols));
+        scip_call!(ffi::SCIPlpiGetNRows(lpi, &mut n_rows));
+        if cols.len() != n_cols as usize || rows.len() != n_rows as usize {
+            return Err(ScipError::new(Retcode::InvalidData, "SCIPlpiSetBase"));
+        }
+
+        // the LP solver is only used to encode the basis as an LP state, its previous state is put back so that
+        // it keeps matching the LP solution SCIP holds until the probing LP is solved
+        let blkmem = unsafe { ffi::SCIPblkmem(self.raw) };
+        let prev_state = LpiState::get(lpi, blkmem)?;
+        let (cstat, rstat) = (basis_stats(cols), basis_stats(rows));
+        let encoded = (|| -> Result<LpiState, ScipError> {
+            scip_call!(ffi::SCIPlpiSetBase(lpi, cstat.as_ptr(), rstat.as_ptr()));
+            LpiState::get(lpi, blkmem)
+        })();
+        scip_call!(ffi::SCIPlpiSetState(lpi, blkmem, prev_state.raw));
+        let mut state = encoded?;
+
+        // SCIP takes ownership of the state and resets the pointer
+        let mut norms = std::ptr::null_mut();
+        scip_call!(ffi::SCIPsetProbingLPState(
+            self.raw,
+            &mut state.raw,
+            &mut norms,
+            false.into(),
+            false.into(),
+        ));
         Ok(())
     }
 
@@ -1580,7 +2279,7 @@ impl ScipPtr {
         status.into()
     }
 
-    pub(crate) fn add_row(&self, row: Row, force_cut: bool) -> Result<bool, Retcode> {
+    pub(crate) fn add_row(&self, row: Row, force_cut: bool) -> Result<bool, ScipError> {
         let mut infeasible = 0;
         scip_call!(ffi::SCIPaddRow(
             self.raw,
@@ -1591,19 +2290,134 @@ impl ScipPtr {
         Ok(infeasible != 0)
     }
 
+    /// Includes the event handler recording the progress of the solve, or replaces its callback if it is
+    /// already included.
+    pub(crate) fn track_progress(
+        &self,
+        callback: Option<ProgressCallback>,
+    ) -> Result<(), ScipError> {
+        if let Some(tracker) = self.progress_tracker() {
+            tracker.callback = callback;
+            return Ok(());
+        }
+
+        unsafe extern "C" fn eventhdlrfree(
+            _scip: *mut ffi::SCIP,
+            eventhdlr: *mut ffi::SCIP_EVENTHDLR,
+        ) -> ffi::SCIP_Retcode {
+            catch_callback_panic(|| {
+                let data_ptr = unsafe { ffi::SCIPeventhdlrGetData(eventhdlr) };
+                assert!(!data_ptr.is_null());
+                drop(unsafe { Box::from_raw(data_ptr as *mut ProgressTracker) });
+                Retcode::Okay.into()
+            })
+        }
+
+        extern "C" fn eventhdlrinit(
+            scip: *mut ffi::SCIP,
+            eventhdlr: *mut ffi::SCIP_EVENTHDLR,
+        ) -> ffi::SCIP_Retcode {
+            catch_callback_panic(|| {
+                let data_ptr = unsafe { ffi::SCIPeventhdlrGetData(eventhdlr) };
+                assert!(!data_ptr.is_null());
+                let tracker = unsafe { &mut *(data_ptr as *mut ProgressTracker) };
+                tracker.rows.clear();
+                let name = CString::new("display/freq").unwrap();
+                scip_call_panic!(ffi::SCIPgetLongintParam(
+                    scip,
+                    name.as_ptr(),
+                    &mut tracker.freq
+                ));
+                let mask = EventMask::NODE_SOLVED | EventMask::BEST_SOL_FOUND;
+                unsafe {
+                    ffi::SCIPcatchEvent(
+                        scip,
+                        mask.into(),
+                        eventhdlr,
+                        std::ptr::null_mut(),
+                        std::ptr::null_mut(),
+                    )
+                }
+            })
+        }
+
+        extern "C" fn eventhdlrexec(
+            scip: *mut ffi::SCIP,
+            eventhdlr: *mut ffi::SCIP_EVENTHDLR,
+            event: *mut ffi::SCIP_EVENT,
+            _event_data: *mut ffi::SCIP_EVENTDATA,
+        ) -> ffi::SCIP_Retcode {
+            catch_callback_panic(|| {
+                let data_ptr = unsafe { ffi::SCIPeventhdlrGetData(eventhdlr) };
+                assert!(!data_ptr.is_null());
+                let tracker = unsafe { &mut *(data_ptr as *mut ProgressTracker) };
+                let event_type = unsafe { ffi::SCIPeventGetType(event) };
+                let new_incumbent = event_type & u64::from(EventMask::BEST_SOL_FOUND) != 0;
+                let row = ProgressRow::current(scip, new_incumbent);
+                if new_incumbent || tracker.is_due(row.nodes) {
+                    tracker.record(row);
+                }
+                Retcode::Okay.into()
+            })
+        }
+
+        let c_name = CString::new("russcip_progress").unwrap();
+        let c_desc = CString::new("records the progress table of the solve").unwrap();
+        let tracker = Box::new(ProgressTracker {
+            rows: Vec::new(),
+            callback,
+            freq: 0,
+        });
+        let eventhdlr_ptr = Box::into_raw(tracker);
+
+        scip_call! {
+            ffi::SCIPincludeEventhdlr(
+                self.raw,
+                c_name.as_ptr(),
+                c_desc.as_ptr(),
+                None,
+                Some(eventhdlrfree),
+                Some(eventhdlrinit),
+                None,
+                None,
+                None,
+                None,
+                Some(eventhdlrexec),
+                eventhdlr_ptr as *mut ffi::SCIP_EVENTHDLRDATA,
+            )
+        }
+
+        Ok(())
+    }
+
+    /// Returns the data of the progress event handler, if it is included.
+    #[allow(clippy::mut_from_ref)]
+    pub(crate) fn progress_tracker(&self) -> Option<&mut ProgressTracker> {
+        let name = CString::new("russcip_progress").unwrap();
+        let eventhdlr = unsafe { ffi::SCIPfindEventhdlr(self.raw, name.as_ptr()) };
+        if eventhdlr.is_null() {
+            return None;
+        }
+        let data_ptr = unsafe { ffi::SCIPeventhdlrGetData(eventhdlr) };
+        assert!(!data_ptr.is_null());
+        Some(unsafe { &mut *(data_ptr as *mut ProgressTracker) })
+    }
+
     #[cfg(feature = "datastore")]
     // Initializes an anymap as a generic datastore, and keeps a reference to it on an
     // unused plugin (eventhdlr)
-    fn init_datastore(&self) -> Result<(), Retcode> {
+    fn init_datastore(&self) -> Result<(), ScipError> {
         unsafe extern "C" fn eventhdlrfree(
             _scip: *mut ffi::SCIP,
             eventhdlr: *mut ffi::SCIP_EVENTHDLR,
         ) -> ffi::SCIP_Retcode {
-            let data_ptr = unsafe { ffi::SCIPeventhdlrGetData(eventhdlr) };
-            assert!(!data_ptr.is_null());
-            let eventhdlr_ptr = data_ptr as *mut AnyMap;
-            drop(unsafe { Box::from_raw(eventhdlr_ptr) });
-            Retcode::Okay.into()
+            catch_callback_panic(|| {
+                let data_ptr = unsafe { ffi::SCIPeventhdlrGetData(eventhdlr) };
+                assert!(!data_ptr.is_null());
+                let eventhdlr_ptr = data_ptr as *mut AnyMap;
+                drop(unsafe { Box::from_raw(eventhdlr_ptr) });
+                Retcode::Okay.into()
+            })
         }
 
         extern "C" fn eventhdlrexec(
@@ -1649,7 +2463,7 @@ impl ScipPtr {
     }
 
     #[cfg(feature = "datastore")]
-    pub(crate) fn get_store<T: 'static>(&self) -> Result<Option<&T>, Retcode> {
+    pub(crate) fn get_store<T: 'static>(&self) -> Result<Option<&T>, ScipError> {
         let name = CString::new("russcip_datastore").unwrap();
         let mut eventhdlr = unsafe { ffi::SCIPfindEventhdlr(self.raw, name.as_ptr()) };
         if eventhdlr.is_null() {
@@ -1667,7 +2481,7 @@ impl ScipPtr {
 
     #[allow(clippy::mut_from_ref)]
     #[cfg(feature = "datastore")]
-    pub(crate) fn get_mut_store<T: 'static>(&self) -> Result<Option<&mut T>, Retcode> {
+    pub(crate) fn get_mut_store<T: 'static>(&self) -> Result<Option<&mut T>, ScipError> {
         let name = CString::new("russcip_datastore").unwrap();
         let mut eventhdlr = unsafe { ffi::SCIPfindEventhdlr(self.raw, name.as_ptr()) };
         if eventhdlr.is_null() {
@@ -1684,7 +2498,7 @@ impl ScipPtr {
     }
 
     #[cfg(feature = "datastore")]
-    pub(crate) fn set_store<T: 'static>(&self, thing: T) -> Result<(), Retcode> {
+    pub(crate) fn set_store<T: 'static>(&self, thing: T) -> Result<(), ScipError> {
         let name = CString::new("russcip_datastore").unwrap();
         let mut eventhdlr = unsafe { ffi::SCIPfindEventhdlr(self.raw, name.as_ptr()) };
         if eventhdlr.is_null() {
@@ -1705,18 +2519,18 @@ impl ScipPtr {
         vars: Vec<&Variable>,
         weights: Option<&[f64]>,
         name: &str,
-    ) -> Result<*mut SCIP_Cons, Retcode> {
+    ) -> Result<*mut SCIP_Cons, ScipError> {
         if vars.is_empty() {
-            return Err(Retcode::ParameterWrongVal);
+            return Err(Retcode::ParameterWrongVal.into());
         }
 
         if let Some(ws) = weights {
             if vars.len() != ws.len() {
-                return Err(Retcode::ParameterWrongVal);
+                return Err(Retcode::ParameterWrongVal.into());
             }
         }
 
-        let c_name = CString::new(name).unwrap();
+        let c_name = c_string(name)?;
         let mut scip_cons = MaybeUninit::uninit();
 
         let mut var_ptrs = vars.iter().map(|v| v.raw).collect::<Vec<_>>();
@@ -1787,8 +2601,12 @@ impl Drop for ScipPtr {
             }
         }
 
-        // free SCIP instance
-        unsafe { ffi::SCIPfree(&mut self.raw) };
+        // free SCIP instance, resuming the panics of the free callbacks unless the model is dropped while unwinding
+        if std::thread::panicking() {
+            unsafe { ffi::SCIPfree(&mut self.raw) };
+        } else {
+            resume_callback_panics(|| unsafe { ffi::SCIPfree(&mut self.raw) });
+        }
     }
 }
 
@@ -1799,7 +2617,7 @@ mod tests {
     fn test_datastore() {
         use crate::scip::ScipPtr;
 
-        let scip = ScipPtr::new();
+        let scip = ScipPtr::new().unwrap();
         assert!(!scip.raw.is_null());
 
         // Test with a simple integer
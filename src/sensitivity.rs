@@ -0,0 +1,380 @@
+use crate::constraint::Constraint;
+use crate::ffi;
+use crate::lpi::{LpSol, Lpi};
+use crate::retcode::{Retcode, ScipError};
+use crate::row::BasisStatus;
+use crate::scip::ScipPtr;
+use crate::variable::Variable;
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
+
+/// Pivot elements below this magnitude are treated as zero in the ratio tests.
+const PIVOT_TOL: f64 = 1e-9;
+/// The relative tolerance for deciding whether a value lies at one of its bounds.
+const BOUND_TOL: f64 = 1e-6;
+
+/// The sensitivity of the optimal LP solution to the objective coefficient of a variable.
+#[derive(Debug, Clone)]
+pub struct VarSensitivity {
+    /// The original variable.
+    pub var: Variable,
+    /// The value of the variable in the optimal LP solution.
+    pub value: f64,
+    /// The reduced cost of the variable, i.e. the change of the optimal objective value per unit increase of the
+    /// variable.
+    pub reduced_cost: f64,
+    /// Whether the variable is basic in the optimal basis.
+    pub basic: bool,
+    /// Whether the variable is an integer variable that was fixed to its value in the MIP solution.
+    pub fixed: bool,
+    /// The range in which the objective coefficient can vary without changing the optimal basis.
+    pub obj_range: (f64, f64),
+}
+
+/// The sensitivity of the optimal LP solution to the sides of a constraint.
+#[derive(Debug, Clone)]
+pub struct ConsSensitivity {
+    /// The original constraint.
+    pub cons: Constraint,
+    /// The activity of the constraint in the optimal LP solution.
+    pub activity: f64,
+    /// The dual value of the constraint, i.e. the change of the optimal objective value per unit increase of its
+    /// active side.
+    pub dual: f64,
+    /// Whether the constraint is active at one of its sides in the optimal basis.
+    pub binding: bool,
+    /// The range in which the left-hand side can vary without changing the optimal basis, `None` if the
+    /// left-hand side is infinite.
+    pub lhs_range: Option<(f64, f64)>,
+    /// The range in which the right-hand side can vary without changing the optimal basis, `None` if the
+    /// right-hand side is infinite.
+    pub rhs_range: Option<(f64, f64)>,
+}
+
+/// The sensitivity report of the optimal LP solution, returned by `Model::sensitivity`.
+///
+/// For problems with integer variables, the report is computed for the LP obtained by fixing all integer
+/// variables at their values in the best solution. All values refer to the original objective sense.
+#[derive(Debug, Clone)]
+pub struct SensitivityReport {
+    /// The optimal objective value of the LP, including the objective offset.
+    pub obj_val: f64,
+    /// The sensitivity of each original variable.
+    pub vars: Vec<VarSensitivity>,
+    /// The sensitivity of each original constraint.
+    pub conss: Vec<ConsSensitivity>,
+}
+
+impl SensitivityReport {
+    /// Returns the sensitivity of the given variable.
+    pub fn var(&self, var: &Variable) -> Option<&VarSensitivity> {
+        self.vars.iter().find(|v| v.var.raw == var.raw)
+    }
+
+    /// Returns the sensitivity of the given constraint.
+    pub fn cons(&self, cons: &Constraint) -> Option<&ConsSensitivity> {
+        self.conss.iter().find(|c| c.cons.raw == cons.raw)
+    }
+}
+
+/// The sign a reduced cost or dual value has to keep for the basis to stay optimal.
+#[derive(Clone, Copy)]
+enum Sign {
+    NonNegative,
+    NonPositive,
+    Zero,
+    Free,
+}
+
+impl Sign {
+    /// The sign of a nonbasic value at the lower end, at the upper end or strictly inside of its range.
+    fn of(value: f64, lower: f64, upper: f64) -> Self {
+        if lower == upper {
+            Sign::Free
+        } else if is_at(value, lower) {
+            Sign::NonNegative
+        } else if is_at(value, upper) {
+            Sign::NonPositive
+        } else {
+            Sign::Zero
+        }
+    }
+}
+
+fn is_at(value: f64, bound: f64) -> bool {
+    bound.is_finite() && (value - bound).abs() <= BOUND_TOL * (1. + bound.abs())
+}
+
+/// Restricts the steps `delta` to those for which `value + delta * coef` keeps the given sign.
+fn keep_sign(range: &mut (f64, f64), value: f64, coef: f64, sign: Sign) {
+    if coef.abs() <= PIVOT_TOL {
+        return;
+    }
+    let step = -value / coef;
+    match sign {
+        Sign::Free => {}
+        Sign::Zero => *range = (range.0.max(0.), range.1.min(0.)),
+        Sign::NonNegative if coef > 0. => range.0 = range.0.max(step),
+        Sign::NonNegative => range.1 = range.1.min(step),
+        Sign::NonPositive if coef > 0. => range.1 = range.1.min(step),
+        Sign::NonPositive => range.0 = range.0.max(step),
+    }
+}
+
+/// Restricts the steps `delta` to those for which `value + delta * coef` stays within `[lower, upper]`.
+fn keep_bounds(range: &mut (f64, f64), value: f64, lower: f64, upper: f64, coef: f64) {
+    if coef.abs() <= PIVOT_TOL {
+        return;
+    }
+    let (to_lower, to_upper) = ((lower - value) / coef, (upper - value) / coef);
+    range.0 = range.0.max(to_lower.min(to_upper));
+    range.1 = range.1.min(to_lower.max(to_upper));
+}
+
+/// Moves the steps by `base`, keeping a step of zero in the range despite numerical noise.
+fn shift(range: (f64, f64), base: f64) -> (f64, f64) {
+    (base + range.0.min(0.), base + range.1.max(0.))
+}
+
+/// Builds the LP of the original problem, with the integer variables fixed at their values in the best
+/// solution, solves it with the LP solver and computes the objective and right-hand side ranging of the optimal
+/// basis.
+pub(crate) fn analyze(scip: &Rc<ScipPtr>) -> Result<SensitivityReport, ScipError> {
+    // the LP is a minimization problem
+    let sense = unsafe { ffi::SCIPgetObjsense(scip.raw) } as f64;
+    let vars: Vec<_> = scip.vars(true, false).into_values().collect();
+    let conss = scip.orig_conss();
+    let best_sol = scip.best_sol();
+
+    let mut lpi = Lpi::new("sensitivity")?;
+    let mut cols = HashMap::with_capacity(vars.len());
+    let (mut objs, mut lbs, mut ubs, mut fixed) = (Vec::new(), Vec::new(), Vec::new(), Vec::new());
+    for &var in &vars {
+        let mut lb = scip.to_f64_inf(unsafe { ffi::SCIPvarGetLbOriginal(var) });
+        let mut ub = scip.to_f64_inf(unsafe { ffi::SCIPvarGetUbOriginal(var) });
+        let var_type = unsafe { ffi::SCIPvarGetType(var) };
+        let is_integer = var_type == ffi::SCIP_Vartype_SCIP_VARTYPE_BINARY
+            || var_type == ffi::SCIP_Vartype_SCIP_VARTYPE_INTEGER;
+        if is_integer {
+            let sol =
+                best_sol.ok_or_else(|| ScipError::new(Retcode::InvalidCall, "SCIPgetBestSol"))?;
+            let val = unsafe { ffi::SCIPgetSolVal(scip.raw, sol, var) }.round();
+            (lb, ub) = (val, val);
+        }
+        let obj = sense * unsafe { ffi::SCIPvarGetObj(var) };
+        cols.insert(var, lpi.add_col(obj, lb, ub, &[])?);
+        objs.push(obj);
+        lbs.push(lb);
+        ubs.push(ub);
+        fixed.push(is_integer);
+    }
+
+    let (mut lhss, mut rhss) = (Vec::new(), Vec::new());
+    for &cons in &conss {
+        let row = scip
+            .cons_linear_row(cons)
+            .ok_or_else(|| ScipError::new(Retcode::InvalidData, "SCIPgetConsVals"))?;
+        let mut entries = BTreeMap::new();
+        for (var, val) in row.vars.iter().zip(row.vals) {
+            let col = cols
+                .get(var)
+                .ok_or_else(|| ScipError::new(Retcode::InvalidData, "SCIPgetConsVars"))?;
+            *entries.entry(*col).or_insert(0.) += val;
+        }
+        let entries: Vec<_> = entries.into_iter().collect();
+        let (lhs, rhs) = (row.lhs, row.rhs);
+        lpi.add_row(lhs, rhs, &entries)?;
+        lhss.push(lhs);
+        rhss.push(rhs);
+    }
+
+    lpi.solve_dual()?;
+    if !lpi.is_optimal() {
+        return Err(ScipError::new(Retcode::LpError, "SCIPlpiSolveDual"));
+    }
+    let LpSol {
+        obj_val,
+        primal,
+        dual,
+        activity,
+        redcost,
+    } = lpi.sol()?;
+    let (cstat, rstat) = lpi.base()?;
+    let bind = lpi.basis_ind()?;
+    let is_basic = |stat: BasisStatus| stat == BasisStatus::Basic;
+    let mut position = vec![None; vars.len()];
+    for (r, &ind) in bind.iter().enumerate() {
+        if ind >= 0 {
+            position[ind as usize] = Some(r);
+        }
+    }
+
+    let mut var_reports = Vec::with_capacity(vars.len());
+    for (j, &var) in vars.iter().enumerate() {
+        let mut range = (f64::NEG_INFINITY, f64::INFINITY);
+        if let Some(r) = position[j].filter(|_| is_basic(cstat[j])) {
+            // changing the cost of a basic variable changes the duals by the row of the inverse basis
+            let binv_row = lpi.binv_row(r)?;
+            let binv_a_row = lpi.binv_a_row(r, Some(&binv_row))?;
+            for k in 0..vars.len() {
+                if k != j && !is_basic(cstat[k]) {
+                    let sign = Sign::of(primal[k], lbs[k], ubs[k]);
+                    keep_sign(&mut range, redcost[k], -binv_a_row[k], sign);
+                }
+            }
+            for i in 0..conss.len() {
+                if !is_basic(rstat[i]) {
+                    let sign = Sign::of(activity[i], lhss[i], rhss[i]);
+                    keep_sign(&mut range, dual[i], binv_row[i], sign);
+                }
+            }
+        } else {
+            let sign = Sign::of(primal[j], lbs[j], ubs[j]);
+            keep_sign(&mut range, redcost[j], 1., sign);
+        }
+        let (lo, hi) = shift(range, objs[j]);
+        var_reports.push(VarSensitivity {
+            var: Variable {
+                raw: var,
+                scip: scip.clone(),
+            },
+            value: primal[j],
+            reduced_cost: sense * redcost[j],
+            basic: is_basic(cstat[j]),
+            fixed: fixed[j],
+            obj_range: if sense < 0. { (-hi, -lo) } else { (lo, hi) },
+        });
+    }
+
+    let mut cons_reports = Vec::with_capacity(conss.len());
+    for (i, &cons) in conss.iter().enumerate() {
+        let (act, lhs, rhs) = (activity[i], lhss[i], rhss[i]);
+        let at_lhs = !is_basic(rstat[i]) && is_at(act, lhs);
+        let at_rhs = !is_basic(rstat[i]) && is_at(act, rhs);
+        // an inactive side can move up to the activity
+        let mut lhs_range = lhs.is_finite().then_some((f64::NEG_INFINITY, act));
+        let mut rhs_range = rhs.is_finite().then_some((act, f64::INFINITY));
+        if at_lhs || at_rhs {
+            // moving the active side by delta moves the basic variables by delta times the column of the
+            // inverse basis, and the activity of basic rows in the opposite direction
+            let binv_col = lpi.binv_col(i)?;
+            let mut range = (f64::NEG_INFINITY, f64::INFINITY);
+            for (r, &ind) in bind.iter().enumerate() {
+                if ind >= 0 {
+                    let k = ind as usize;
+                    keep_bounds(&mut range, primal[k], lbs[k], ubs[k], binv_col[r]);
+                } else {
+                    let k = (-1 - ind) as usize;
+                    keep_bounds(&mut range, activity[k], lhss[k], rhss[k], -binv_col[r]);
+                }
+            }
+            if at_lhs && at_rhs {
+                lhs_range = Some(shift(range, act));
+                rhs_range = lhs_range;
+            } else if at_lhs {
+                range.1 = range.1.min(rhs - act);
+                lhs_range = Some(shift(range, act));
+            } else {
+                range.0 = range.0.max(lhs - act);
+                rhs_range = Some(shift(range, act));
+            }
+        }
+        cons_reports.push(ConsSensitivity {
+            cons: Constraint {
+                raw: cons,
+                scip: scip.clone(),
+            },
+            activity: act,
+            dual: sense * dual[i],
+            binding: at_lhs || at_rhs,
+            lhs_range,
+            rhs_range,
+        });
+    }
+
+    Ok(SensitivityReport {
+        obj_val: sense * obj_val + unsafe { ffi::SCIPgetOrigObjoffset(scip.raw) },
+        vars: var_reports,
+        conss: cons_reports,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn assert_approx(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() <= 1e-6 * (1. + expected.abs()) || actual == expected,
+            "{actual} != {expected}"
+        );
+    }
+
+    fn assert_range(actual: Option<(f64, f64)>, expected: (f64, f64)) {
+        let actual = actual.unwrap();
+        assert_approx(actual.0, expected.0);
+        assert_approx(actual.1, expected.1);
+    }
+
+    #[test]
+    fn lp_sensitivity() {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .create_prob("wyndor")
+            .maximize();
+        let x = model.add_var(0., f64::INFINITY, 3., "x", VarType::Continuous);
+        let y = model.add_var(0., f64::INFINITY, 5., "y", VarType::Continuous);
+        let c1 = model.add_cons(vec![&x], &[1.], -f64::INFINITY, 4., "c1");
+        let c2 = model.add_cons(vec![&y], &[2.], -f64::INFINITY, 12., "c2");
+        let c3 = model.add_cons(vec![&x, &y], &[3., 2.], -f64::INFINITY, 18., "c3");
+
+        let solved = model.solve();
+        let report = solved.sensitivity();
+        assert_approx(report.obj_val, 36.);
+
+        let x = report.var(&x).unwrap();
+        assert_approx(x.value, 2.);
+        assert!(x.basic);
+        assert_approx(x.obj_range.0, 0.);
+        assert_approx(x.obj_range.1, 7.5);
+        let y = report.var(&y).unwrap();
+        assert_approx(y.value, 6.);
+        assert_approx(y.obj_range.0, 2.);
+        assert_eq!(y.obj_range.1, f64::INFINITY);
+
+        let c1 = report.cons(&c1).unwrap();
+        assert!(!c1.binding);
+        assert_approx(c1.dual, 0.);
+        assert_range(c1.rhs_range, (2., f64::INFINITY));
+        assert!(c1.lhs_range.is_none());
+        let c2 = report.cons(&c2).unwrap();
+        assert!(c2.binding);
+        assert_approx(c2.dual, 1.5);
+        assert_range(c2.rhs_range, (6., 18.));
+        let c3 = report.cons(&c3).unwrap();
+        assert_approx(c3.dual, 1.);
+        assert_range(c3.rhs_range, (12., 24.));
+    }
+
+    #[test]
+    fn mip_sensitivity() {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .create_prob("wyndor")
+            .maximize();
+        let x = model.add_var(0., f64::INFINITY, 3., "x", VarType::Continuous);
+        let y = model.add_var(0., f64::INFINITY, 5., "y", VarType::Integer);
+        model.add_cons(vec![&x], &[1.], -f64::INFINITY, 4., "c1");
+        model.add_cons(vec![&y], &[2.], -f64::INFINITY, 12., "c2");
+        let c3 = model.add_cons(vec![&x, &y], &[3., 2.], -f64::INFINITY, 18., "c3");
+
+        let solved = model.solve();
+        let report = solved.sensitivity();
+        assert_approx(report.obj_val, 36.);
+        assert!(report.var(&y).unwrap().fixed);
+        assert!(!report.var(&x).unwrap().fixed);
+        assert_approx(report.cons(&c3).unwrap().dual, 1.);
+    }
+}
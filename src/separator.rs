@@ -1,4 +1,5 @@
-use crate::{Model, Retcode, Row, Solving, ffi, scip_call};
+use crate::error::Infallible;
+use crate::{Model, PluginError, Retcode, Row, Solving, ffi, scip_call};
 use scip_sys::{SCIP_ROW, SCIP_Result};
 use std::fmt::Debug;
 
@@ -15,6 +16,39 @@ pub trait Separator {
     fn execute_lp(&mut self, model: Model<Solving>, sep: SCIPSeparator) -> SeparationResult;
 }
 
+/// A fallible variant of `Separator`, for separators that can fail (e.g. because they call external code).
+///
+/// Every `Separator` is a `TrySeparator` that never fails. An error aborts the solve and is returned by
+/// `Model::try_solve`.
+pub trait TrySeparator {
+    /// Executes the separator on the LP solution, see `Separator::execute_lp`.
+    fn try_execute_lp(
+        &mut self,
+        model: Model<Solving>,
+        sep: SCIPSeparator,
+    ) -> Result<SeparationResult, PluginError>;
+}
+
+impl<S: Separator + ?Sized> TrySeparator for S {
+    fn try_execute_lp(
+        &mut self,
+        model: Model<Solving>,
+        sep: SCIPSeparator,
+    ) -> Result<SeparationResult, PluginError> {
+        Ok(self.execute_lp(model, sep))
+    }
+}
+
+impl TrySeparator for Infallible<dyn Separator> {
+    fn try_execute_lp(
+        &mut self,
+        model: Model<Solving>,
+        sep: SCIPSeparator,
+    ) -> Result<SeparationResult, PluginError> {
+        Ok(self.0.execute_lp(model, sep))
+    }
+}
+
 /// The result of a separation routine.
 pub enum SeparationResult {
     /// Detected that the node is infeasible in the variable's bounds and can be cut off
@@ -132,7 +166,7 @@ impl SCIPSeparator {
         modifiable: bool,
         removable: bool,
     ) -> Result<Row, Retcode> {
-        let name = std::ffi::CString::new(name).unwrap();
+        let name = std::ffi::CString::new(name).map_err(|_| Retcode::InvalidData)?;
         let local = if local { 1 } else { 0 };
         let modifiable = if modifiable { 1 } else { 0 };
         let removable = if removable { 1 } else { 0 };
@@ -1,8 +1,11 @@
 use std::fmt;
 use std::rc::Rc;
 
+use crate::constraint::Constraint;
+use crate::feasibility::linear_activity;
+use crate::retcode::{Retcode, ScipError};
 use crate::scip::ScipPtr;
-use crate::variable::Variable;
+use crate::variable::{VarId, Variable};
 use crate::{ffi, scip_call_panic};
 
 /// A wrapper for a SCIP solution.
@@ -57,6 +60,106 @@ impl Solution {
         map
     }
 
+    /// Returns the activity of a constraint in the solution, i.e. the value of its linear expression.
+    /// Returns `None` if the constraint cannot be represented as a single linear constraint.
+    pub fn activity(&self, cons: &Constraint) -> Option<f64> {
+        self.linear_activity(cons).map(|(activity, _, _)| activity)
+    }
+
+    /// Returns the slack of a constraint in the solution, i.e. the distance of its activity to the nearest side.
+    /// The slack is negative if the constraint is violated, and infinite if the constraint has no finite side.
+    /// Returns `None` if the constraint cannot be represented as a single linear constraint.
+    pub fn slack(&self, cons: &Constraint) -> Option<f64> {
+        self.linear_activity(cons)
+            .map(|(activity, lhs, rhs)| (activity - lhs).min(rhs - activity))
+    }
+
+    /// Same as `activity`, but returns an error if the constraint cannot be represented as a single linear
+    /// constraint.
+    pub fn try_activity(&self, cons: &Constraint) -> Result<f64, ScipError> {
+        self.activity(cons)
+            .ok_or(ScipError::new(Retcode::InvalidCall, ""))
+    }
+
+    /// Same as `slack`, but returns an error if the constraint cannot be represented as a single linear
+    /// constraint.
+    pub fn try_slack(&self, cons: &Constraint) -> Result<f64, ScipError> {
+        self.slack(cons)
+            .ok_or(ScipError::new(Retcode::InvalidCall, ""))
+    }
+
+    /// Returns whether the activity of a constraint in the solution is within `tol` of one of its sides.
+    /// Returns `false` if the constraint cannot be represented as a single linear constraint.
+    pub fn is_binding(&self, cons: &Constraint, tol: f64) -> bool {
+        self.slack(cons).is_some_and(|slack| slack.abs() <= tol)
+    }
+
+    fn linear_activity(&self, cons: &Constraint) -> Option<(f64, f64, f64)> {
+        linear_activity(&self.scip_ptr, cons.raw, self.raw)
+    }
+
+    /// Returns the name of the heuristic that found the solution, including heuristics implemented in Rust.
+    ///
+    /// Returns `None` if the solution was not found by a heuristic, e.g. for LP solutions or solutions added by the
+    /// user outside of a heuristic.
+    pub fn heur_name(&self) -> Option<String> {
+        let heur = unsafe { ffi::SCIPsolGetHeur(self.raw) };
+        if heur.is_null() {
+            return None;
+        }
+        let name = unsafe { std::ffi::CStr::from_ptr(ffi::SCIPheurGetName(heur)) };
+        Some(name.to_string_lossy().into_owned())
+    }
+
+    /// Returns the kind of plugin or method that found the solution.
+    pub fn sol_type(&self) -> SolType {
+        unsafe { ffi::SCIPsolGetType(self.raw) }.into()
+    }
+
+    /// Returns the solving time in seconds at which the solution was found.
+    pub fn time(&self) -> f64 {
+        unsafe { ffi::SCIPsolGetTime(self.raw) }
+    }
+
+    /// Returns the number of the node at which the solution was found.
+    pub fn node_number(&self) -> usize {
+        unsafe { ffi::SCIPsolGetNodenum(self.raw) }.max(0) as usize
+    }
+
+    /// Returns the depth of the node at which the solution was found, or `None` if it was found outside of the
+    /// branch-and-bound tree, e.g. during presolving.
+    pub fn depth(&self) -> Option<usize> {
+        let depth = unsafe { ffi::SCIPsolGetDepth(self.raw) };
+        (depth >= 0).then_some(depth as usize)
+    }
+
+    /// Returns the number of the run (restarts included) in which the solution was found.
+    pub fn run(&self) -> usize {
+        unsafe { ffi::SCIPsolGetRunnum(self.raw) }.max(0) as usize
+    }
+
+    /// Returns an owned copy of the solution in terms of the original variables, which can outlive the model and
+    /// be passed to `ProblemOrSolving::solution_from_data` of another model of the same problem.
+    pub fn snapshot(&self) -> SolutionData {
+        let vars = unsafe { ffi::SCIPgetOrigVars(self.scip_ptr.raw) };
+        let n_vars = unsafe { ffi::SCIPgetNOrigVars(self.scip_ptr.raw) };
+        let mut values = Vec::with_capacity(n_vars as usize);
+        for i in 0..n_vars {
+            let var = unsafe { *vars.offset(i as isize) };
+            let name_ptr = unsafe { ffi::SCIPvarGetName(var) };
+            let name = unsafe { std::ffi::CStr::from_ptr(name_ptr) };
+            values.push(VarValue {
+                name: name.to_string_lossy().into_owned(),
+                id: unsafe { ffi::SCIPvarGetIndex(var) } as VarId,
+                value: unsafe { ffi::SCIPgetSolVal(self.scip_ptr.raw, self.raw, var) },
+            });
+        }
+        SolutionData {
+            obj_val: self.obj_val(),
+            values,
+        }
+    }
+
     /// Returns the solution as a var-id to value map.
     pub fn as_id_map(&self) -> std::collections::HashMap<i32, f64> {
         let vars = unsafe { ffi::SCIPgetVars(self.scip_ptr.raw) };
@@ -97,11 +200,87 @@ impl fmt::Debug for Solution {
     }
 }
 
+/// The kind of plugin or method that found a solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolType {
+    /// The origin of the solution is unknown, e.g. it was added by the user, or SCIP reported a solution type that
+    /// is not listed here.
+    Unknown,
+    /// The solution was found by a primal heuristic.
+    Heuristic,
+    /// The solution is the solution of a relaxator.
+    Relaxation,
+    /// The solution is the solution of an LP relaxation.
+    LpRelaxation,
+    /// The solution was found during strong branching.
+    StrongBranching,
+    /// The solution is a pseudo solution.
+    Pseudo,
+}
+
+impl From<ffi::SCIP_SOLTYPE> for SolType {
+    fn from(val: ffi::SCIP_SOLTYPE) -> Self {
+        match val {
+            ffi::SCIP_SolType_SCIP_SOLTYPE_HEUR => SolType::Heuristic,
+            ffi::SCIP_SolType_SCIP_SOLTYPE_RELAX => SolType::Relaxation,
+            ffi::SCIP_SolType_SCIP_SOLTYPE_LPRELAX => SolType::LpRelaxation,
+            ffi::SCIP_SolType_SCIP_SOLTYPE_STRONGBRANCH => SolType::StrongBranching,
+            ffi::SCIP_SolType_SCIP_SOLTYPE_PSEUDO => SolType::Pseudo,
+            _ => SolType::Unknown,
+        }
+    }
+}
+
+/// The value of an original variable in a `SolutionData`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VarValue {
+    /// The name of the variable.
+    pub name: String,
+    /// The index of the variable in the model the solution was taken from.
+    pub id: VarId,
+    /// The value of the variable.
+    pub value: f64,
+}
+
+/// An owned solution independent of any model, created with `Solution::snapshot`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolutionData {
+    /// The objective value of the solution.
+    pub obj_val: f64,
+    /// The values of all original variables.
+    pub values: Vec<VarValue>,
+}
+
+impl SolutionData {
+    /// Returns the value of the variable with the given name, if it is part of the solution.
+    ///
+    /// The values are searched linearly, so collect them into a map when looking up many variables.
+    pub fn val(&self, name: &str) -> Option<f64> {
+        self.values
+            .iter()
+            .find(|value| value.name == name)
+            .map(|value| value.value)
+    }
+}
+
 /// Represents and error that can occur when adding a solution.
 #[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum SolError {
     /// The solution is infeasible.
     Infeasible,
+    /// The solution contains a variable that is not part of the model.
+    UnknownVariable(String),
+    /// SCIP failed to add the solution.
+    Scip(ScipError),
+}
+
+impl From<ScipError> for SolError {
+    fn from(error: ScipError) -> Self {
+        SolError::Scip(error)
+    }
 }
 
 #[cfg(test)]
@@ -143,4 +322,117 @@ mod tests {
         assert_eq!(sol_id_map.get(&0).unwrap(), &40.);
         assert_eq!(sol_id_map.get(&1).unwrap(), &20.);
     }
+
+    #[test]
+    fn snapshot_roundtrip() {
+        let solved = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap()
+            .solve();
+        let data = solved.best_sol().unwrap().snapshot();
+        drop(solved);
+
+        assert_eq!(data.obj_val, 200.);
+        assert_eq!(data.val("x1"), Some(40.));
+        assert_eq!(data.val("x2"), Some(20.));
+        assert_eq!(data.val("t_x1"), None);
+
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap();
+        let sol = model.solution_from_data(&data).unwrap();
+        assert_eq!(sol.obj_val(), 200.);
+        model.add_sol(sol).unwrap();
+        let solved = model.solve();
+        assert_eq!(solved.best_sol().unwrap().snapshot(), data);
+
+        let mut unknown = data.clone();
+        unknown.values[0].name = "y".to_string();
+        let model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap();
+        assert_eq!(
+            model.solution_from_data(&unknown).unwrap_err(),
+            SolError::UnknownVariable("y".to_string())
+        );
+    }
+
+    #[test]
+    fn constraint_activities() {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap();
+        let vars = model.vars();
+        let c3 = model.add_cons(vars.iter().collect(), &[1., 1.], 10., 1000., "c3");
+        let solved = model.solve();
+        let sol = solved.best_sol().unwrap();
+
+        assert_eq!(sol.activity(&c3), Some(60.));
+        assert_eq!(sol.slack(&c3), Some(50.));
+        assert!(!sol.is_binding(&c3, 1e-6));
+        assert_eq!(sol.try_slack(&c3), Ok(50.));
+
+        let activities: Vec<_> = solved
+            .constraint_activities(&sol)
+            .into_iter()
+            .map(|(cons, activity)| (cons.name(), activity))
+            .collect();
+        assert_eq!(
+            activities,
+            vec![
+                ("c1".to_string(), 100.),
+                ("c2".to_string(), 80.),
+                ("c3".to_string(), 60.)
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_sol_type() {
+        assert_eq!(SolType::from(u32::MAX), SolType::Unknown);
+    }
+
+    #[test]
+    fn slack_of_free_row() {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap();
+        let vars = model.vars();
+        let free = model.add_cons(
+            vars.iter().collect(),
+            &[1., 1.],
+            -f64::INFINITY,
+            f64::INFINITY,
+            "free",
+        );
+        let solved = model.solve();
+        let sol = solved.best_sol().unwrap();
+
+        assert_eq!(sol.activity(&free), Some(60.));
+        assert_eq!(sol.slack(&free), Some(f64::INFINITY));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn snapshot_serde() {
+        let solved = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .read_prob("data/test/simple.lp")
+            .unwrap()
+            .solve();
+        let data = solved.best_sol().unwrap().snapshot();
+        let json = serde_json::to_string(&data).unwrap();
+        assert_eq!(serde_json::from_str::<SolutionData>(&json).unwrap(), data);
+    }
 }
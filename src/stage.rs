@@ -0,0 +1,95 @@
+use crate::ffi;
+use scip_sys::SCIP_Stage;
+
+/// An enum representing the stage of a SCIP instance, as returned by `Model::stage`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Stage {
+    /// SCIP data structures are initialized, no problem exists.
+    Init,
+    /// The problem is being created and modified.
+    Problem,
+    /// The problem is being transformed into solver data space.
+    Transforming,
+    /// The problem was transformed into solver data space.
+    Transformed,
+    /// Presolving is initialized.
+    InitPresolve,
+    /// The problem is being presolved.
+    Presolving,
+    /// Presolving is exited.
+    ExitPresolve,
+    /// The problem was presolved.
+    Presolved,
+    /// The solving process data is being initialized.
+    InitSolve,
+    /// The problem is being solved.
+    Solving,
+    /// The problem was solved.
+    Solved,
+    /// The solving process data is being freed.
+    ExitSolve,
+    /// The transformed problem is being freed.
+    FreeTrans,
+    /// SCIP data structures are being freed.
+    Free,
+    /// SCIP reported a stage that is not listed here. It is ordered after all other stages.
+    Unknown,
+}
+
+impl From<SCIP_Stage> for Stage {
+    /// Converts a u32 value to a `Stage` enum variant.
+    fn from(val: SCIP_Stage) -> Self {
+        match val {
+            ffi::SCIP_Stage_SCIP_STAGE_INIT => Stage::Init,
+            ffi::SCIP_Stage_SCIP_STAGE_PROBLEM => Stage::Problem,
+            ffi::SCIP_Stage_SCIP_STAGE_TRANSFORMING => Stage::Transforming,
+            ffi::SCIP_Stage_SCIP_STAGE_TRANSFORMED => Stage::Transformed,
+            ffi::SCIP_Stage_SCIP_STAGE_INITPRESOLVE => Stage::InitPresolve,
+            ffi::SCIP_Stage_SCIP_STAGE_PRESOLVING => Stage::Presolving,
+            ffi::SCIP_Stage_SCIP_STAGE_EXITPRESOLVE => Stage::ExitPresolve,
+            ffi::SCIP_Stage_SCIP_STAGE_PRESOLVED => Stage::Presolved,
+            ffi::SCIP_Stage_SCIP_STAGE_INITSOLVE => Stage::InitSolve,
+            ffi::SCIP_Stage_SCIP_STAGE_SOLVING => Stage::Solving,
+            ffi::SCIP_Stage_SCIP_STAGE_SOLVED => Stage::Solved,
+            ffi::SCIP_Stage_SCIP_STAGE_EXITSOLVE => Stage::ExitSolve,
+            ffi::SCIP_Stage_SCIP_STAGE_FREETRANS => Stage::FreeTrans,
+            ffi::SCIP_Stage_SCIP_STAGE_FREE => Stage::Free,
+            _ => Stage::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::{Model, WithSolutions};
+
+    #[test]
+    fn stages() {
+        let model = Model::new().hide_output();
+        assert_eq!(model.stage(), Stage::Init);
+        let model = model.include_default_plugins();
+        assert_eq!(model.stage(), Stage::Init);
+        let model = model.read_prob("data/test/simple.lp").unwrap();
+        assert_eq!(model.stage(), Stage::Problem);
+
+        let model = model.presolve();
+        assert_eq!(model.stage(), Stage::Presolved);
+        assert!(model.n_vars() <= 2);
+
+        let solved = model.solve();
+        assert_eq!(solved.stage(), Stage::Solved);
+        assert_eq!(solved.status(), Status::Optimal);
+        assert_eq!(solved.obj_val(), 200.);
+        assert!(solved.best_sol().is_some());
+
+        let model = solved.free_transform();
+        assert_eq!(model.stage(), Stage::Problem);
+    }
+    #[test]
+    fn unknown_stage() {
+        assert_eq!(Stage::from(u32::MAX), Stage::Unknown);
+        assert!(Stage::Unknown > Stage::Free);
+    }
+}
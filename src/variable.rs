@@ -104,6 +104,31 @@ impl Variable {
         unsafe { ffi::SCIPgetVarSol(self.scip.raw, self.raw) }
     }
 
+    /// Returns the reduced cost of the variable in the last LP solution, or `None` if the variable has no column
+    /// in the LP, e.g. before solving or if presolving removed it.
+    ///
+    /// The reduced cost refers to the objective sense of the original problem. After solving, it is only
+    /// meaningful for pure LPs solved without presolving; use `Model::sensitivity` otherwise.
+    pub fn reduced_cost(&self) -> Option<f64> {
+        let stage = unsafe { ffi::SCIPgetStage(self.scip.raw) };
+        if stage != ffi::SCIP_Stage_SCIP_STAGE_SOLVING && stage != ffi::SCIP_Stage_SCIP_STAGE_SOLVED
+        {
+            return None;
+        }
+        let var = if self.is_original() {
+            self.transformed()?
+        } else {
+            self.clone()
+        };
+        if unsafe { ffi::SCIPvarGetStatus(var.raw) } != ffi::SCIP_Varstatus_SCIP_VARSTATUS_COLUMN {
+            return None;
+        }
+        let col = unsafe { ffi::SCIPvarGetCol(var.raw) };
+        // the LP of the transformed problem is always a minimization
+        let sense = unsafe { ffi::SCIPgetObjsense(self.scip.raw) } as f64;
+        Some(sense * unsafe { ffi::SCIPgetColRedcost(self.scip.raw, col) })
+    }
+
     /// Returns whether the variable is deleted.
     pub fn is_deleted(&self) -> bool {
         unsafe { ffi::SCIPvarIsDeleted(self.raw) != 0 }
@@ -227,7 +252,7 @@ impl From<SCIP_Status> for VarStatus {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Model, ObjSense, ProblemOrSolving, minimal_model};
+    use crate::{Model, ObjSense, ParamSetting, ProblemOrSolving, Status, minimal_model};
 
     #[test]
     fn var_data() {
@@ -254,6 +279,42 @@ mod tests {
         assert!(!var.inner().is_null());
     }
 
+    #[test]
+    fn reduced_cost() {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .create_prob("test")
+            .set_presolving(ParamSetting::Off);
+        let x = model.add_var(0., 10., 1., "x", VarType::Continuous);
+        let y = model.add_var(0., 10., 2., "y", VarType::Continuous);
+        model.add_cons(vec![&x, &y], &[1., 1.], 2., f64::INFINITY, "c");
+        assert_eq!(x.reduced_cost(), None);
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert_eq!(x.reduced_cost(), Some(0.));
+        assert_eq!(y.reduced_cost(), Some(1.));
+    }
+
+    #[test]
+    fn reduced_cost_maximize() {
+        let mut model = Model::new()
+            .hide_output()
+            .include_default_plugins()
+            .create_prob("test")
+            .set_presolving(ParamSetting::Off)
+            .maximize();
+        let x = model.add_var(0., 10., 1., "x", VarType::Continuous);
+        let y = model.add_var(0., 10., -2., "y", VarType::Continuous);
+        model.add_cons(vec![&x, &y], &[1., 1.], -f64::INFINITY, 2., "c");
+
+        let solved = model.solve();
+        assert_eq!(solved.status(), Status::Optimal);
+        assert_eq!(x.reduced_cost(), Some(0.));
+        assert_eq!(y.reduced_cost(), Some(-3.));
+    }
+
     #[test]
     fn var_memory_safety() {
         let mut model = Model::new()